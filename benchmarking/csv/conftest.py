@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+import pytest
+
+
+@pytest.fixture(scope="session")
+def small_csv_files(tmp_path_factory) -> list[str]:
+    """Many small CSV files, which are each read in a single chunk"""
+    directory = tmp_path_factory.mktemp("small_csv_files")
+    paths = []
+    for i in range(1000):
+        path = directory / f"file_{i}.csv"
+        path.write_text("id,name,value\n" + "".join(f"{j},name_{j},{j * 0.5}\n" for j in range(20)))
+        paths.append(str(path))
+    return paths
+
+
+@pytest.fixture(scope="session")
+def large_csv_file(tmp_path_factory) -> str:
+    """A single CSV file that is read in many chunks"""
+    path = tmp_path_factory.mktemp("large_csv_file") / "file.csv"
+    path.write_text("id,name,value\n" + "".join(f"{j},name_{j},{j * 0.5}\n" for j in range(1_000_000)))
+    return str(path)
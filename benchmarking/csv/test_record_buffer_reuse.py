@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.daft import CsvReadOptions
+from daft.table import LegacyTable
+
+
+def read_csv_files(paths: list[str], read_options: CsvReadOptions) -> list[LegacyTable]:
+    return [LegacyTable.read_csv(path, read_options=read_options) for path in paths]
+
+
+@pytest.mark.benchmark(group="small_files")
+@pytest.mark.parametrize("reuse_record_buffers", [False, True], ids=["fresh_buffers", "reused_buffers"])
+def test_read_csv_small_files(small_csv_files, reuse_record_buffers, benchmark):
+    # Files are read one at a time from Python, so this measures the overhead of the pool for single-chunk reads;
+    # buffers are only shared across files by bulk reads.
+    read_options = CsvReadOptions(reuse_record_buffers=reuse_record_buffers)
+    tables = benchmark(read_csv_files, small_csv_files, read_options)
+    assert len(tables) == len(small_csv_files)
+    assert all(len(table) == 20 for table in tables)
+
+
+@pytest.mark.benchmark(group="many_chunks")
+@pytest.mark.parametrize("reuse_record_buffers", [False, True], ids=["fresh_buffers", "reused_buffers"])
+def test_read_csv_many_chunks(large_csv_file, reuse_record_buffers, benchmark):
+    read_options = CsvReadOptions(chunk_size=16 * 1024, reuse_record_buffers=reuse_record_buffers)
+    [table] = benchmark(read_csv_files, [large_csv_file], read_options)
+    assert len(table) == 1_000_000
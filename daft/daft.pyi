@@ -201,6 +201,138 @@ class CsvSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class CsvConvertOptions:
+    """
+    Options for converting CSV data to Daft data.
+    """
+
+    limit: int | None
+    include_columns: list[str] | None
+    column_names: list[str] | None
+    schema: PySchema | None
+    include_columns_as: list[tuple[str, str]] | None
+    thousands_separator: str | None
+    parentheses_as_negative: bool
+    null_values: list[str] | None
+    true_values: list[str] | None
+    false_values: list[str] | None
+    all_strings: bool
+    dtype_overrides: list[tuple[str, PyDataType]] | None
+    predicate: list[PyExpr] | None
+    include_column_indices: list[int] | None
+    date_format: str | None
+    timestamp_format: str | None
+    case_insensitive_columns: bool
+    dedup_column_names: bool
+    infer_decimals: bool
+    decimal_scales: list[tuple[str, int]] | None
+    non_nullable_columns: list[str] | None
+
+    def __init__(
+        self,
+        limit: int | None = None,
+        include_columns: list[str] | None = None,
+        column_names: list[str] | None = None,
+        schema: PySchema | None = None,
+        include_columns_as: list[tuple[str, str]] | None = None,
+        thousands_separator: str | None = None,
+        parentheses_as_negative: bool = False,
+        null_values: list[str] | None = None,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        all_strings: bool = False,
+        dtype_overrides: list[tuple[str, PyDataType]] | None = None,
+        predicate: list[PyExpr] | None = None,
+        include_column_indices: list[int] | None = None,
+        date_format: str | None = None,
+        timestamp_format: str | None = None,
+        case_insensitive_columns: bool = False,
+        dedup_column_names: bool = True,
+        infer_decimals: bool = False,
+        decimal_scales: list[tuple[str, int]] | None = None,
+        non_nullable_columns: list[str] | None = None,
+    ): ...
+
+class CsvParseOptions:
+    """
+    Options for parsing CSV files.
+    """
+
+    has_header: bool
+    delimiter: int
+    escape: int | None
+    comment: int | None
+    skip_rows: int
+    line_terminator: int | None
+    double_quote: bool
+    null_byte_handling: str
+    trim: bool
+    flexible: bool
+    encoding: str | None
+
+    def __init__(
+        self,
+        has_header: bool = True,
+        delimiter: str | None = None,
+        escape: str | None = None,
+        comment: str | None = None,
+        skip_rows: int = 0,
+        line_terminator: str | None = None,
+        double_quote: bool = True,
+        null_byte_handling: str = "error",
+        trim: bool = False,
+        flexible: bool = False,
+        encoding: str | None = None,
+    ): ...
+
+class CsvReadOptions:
+    """
+    Options for reading CSV files.
+    """
+
+    buffer_size: int | None
+    chunk_size: int | None
+    inference_max_rows: int | None
+    max_bytes: int | None
+    max_chunks_in_flight: int | None
+    mean_row_size_hint: int | None
+    stddev_row_size_hint: int | None
+    start_byte: int | None
+    reuse_record_buffers: bool
+    reuse_first_schema: bool
+    strict_schema: bool
+    skip_empty_files: bool
+    compute_statistics: bool
+
+    def __init__(
+        self,
+        buffer_size: int | None = None,
+        chunk_size: int | None = None,
+        inference_max_rows: int | None = None,
+        max_bytes: int | None = None,
+        max_chunks_in_flight: int | None = None,
+        mean_row_size_hint: int | None = None,
+        stddev_row_size_hint: int | None = None,
+        start_byte: int | None = None,
+        reuse_record_buffers: bool = False,
+        reuse_first_schema: bool = True,
+        strict_schema: bool = False,
+        skip_empty_files: bool = True,
+        compute_statistics: bool = False,
+    ): ...
+
+class CsvWriteOptions:
+    """
+    Options for writing CSV files.
+    """
+
+    float_precision: int | None
+
+    def __init__(
+        self,
+        float_precision: int | None = None,
+    ): ...
+
 class JsonSourceConfig:
     """
     Configuration of a JSON data source.
@@ -239,6 +371,20 @@ class FileFormatConfig:
     def __eq__(self, other: FileFormatConfig) -> bool: ...  # type: ignore[override]
     def __ne__(self, other: FileFormatConfig) -> bool: ...  # type: ignore[override]
 
+class ScanOperator:
+    """
+    A scan over a data source, into which filters, projections and limits can be pushed down.
+    """
+
+    @staticmethod
+    def anonymous_scan(
+        schema: PySchema, file_type: str, files: list[str], hive_partitioning: bool = False
+    ) -> ScanOperator: ...
+    @staticmethod
+    def python_scan(operator: Any) -> ScanOperator: ...
+    def select(self, columns: list[str]) -> ScanOperator: ...
+    def limit(self, num: int) -> ScanOperator: ...
+
 class FileInfo:
     """
     Metadata for a single file.
@@ -380,6 +526,7 @@ def read_parquet(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
     coerce_int96_timestamp_unit: PyTimeUnit | None = None,
+    keep_dictionary_columns: list[str] | None = None,
 ): ...
 def read_parquet_bulk(
     uris: list[str],
@@ -391,6 +538,7 @@ def read_parquet_bulk(
     num_parallel_tasks: int | None = 128,
     multithreaded_io: bool | None = None,
     coerce_int96_timestamp_unit: PyTimeUnit | None = None,
+    keep_dictionary_columns: list[str] | None = None,
 ): ...
 def read_parquet_statistics(
     uris: PySeries,
@@ -426,24 +574,38 @@ def read_parquet_schema(
 ): ...
 def read_csv(
     uri: str,
-    column_names: list[str] | None = None,
-    include_columns: list[str] | None = None,
-    num_rows: int | None = None,
-    has_header: bool | None = None,
-    delimiter: str | None = None,
+    convert_options: CsvConvertOptions | None = None,
+    parse_options: CsvParseOptions | None = None,
+    read_options: CsvReadOptions | None = None,
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
-    schema: PySchema | None = None,
-    buffer_size: int | None = None,
-    chunk_size: int | None = None,
 ): ...
 def read_csv_schema(
     uri: str,
-    has_header: bool | None = None,
-    delimiter: str | None = None,
+    parse_options: CsvParseOptions | None = None,
+    max_bytes: int | None = None,
+    max_rows: int | None = None,
+    null_values: list[str] | None = None,
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def read_csv_schema_bulk(
+    uris: list[str],
+    parse_options: CsvParseOptions | None = None,
+    max_bytes: int | None = None,
+    max_rows: int | None = None,
+    convert_options: CsvConvertOptions | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+    merge_schemas: bool | None = None,
+) -> list[PySchema]: ...
+def infer_csv_schema(
+    uri: str,
+    parse_options: CsvParseOptions | None = None,
+    read_options: CsvReadOptions | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+) -> tuple[PySchema, int | None]: ...
 
 class PyTimeUnit:
     @staticmethod
@@ -501,6 +663,8 @@ class PyDataType:
     @staticmethod
     def extension(name: str, storage_data_type: PyDataType, metadata: str | None = None) -> PyDataType: ...
     @staticmethod
+    def dictionary(values_data_type: PyDataType) -> PyDataType: ...
+    @staticmethod
     def embedding(data_type: PyDataType, size: int) -> PyDataType: ...
     @staticmethod
     def image(mode: ImageMode | None = None, height: int | None = None, width: int | None = None) -> PyDataType: ...
@@ -536,6 +700,7 @@ class PySchema:
     def from_field_name_and_types(names_and_types: list[tuple[str, PyDataType]]) -> PySchema: ...
     @staticmethod
     def from_fields(fields: list[PyField]) -> PySchema: ...
+    def to_sql_ddl(self, table_name: str, dialect: str) -> str: ...
     def __reduce__(self) -> tuple: ...
     def __repr__(self) -> str: ...
     def _repr_html_(self) -> str: ...
@@ -576,6 +741,7 @@ class PyExpr:
     def is_null(self) -> PyExpr: ...
     def name(self) -> str: ...
     def to_field(self, schema: PySchema) -> PyField: ...
+    def to_projected_fields(self, schema: PySchema) -> list[PyField]: ...
     def __repr__(self) -> str: ...
     def __hash__(self) -> int: ...
     def __reduce__(self) -> tuple: ...
@@ -603,7 +769,13 @@ class PyExpr:
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
 def col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
-def udf(func: Callable, expressions: list[PyExpr], return_dtype: PyDataType) -> PyExpr: ...
+def udf(
+    func: Callable,
+    expressions: list[PyExpr],
+    return_dtype: PyDataType,
+    batch_size: int | None = None,
+    struct_output: bool = False,
+) -> PyExpr: ...
 
 class PySeries:
     @staticmethod
@@ -711,6 +883,9 @@ class PyMicroPartition:
     def column_names(self) -> list[str]: ...
     def get_column(self, name: str) -> PySeries: ...
     def size_bytes(self) -> int: ...
+    def size_bytes_is_estimate(self) -> bool: ...
+    def chunk_info(self) -> list[tuple[int, int]]: ...
+    def to_sql_ddl(self, table_name: str, dialect: str) -> str: ...
     def _repr_html_(self) -> str: ...
     @staticmethod
     def empty(schema: PySchema | None = None) -> PyMicroPartition: ...
@@ -722,6 +897,7 @@ class PyMicroPartition:
     def concat(tables: list[PyMicroPartition]) -> PyMicroPartition: ...
     def slice(self, start: int, end: int) -> PyMicroPartition: ...
     def to_table(self) -> PyTable: ...
+    def to_arrow_record_batches(self) -> list[pyarrow.RecordBatch]: ...
     def cast_to_schema(self, schema: PySchema) -> PyMicroPartition: ...
     def eval_expression_list(self, exprs: list[PyExpr]) -> PyMicroPartition: ...
     def take(self, idx: PySeries) -> PyMicroPartition: ...
@@ -730,15 +906,20 @@ class PyMicroPartition:
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
     def join(self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyMicroPartition: ...
+    def join_with_predicate(
+        self, right: PyMicroPartition, predicate: PyExpr, max_output_rows: int | None = None
+    ) -> PyMicroPartition: ...
     def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
     def head(self, num: int) -> PyMicroPartition: ...
     def sample(self, num: int) -> PyMicroPartition: ...
     def quantiles(self, num: int) -> PyMicroPartition: ...
+    def approx_quantiles(self, num: int, epsilon: float) -> PyMicroPartition: ...
     def partition_by_hash(self, exprs: list[PyExpr], num_partitions: int) -> list[PyMicroPartition]: ...
     def partition_by_random(self, num_partitions: int, seed: int) -> list[PyMicroPartition]: ...
     def partition_by_range(
         self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
     ) -> list[PyMicroPartition]: ...
+    def split_by_size(self, target_bytes: int) -> list[PyMicroPartition]: ...
     def __repr__(self) -> str: ...
     def __len__(self) -> int: ...
     @classmethod
@@ -752,6 +933,25 @@ class PyMicroPartition:
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: PyTimeUnit = PyTimeUnit.nanoseconds(),
+        filter: PyExpr | None = None,
+        keep_dictionary_columns: list[str] | None = None,
+    ): ...
+    def write_parquet(
+        self,
+        uri: str,
+        compression: str | None = None,
+        row_group_size: int | None = None,
+        io_config: IOConfig | None = None,
+    ) -> str: ...
+    @classmethod
+    def read_parquet_from_bytes(
+        cls,
+        data: bytes,
+        columns: list[str] | None = None,
+        start_offset: int | None = None,
+        num_rows: int | None = None,
+        row_groups: list[int] | None = None,
+        coerce_int96_timestamp_unit: PyTimeUnit = PyTimeUnit.nanoseconds(),
     ): ...
     @classmethod
     def read_parquet_bulk(
@@ -765,21 +965,35 @@ class PyMicroPartition:
         num_parallel_tasks: int | None = None,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: PyTimeUnit | None = None,
+        keep_dictionary_columns: list[str] | None = None,
     ): ...
     @classmethod
     def read_csv(
         cls,
         uri: str,
-        column_names: list[str] | None = None,
-        include_columns: list[str] | None = None,
-        num_rows: int | None = None,
-        has_header: bool | None = None,
-        delimiter: str | None = None,
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
-        schema: PySchema | None = None,
-        buffer_size: int | None = None,
-        chunk_size: int | None = None,
+    ): ...
+    @classmethod
+    def read_csv_bulk(
+        cls,
+        uris: list[str],
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
+        io_config: IOConfig | None = None,
+        multithreaded_io: bool | None = None,
+    ): ...
+    @classmethod
+    def read_csv_from_bytes(
+        cls,
+        data: bytes,
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
     ): ...
 
 class PhysicalPlanScheduler:
@@ -238,6 +238,16 @@ class DataType:
     def extension(cls, name: str, storage_dtype: DataType, metadata: str | None = None) -> DataType:
         return cls._from_pydatatype(PyDataType.extension(name, storage_dtype._dtype, metadata))
 
+    @classmethod
+    def dictionary(cls, dtype: DataType) -> DataType:
+        """Create a Dictionary DataType: dictionary-encoded values of type ``dtype``, stored as keys into a dictionary
+        of the distinct values
+
+        Args:
+            dtype: DataType of the values
+        """
+        return cls._from_pydatatype(PyDataType.dictionary(dtype._dtype))
+
     @classmethod
     def embedding(cls, dtype: DataType, size: int) -> DataType:
         """Create an Embedding DataType: embeddings are fixed size arrays, where each element
@@ -365,6 +375,9 @@ class DataType:
             assert isinstance(arrow_type, pa.FixedSizeListType)
             field = arrow_type.value_field
             return cls.fixed_size_list(cls.from_arrow_type(field.type), arrow_type.list_size)
+        elif pa.types.is_dictionary(arrow_type):
+            assert isinstance(arrow_type, pa.DictionaryType)
+            return cls.dictionary(cls.from_arrow_type(arrow_type.value_type))
         elif pa.types.is_struct(arrow_type):
             assert isinstance(arrow_type, pa.StructType)
             fields = [arrow_type[i] for i in range(arrow_type.num_fields)]
@@ -121,8 +121,16 @@ class Expression:
             return lit(obj)
 
     @staticmethod
-    def udf(func: Callable, expressions: builtins.list[Expression], return_dtype: DataType) -> Expression:
-        return Expression._from_pyexpr(_udf(func, [e._expr for e in expressions], return_dtype._dtype))
+    def udf(
+        func: Callable,
+        expressions: builtins.list[Expression],
+        return_dtype: DataType,
+        batch_size: int | None = None,
+        struct_output: bool = False,
+    ) -> Expression:
+        return Expression._from_pyexpr(
+            _udf(func, [e._expr for e in expressions], return_dtype._dtype, batch_size, struct_output)
+        )
 
     def __bool__(self) -> bool:
         raise ValueError(
@@ -378,6 +386,9 @@ class Expression:
     def _to_field(self, schema: Schema) -> Field:
         return Field._from_pyfield(self._expr.to_field(schema._schema))
 
+    def _to_projected_fields(self, schema: Schema) -> builtins.list[Field]:
+        return [Field._from_pyfield(f) for f in self._expr.to_projected_fields(schema._schema)]
+
     def __hash__(self) -> int:
         return self._expr.__hash__()
 
@@ -751,7 +762,7 @@ class ExpressionsProjection(Iterable[Expression]):
         return [expr._expr for expr in self]
 
     def resolve_schema(self, schema: Schema) -> Schema:
-        fields = [e._to_field(schema) for e in self]
+        fields = [f for e in self for f in e._to_projected_fields(schema)]
         return Schema._from_field_name_and_types([(f.name, f.dtype) for f in fields])
 
 
@@ -5,7 +5,13 @@ from typing import TYPE_CHECKING, Any
 
 import pyarrow as pa
 
-from daft.daft import IOConfig, JoinType
+from daft.daft import (
+    CsvConvertOptions,
+    CsvParseOptions,
+    CsvReadOptions,
+    IOConfig,
+    JoinType,
+)
 from daft.daft import PyMicroPartition as _PyMicroPartition
 from daft.daft import PyTable as _PyTable
 from daft.datatype import DataType, TimeUnit
@@ -13,6 +19,7 @@ from daft.expressions import Expression, ExpressionsProjection
 from daft.logical.schema import Schema
 from daft.series import Series
 from daft.table import Table
+from daft.table.table import _csv_options_from_deprecated_kwargs
 
 if TYPE_CHECKING:
     import pandas as pd
@@ -46,6 +53,20 @@ class MicroPartition:
     def size_bytes(self) -> int:
         return self._micropartition.size_bytes()
 
+    def size_bytes_is_estimate(self) -> bool:
+        """Whether `size_bytes` is estimated from statistics, which is the case until this MicroPartition is loaded"""
+        return self._micropartition.size_bytes_is_estimate()
+
+    def chunk_info(self) -> list[tuple[int, int]]:
+        """Returns the (number of rows, size in bytes) of each internal table of this MicroPartition"""
+        return self._micropartition.chunk_info()
+
+    def to_sql_ddl(self, table_name: str, dialect: str = "postgres") -> str:
+        """Returns a SQL `CREATE TABLE` statement for a table with this MicroPartition's schema, in the provided SQL
+        dialect, one of "postgres", "mysql" or "snowflake"
+        """
+        return self._micropartition.to_sql_ddl(table_name, dialect)
+
     def __len__(self) -> int:
         return len(self._micropartition)
 
@@ -129,6 +150,9 @@ class MicroPartition:
             cast_tensors_to_ray_tensor_dtype=cast_tensors_to_ray_tensor_dtype, convert_large_arrays=convert_large_arrays
         )
 
+    def to_arrow_record_batches(self) -> list[pa.RecordBatch]:
+        return self._micropartition.to_arrow_record_batches()
+
     def to_pydict(self) -> dict[str, list]:
         return self.to_table().to_pydict()
 
@@ -193,6 +217,9 @@ class MicroPartition:
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
+    def approx_quantiles(self, num: int, epsilon: float = 0.01) -> MicroPartition:
+        return MicroPartition._from_pymicropartition(self._micropartition.approx_quantiles(num, epsilon))
+
     def explode(self, columns: ExpressionsProjection) -> MicroPartition:
         """NOTE: Expressions here must be Explode expressions (Expression._explode())"""
         to_explode_pyexprs = [e._expr for e in columns]
@@ -256,6 +283,12 @@ class MicroPartition:
             for t in self._micropartition.partition_by_random(num_partitions, seed)
         ]
 
+    def split_by_size(self, target_bytes: int) -> list[MicroPartition]:
+        if not isinstance(target_bytes, int):
+            raise TypeError(f"Expected a target_bytes to be int, got {type(target_bytes)}")
+
+        return [MicroPartition._from_pymicropartition(t) for t in self._micropartition.split_by_size(target_bytes)]
+
     ###
     # Compute methods (MicroPartition -> Series)
     ###
@@ -301,6 +334,8 @@ class MicroPartition:
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: TimeUnit = TimeUnit.ns(),
+        filter: Expression | None = None,
+        keep_dictionary_columns: list[str] | None = None,
     ) -> MicroPartition:
         return MicroPartition._from_pymicropartition(
             _PyMicroPartition.read_parquet(
@@ -312,6 +347,38 @@ class MicroPartition:
                 io_config,
                 multithreaded_io,
                 coerce_int96_timestamp_unit._timeunit,
+                filter._expr if filter is not None else None,
+                keep_dictionary_columns,
+            )
+        )
+
+    def write_parquet(
+        self,
+        path: str,
+        compression: str | None = None,
+        row_group_size: int | None = None,
+        io_config: IOConfig | None = None,
+    ) -> str:
+        return self._micropartition.write_parquet(path, compression, row_group_size, io_config)
+
+    @classmethod
+    def read_parquet_from_bytes(
+        cls,
+        data: bytes,
+        columns: list[str] | None = None,
+        start_offset: int | None = None,
+        num_rows: int | None = None,
+        row_groups: list[int] | None = None,
+        coerce_int96_timestamp_unit: TimeUnit = TimeUnit.ns(),
+    ) -> MicroPartition:
+        return MicroPartition._from_pymicropartition(
+            _PyMicroPartition.read_parquet_from_bytes(
+                data,
+                columns,
+                start_offset,
+                num_rows,
+                row_groups,
+                coerce_int96_timestamp_unit._timeunit,
             )
         )
 
@@ -327,6 +394,7 @@ class MicroPartition:
         num_parallel_tasks: int | None = 128,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: TimeUnit = TimeUnit.ns(),
+        keep_dictionary_columns: list[str] | None = None,
     ) -> MicroPartition:
         return MicroPartition._from_pymicropartition(
             _PyMicroPartition.read_parquet_bulk(
@@ -339,6 +407,7 @@ class MicroPartition:
                 num_parallel_tasks,
                 multithreaded_io,
                 coerce_int96_timestamp_unit._timeunit,
+                keep_dictionary_columns,
             )
         )
 
@@ -353,22 +422,73 @@ class MicroPartition:
         delimiter: str | None = None,
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
+        *,
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
         schema: Schema | None = None,
         buffer_size: int | None = None,
         chunk_size: int | None = None,
     ) -> MicroPartition:
+        convert_options, parse_options, read_options = _csv_options_from_deprecated_kwargs(
+            "MicroPartition.read_csv",
+            convert_options,
+            parse_options,
+            read_options,
+            column_names=column_names,
+            include_columns=include_columns,
+            num_rows=num_rows,
+            has_header=has_header,
+            delimiter=delimiter,
+            schema=schema,
+            buffer_size=buffer_size,
+            chunk_size=chunk_size,
+        )
         return MicroPartition._from_pymicropartition(
             _PyMicroPartition.read_csv(
                 uri=path,
-                column_names=column_names,
-                include_columns=include_columns,
-                num_rows=num_rows,
-                has_header=has_header,
-                delimiter=delimiter,
+                convert_options=convert_options,
+                parse_options=parse_options,
+                read_options=read_options,
+                io_config=io_config,
+                multithreaded_io=multithreaded_io,
+            )
+        )
+
+    @classmethod
+    def read_csv_bulk(
+        cls,
+        paths: list[str],
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
+        io_config: IOConfig | None = None,
+        multithreaded_io: bool | None = None,
+    ) -> MicroPartition:
+        return MicroPartition._from_pymicropartition(
+            _PyMicroPartition.read_csv_bulk(
+                uris=paths,
+                convert_options=convert_options,
+                parse_options=parse_options,
+                read_options=read_options,
                 io_config=io_config,
                 multithreaded_io=multithreaded_io,
-                schema=schema._schema if schema is not None else None,
-                buffer_size=buffer_size,
-                chunk_size=chunk_size,
+            )
+        )
+
+    @classmethod
+    def read_csv_from_bytes(
+        cls,
+        data: bytes,
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
+    ) -> MicroPartition:
+        return MicroPartition._from_pymicropartition(
+            _PyMicroPartition.read_csv_from_bytes(
+                data,
+                convert_options=convert_options,
+                parse_options=parse_options,
+                read_options=read_options,
             )
         )
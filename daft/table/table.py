@@ -1,12 +1,13 @@
 from __future__ import annotations
 
 import logging
+import warnings
 from typing import TYPE_CHECKING, Any
 
 import pyarrow as pa
 
 from daft.arrow_utils import ensure_table
-from daft.daft import JoinType
+from daft.daft import CsvConvertOptions, CsvParseOptions, CsvReadOptions, JoinType
 from daft.daft import PyTable as _PyTable
 from daft.daft import read_csv as _read_csv
 from daft.daft import read_parquet as _read_parquet
@@ -381,6 +382,7 @@ class Table:
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: TimeUnit = TimeUnit.ns(),
+        keep_dictionary_columns: list[str] | None = None,
     ) -> Table:
         return Table._from_pytable(
             _read_parquet(
@@ -392,6 +394,7 @@ class Table:
                 io_config=io_config,
                 multithreaded_io=multithreaded_io,
                 coerce_int96_timestamp_unit=coerce_int96_timestamp_unit._timeunit,
+                keep_dictionary_columns=keep_dictionary_columns,
             )
         )
 
@@ -407,6 +410,7 @@ class Table:
         num_parallel_tasks: int | None = 128,
         multithreaded_io: bool | None = None,
         coerce_int96_timestamp_unit: TimeUnit = TimeUnit.ns(),
+        keep_dictionary_columns: list[str] | None = None,
     ) -> list[Table]:
         pytables = _read_parquet_bulk(
             uris=paths,
@@ -418,6 +422,7 @@ class Table:
             num_parallel_tasks=num_parallel_tasks,
             multithreaded_io=multithreaded_io,
             coerce_int96_timestamp_unit=coerce_int96_timestamp_unit._timeunit,
+            keep_dictionary_columns=keep_dictionary_columns,
         )
         return [Table._from_pytable(t) for t in pytables]
 
@@ -450,27 +455,95 @@ class Table:
         delimiter: str | None = None,
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
+        *,
+        convert_options: CsvConvertOptions | None = None,
+        parse_options: CsvParseOptions | None = None,
+        read_options: CsvReadOptions | None = None,
         schema: Schema | None = None,
         buffer_size: int | None = None,
         chunk_size: int | None = None,
     ) -> Table:
+        convert_options, parse_options, read_options = _csv_options_from_deprecated_kwargs(
+            "Table.read_csv",
+            convert_options,
+            parse_options,
+            read_options,
+            column_names=column_names,
+            include_columns=include_columns,
+            num_rows=num_rows,
+            has_header=has_header,
+            delimiter=delimiter,
+            schema=schema,
+            buffer_size=buffer_size,
+            chunk_size=chunk_size,
+        )
         return Table._from_pytable(
             _read_csv(
                 uri=path,
-                column_names=column_names,
-                include_columns=include_columns,
-                num_rows=num_rows,
-                has_header=has_header,
-                delimiter=delimiter,
+                convert_options=convert_options,
+                parse_options=parse_options,
+                read_options=read_options,
                 io_config=io_config,
                 multithreaded_io=multithreaded_io,
-                schema=schema._schema if schema is not None else None,
-                buffer_size=buffer_size,
-                chunk_size=chunk_size,
             )
         )
 
 
+def _csv_options_from_deprecated_kwargs(
+    method: str,
+    convert_options: CsvConvertOptions | None,
+    parse_options: CsvParseOptions | None,
+    read_options: CsvReadOptions | None,
+    column_names: list[str] | None = None,
+    include_columns: list[str] | None = None,
+    num_rows: int | None = None,
+    has_header: bool | None = None,
+    delimiter: str | None = None,
+    schema: Schema | None = None,
+    buffer_size: int | None = None,
+    chunk_size: int | None = None,
+) -> tuple[CsvConvertOptions | None, CsvParseOptions | None, CsvReadOptions | None]:
+    """Maps the keyword arguments that `read_csv` took before the CSV options classes existed onto those classes,
+    warning that they are deprecated.
+    """
+    convert_kwargs = {
+        "column_names": column_names,
+        "include_columns": include_columns,
+        "limit": num_rows,
+        "schema": schema._schema if schema is not None else None,
+    }
+    parse_kwargs = {"has_header": has_header, "delimiter": delimiter}
+    read_kwargs = {"buffer_size": buffer_size, "chunk_size": chunk_size}
+    deprecated = [
+        "num_rows" if name == "limit" else name
+        for kwargs in (convert_kwargs, parse_kwargs, read_kwargs)
+        for name, value in kwargs.items()
+        if value is not None
+    ]
+    if not deprecated:
+        return convert_options, parse_options, read_options
+    warnings.warn(
+        f"Passing {', '.join(deprecated)} to {method} is deprecated and will be removed in a future release; "
+        "use convert_options, parse_options and read_options instead",
+        DeprecationWarning,
+        stacklevel=3,
+    )
+
+    def resolve(options, options_name, options_cls, kwargs):
+        kwargs = {name: value for name, value in kwargs.items() if value is not None}
+        if not kwargs:
+            return options
+        if options is not None:
+            raise ValueError(f"Cannot pass both {options_name} and the deprecated {', '.join(kwargs)} to {method}")
+        return options_cls(**kwargs)
+
+    return (
+        resolve(convert_options, "convert_options", CsvConvertOptions, convert_kwargs),
+        resolve(parse_options, "parse_options", CsvParseOptions, parse_kwargs),
+        resolve(read_options, "read_options", CsvReadOptions, read_kwargs),
+    )
+
+
 def _trim_pyarrow_large_arrays(arr: pa.ChunkedArray) -> pa.ChunkedArray:
     if pa.types.is_large_binary(arr.type) or pa.types.is_large_string(arr.type):
         if pa.types.is_large_binary(arr.type):
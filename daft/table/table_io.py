@@ -14,7 +14,14 @@ from pyarrow import json as pajson
 from pyarrow import parquet as papq
 from pyarrow.fs import FileSystem
 
-from daft.daft import NativeStorageConfig, PythonStorageConfig, StorageConfig
+from daft.daft import (
+    CsvConvertOptions,
+    CsvParseOptions,
+    CsvReadOptions,
+    NativeStorageConfig,
+    PythonStorageConfig,
+    StorageConfig,
+)
 from daft.expressions import ExpressionsProjection
 from daft.filesystem import _resolve_paths_and_filesystem
 from daft.logical.schema import Schema
@@ -217,15 +224,18 @@ def read_csv(
             has_header = csv_options.header_index is not None
             tbl = Table.read_csv(
                 str(file),
-                column_names=schema.column_names() if not has_header else None,
-                include_columns=read_options.column_names,
-                num_rows=read_options.num_rows,
-                has_header=has_header,
-                delimiter=csv_options.delimiter,
+                convert_options=CsvConvertOptions(
+                    limit=read_options.num_rows,
+                    include_columns=read_options.column_names,
+                    column_names=schema.column_names() if not has_header else None,
+                    schema=schema._schema,
+                ),
+                parse_options=CsvParseOptions(
+                    has_header=has_header,
+                    delimiter=csv_options.delimiter,
+                ),
+                read_options=CsvReadOptions(buffer_size=csv_options.buffer_size, chunk_size=csv_options.chunk_size),
                 io_config=config.io_config,
-                schema=schema,
-                buffer_size=csv_options.buffer_size,
-                chunk_size=csv_options.chunk_size,
             )
             return _cast_table_to_schema(tbl, read_options=read_options, schema=schema)
 
@@ -19,6 +19,21 @@ except ImportError:
 UserProvidedPythonFunction = Callable[..., Series]
 
 
+def _to_pyseries(result, name: str, is_python_dtype: bool) -> PySeries:
+    """Post-processing of results into a Series, which is cast to the declared return dtype on the Rust side"""
+    if isinstance(result, Series):
+        return result.rename(name)._series
+    elif isinstance(result, list):
+        if is_python_dtype:
+            return Series.from_pylist(result, name=name, pyobj="force")._series
+        else:
+            return Series.from_pylist(result, name=name, pyobj="allow")._series
+    elif _NUMPY_AVAILABLE and isinstance(result, np.ndarray):
+        return Series.from_numpy(result, name=name)._series
+    else:
+        raise NotImplementedError(f"Return type not supported for UDF: {type(result)}")
+
+
 @dataclasses.dataclass(frozen=True)
 class PartialUDF:
     udf: UDF
@@ -29,7 +44,7 @@ class PartialUDF:
     def expressions(self) -> dict[str, Expression]:
         return {key: val for key, val in self.bound_args.arguments.items() if isinstance(val, Expression)}
 
-    def __call__(self, evaluated_expressions: list[Series]) -> PySeries:
+    def __call__(self, evaluated_expressions: list[Series]) -> PySeries | dict[str, PySeries]:
         kwarg_keys = list(self.bound_args.kwargs.keys())
         arg_keys = [k for k in self.bound_args.arguments.keys() if k not in self.bound_args.kwargs.keys()]
         pyvalues = {key: val for key, val in self.bound_args.arguments.items() if not isinstance(val, Expression)}
@@ -70,18 +85,14 @@ class PartialUDF:
         # and is quite error prone! If our Series naming logic here is wrong, things will break when the UDF is run on a table.
         name = evaluated_expressions[0].name()
 
-        # Post-processing of results into a Series of the appropriate dtype
-        if isinstance(result, Series):
-            return result.rename(name).cast(self.udf.return_dtype)._series
-        elif isinstance(result, list):
-            if self.udf.return_dtype == DataType.python():
-                return Series.from_pylist(result, name=name, pyobj="force")._series
-            else:
-                return Series.from_pylist(result, name=name, pyobj="allow").cast(self.udf.return_dtype)._series
-        elif _NUMPY_AVAILABLE and isinstance(result, np.ndarray):
-            return Series.from_numpy(result, name=name).cast(self.udf.return_dtype)._series
-        else:
-            raise NotImplementedError(f"Return type not supported for UDF: {type(result)}")
+        # UDFs with struct output may return a dict of field names to values, which is assembled into a struct Series
+        # on the Rust side
+        if self.udf.struct_output and isinstance(result, dict):
+            return {
+                field_name: _to_pyseries(value, field_name, is_python_dtype=False)
+                for field_name, value in result.items()
+            }
+        return _to_pyseries(result, name, is_python_dtype=self.udf.return_dtype == DataType.python())
 
     def __hash__(self) -> int:
         # Make the bound arguments hashable in the basic case when every argument is itself hashable.
@@ -96,6 +107,8 @@ class PartialUDF:
 class UDF:
     func: UserProvidedPythonFunction
     return_dtype: DataType
+    batch_size: int | None = None
+    struct_output: bool = False
 
     def __post_init__(self):
         """Analagous to the @functools.wraps(self.func) pattern
@@ -113,6 +126,8 @@ class UDF:
             func=partial_udf,
             expressions=expressions,
             return_dtype=self.return_dtype,
+            batch_size=self.batch_size,
+            struct_output=self.struct_output,
         )
 
     def bind_func(self, *args, **kwargs):
@@ -141,12 +156,14 @@ class UDF:
         raise NotImplementedError(f"UDF type not supported: {type(self.func)}")
 
     def __hash__(self) -> int:
-        return hash((self.func, self.return_dtype))
+        return hash((self.func, self.return_dtype, self.batch_size, self.struct_output))
 
 
 def udf(
     *,
     return_dtype: DataType,
+    batch_size: int | None = None,
+    struct_output: bool = False,
 ) -> Callable[[UserProvidedPythonFunction], UDF]:
     """Decorator to convert a Python function into a UDF
 
@@ -176,6 +193,11 @@ def udf(
 
     Args:
         return_dtype (DataType): Returned type of the UDF
+        batch_size (int | None): Maximum number of rows to pass to the UDF per call, e.g. to bound the memory used by each
+            call, or to batch inputs for GPU inference; if not provided, the UDF is called once on all rows of a partition
+        struct_output (bool): Whether the UDF returns multiple columns, as a struct Series or a dict of field names to
+            Series, lists or numpy arrays, which are unpacked into a column per field; ``return_dtype`` must be a struct
+            type declaring the names and types of the returned fields
 
     Returns:
         Callable[[UserProvidedPythonFunction], UDF]: UDF decorator - converts a user-provided Python function as a UDF that can be called on Expressions
@@ -185,6 +207,8 @@ def udf(
         return UDF(
             func=f,
             return_dtype=return_dtype,
+            batch_size=batch_size,
+            struct_output=struct_output,
         )
 
     return _udf
@@ -8,9 +8,9 @@ use crate::{
         DataArray,
     },
     datatypes::{
-        BinaryType, BooleanType, DaftArrowBackedType, DaftDataType, ExtensionArray, Field,
-        Float32Type, Float64Type, Int128Type, Int16Type, Int32Type, Int64Type, Int8Type, NullType,
-        UInt16Type, UInt32Type, UInt64Type, UInt8Type, Utf8Type,
+        BinaryType, BooleanType, DaftArrowBackedType, DaftDataType, DictionaryType, ExtensionType,
+        Field, Float32Type, Float64Type, Int128Type, Int16Type, Int32Type, Int64Type, Int8Type,
+        NullType, UInt16Type, UInt32Type, UInt64Type, UInt8Type, Utf8Type,
     },
     DataType, IntoSeries, Series,
 };
@@ -165,22 +165,29 @@ impl_arrow_backed_data_array_growable!(
     arrow2::array::growable::GrowableUtf8<'a, i64>
 );
 
-/// ExtensionTypes are slightly different, because they have a dynamic inner type
-pub struct ArrowExtensionGrowable<'a> {
+/// ExtensionTypes and DictionaryTypes are slightly different, because they have a dynamic inner type
+pub struct ArrowDynamicGrowable<'a, T: DaftArrowBackedType> {
     name: String,
     dtype: DataType,
     child_growable: Box<dyn arrow2::array::growable::Growable<'a> + 'a>,
+    _phantom: PhantomData<&'a T>,
 }
 
-impl<'a> ArrowExtensionGrowable<'a> {
+pub type ArrowExtensionGrowable<'a> = ArrowDynamicGrowable<'a, ExtensionType>;
+pub type ArrowDictionaryGrowable<'a> = ArrowDynamicGrowable<'a, DictionaryType>;
+
+impl<'a, T: DaftArrowBackedType> ArrowDynamicGrowable<'a, T> {
     pub fn new(
         name: &str,
         dtype: &DataType,
-        arrays: Vec<&'a ExtensionArray>,
+        arrays: Vec<&'a DataArray<T>>,
         use_validity: bool,
         capacity: usize,
     ) -> Self {
-        assert!(matches!(dtype, DataType::Extension(..)));
+        assert!(matches!(
+            dtype,
+            DataType::Extension(..) | DataType::Dictionary(..)
+        ));
         let child_ref_arrays = arrays.iter().map(|&a| a.data()).collect::<Vec<_>>();
         let child_growable = arrow2::array::growable::make_growable(
             child_ref_arrays.as_slice(),
@@ -191,11 +198,15 @@ impl<'a> ArrowExtensionGrowable<'a> {
             name: name.to_string(),
             dtype: dtype.clone(),
             child_growable,
+            _phantom: PhantomData,
         }
     }
 }
 
-impl<'a> Growable for ArrowExtensionGrowable<'a> {
+impl<'a, T: DaftArrowBackedType> Growable for ArrowDynamicGrowable<'a, T>
+where
+    DataArray<T>: IntoSeries,
+{
     #[inline]
     fn extend(&mut self, index: usize, start: usize, len: usize) {
         self.child_growable.extend(index, start, len)
@@ -208,6 +219,6 @@ impl<'a> Growable for ArrowExtensionGrowable<'a> {
     fn build(&mut self) -> DaftResult<Series> {
         let arr = self.child_growable.as_box();
         let field = Field::new(self.name.clone(), self.dtype.clone());
-        Ok(ExtensionArray::from_arrow(&field, arr)?.into_series())
+        Ok(DataArray::<T>::from_arrow(&field, arr)?.into_series())
     }
 }
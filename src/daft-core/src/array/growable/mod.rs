@@ -7,9 +7,9 @@ use crate::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeTensorArray, ImageArray, TensorArray, TimestampArray,
         },
-        BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int128Array,
-        Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
-        UInt64Array, UInt8Array, Utf8Array,
+        BinaryArray, BooleanArray, DictionaryArray, ExtensionArray, Float32Array, Float64Array,
+        Int128Array, Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array, Utf8Array,
     },
     with_match_daft_types, DataType, Series,
 };
@@ -181,6 +181,7 @@ impl_growable_array!(Float64Array, arrow_growable::ArrowFloat64Growable<'a>);
 impl_growable_array!(BinaryArray, arrow_growable::ArrowBinaryGrowable<'a>);
 impl_growable_array!(Utf8Array, arrow_growable::ArrowUtf8Growable<'a>);
 impl_growable_array!(ExtensionArray, arrow_growable::ArrowExtensionGrowable<'a>);
+impl_growable_array!(DictionaryArray, arrow_growable::ArrowDictionaryGrowable<'a>);
 impl_growable_array!(
     FixedSizeListArray,
     fixed_size_list_growable::FixedSizeListGrowable<'a>
@@ -5,7 +5,7 @@ use crate::{
     array::DataArray,
     datatypes::{
         logical::{DateArray, Decimal128Array, DurationArray, TimestampArray},
-        BinaryArray, BooleanArray, DaftNumericType, NullArray, Utf8Array,
+        BinaryArray, BooleanArray, DaftNumericType, DictionaryArray, NullArray, Utf8Array,
     },
 };
 
@@ -59,6 +59,7 @@ impl_asarrow_dataarray!(NullArray, array::NullArray);
 impl_asarrow_dataarray!(Utf8Array, array::Utf8Array<i64>);
 impl_asarrow_dataarray!(BooleanArray, array::BooleanArray);
 impl_asarrow_dataarray!(BinaryArray, array::BinaryArray<i64>);
+impl_asarrow_dataarray!(DictionaryArray, array::DictionaryArray<u32>);
 
 #[cfg(feature = "python")]
 impl_asarrow_dataarray!(PythonArray, PseudoArrowArray<pyo3::PyObject>);
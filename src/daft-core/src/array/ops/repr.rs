@@ -7,14 +7,14 @@ use crate::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeTensorArray, ImageArray, TensorArray, TimestampArray,
         },
-        BinaryArray, BooleanArray, DaftNumericType, ExtensionArray, ImageFormat, NullArray,
-        UInt64Array, Utf8Array,
+        BinaryArray, BooleanArray, DaftNumericType, DictionaryArray, ExtensionArray, ImageFormat,
+        NullArray, UInt64Array, Utf8Array,
     },
     with_match_daft_types, DataType, Series,
 };
 use common_error::DaftResult;
 
-use super::image::AsImageObj;
+use super::{as_arrow::AsArrow, image::AsImageObj};
 
 // Default implementation of str_value: format the value with the given format string.
 macro_rules! impl_array_str_value {
@@ -35,6 +35,17 @@ impl_array_str_value!(BooleanArray, "{}");
 impl_array_str_value!(ExtensionArray, "{:?}");
 impl_array_str_value!(DurationArray, "{}");
 
+impl DictionaryArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        let dictionary = self.as_arrow();
+        if !dictionary.is_valid(idx) {
+            return Ok("None".to_string());
+        }
+        let values = Series::try_from(("values", dictionary.values().clone()))?;
+        values.str_value(dictionary.key_value(idx))
+    }
+}
+
 fn pretty_print_bytes(bytes: &[u8], max_len: usize) -> DaftResult<String> {
     /// influenced by pythons bytes repr
     /// https://github.com/python/cpython/blob/main/Objects/bytesobject.c#L1336
@@ -355,6 +366,7 @@ impl_array_html_value!(ListArray);
 impl_array_html_value!(FixedSizeListArray);
 impl_array_html_value!(StructArray);
 impl_array_html_value!(ExtensionArray);
+impl_array_html_value!(DictionaryArray);
 impl_array_html_value!(Decimal128Array);
 impl_array_html_value!(DateArray);
 impl_array_html_value!(DurationArray);
@@ -5,11 +5,11 @@ use crate::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeTensorArray, ImageArray, TensorArray, TimestampArray,
         },
-        BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, Float32Array,
-        Float64Array, NullArray, Utf8Array,
+        BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, DictionaryArray,
+        ExtensionArray, Float32Array, Float64Array, NullArray, Utf8Array,
     },
     kernels::search_sorted::{build_compare_with_nulls, cmp_float},
-    series::Series,
+    series::{IntoSeries, Series},
 };
 use common_error::DaftResult;
 
@@ -586,6 +586,14 @@ impl ExtensionArray {
     }
 }
 
+impl DictionaryArray {
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        // Sort by the decoded values, keeping the dictionary encoding of the result.
+        let indices = self.clone().into_series().argsort(descending)?;
+        self.take(indices.u64()?)
+    }
+}
+
 #[cfg(feature = "python")]
 impl PythonArray {
     pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
@@ -8,8 +8,8 @@ use crate::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeTensorArray, ImageArray, TensorArray, TimestampArray,
         },
-        BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, NullArray,
-        Utf8Array,
+        BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, DictionaryArray,
+        ExtensionArray, NullArray, Utf8Array,
     },
     DataType,
 };
@@ -68,6 +68,7 @@ impl_dataarray_take!(BooleanArray);
 impl_dataarray_take!(BinaryArray);
 impl_dataarray_take!(NullArray);
 impl_dataarray_take!(ExtensionArray);
+impl_dataarray_take!(DictionaryArray);
 impl_logicalarray_take!(Decimal128Array);
 impl_logicalarray_take!(DateArray);
 impl_logicalarray_take!(DurationArray);
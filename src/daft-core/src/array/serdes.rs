@@ -5,7 +5,7 @@ use serde::ser::SerializeMap;
 use crate::{
     datatypes::{
         logical::LogicalArray, BinaryArray, BooleanArray, DaftLogicalType, DaftNumericType,
-        ExtensionArray, Int64Array, NullArray, Utf8Array,
+        DictionaryArray, ExtensionArray, Int64Array, NullArray, Utf8Array,
     },
     DataType, IntoSeries, Series,
 };
@@ -130,6 +130,25 @@ impl serde::Serialize for ExtensionArray {
     }
 }
 
+impl serde::Serialize for DictionaryArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_map(Some(2))?;
+        s.serialize_entry("field", self.field())?;
+        let DataType::Dictionary(values_dtype) = self.data_type() else {
+            return Err(serde::ser::Error::custom(format!(
+                "Expected Dictionary Type, got {}",
+                self.data_type()
+            )));
+        };
+        let values = self.cast(values_dtype).map_err(serde::ser::Error::custom)?;
+        s.serialize_entry("values", &values)?;
+        s.end()
+    }
+}
+
 #[cfg(feature = "python")]
 impl serde::Serialize for PythonArray {
     fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
@@ -30,6 +30,10 @@ impl DataType {
         // - the output type,
         // - the type at which the comparison should be performed.
         use DataType::*;
+        // Dictionary-encoded values are compared as their decoded values.
+        if let (Dictionary(values), other) | (other, Dictionary(values)) = (self, other) {
+            return values.comparison_op(other);
+        }
         match (self, other) {
             (s, o) if s == o => Ok(s.to_physical()),
             (s, o) if s.is_physical() && o.is_physical() => {
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result};
 
-use arrow2::datatypes::DataType as ArrowType;
+use arrow2::datatypes::{DataType as ArrowType, IntegerType};
 
 use crate::datatypes::{field::Field, image_mode::ImageMode, time_unit::TimeUnit};
 
@@ -78,6 +78,8 @@ pub enum DataType {
     Struct(Vec<Field>),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
+    /// Dictionary-encoded values of some data type, stored as [`u32`] keys into a dictionary of the distinct values.
+    Dictionary(Box<DataType>),
     // Stop ArrowTypes
     /// A logical type for embeddings.
     Embedding(Box<DataType>, usize),
@@ -168,6 +170,11 @@ impl DataType {
                 Box::new(dtype.to_arrow()?),
                 metadata.clone(),
             )),
+            DataType::Dictionary(values_dtype) => Ok(ArrowType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(values_dtype.to_arrow()?),
+                false,
+            )),
             DataType::Embedding(..)
             | DataType::Image(..)
             | DataType::FixedShapeImage(..)
@@ -324,6 +331,11 @@ impl DataType {
         matches!(self, DataType::Extension(..))
     }
 
+    #[inline]
+    pub fn is_dictionary(&self) -> bool {
+        matches!(self, DataType::Dictionary(..))
+    }
+
     #[inline]
     pub fn is_python(&self) -> bool {
         match self {
@@ -413,6 +425,9 @@ impl From<&ArrowType> for DataType {
                 let fields: Vec<Field> = fields.iter().map(|fld| fld.into()).collect();
                 DataType::Struct(fields)
             }
+            ArrowType::Dictionary(_, values, _) => {
+                DataType::Dictionary(Box::new(values.as_ref().into()))
+            }
             ArrowType::Extension(name, dtype, metadata) => {
                 if name == DAFT_SUPER_EXTENSION_NAME {
                     if let Some(metadata) = metadata {
@@ -461,6 +476,7 @@ impl Display for DataType {
                     .join(", ");
                 write!(f, "Struct[{fields}]")
             }
+            DataType::Dictionary(values) => write!(f, "Dictionary[{}]", values),
             DataType::Embedding(inner, size) => {
                 write!(f, "Embedding[{}; {}]", inner, size)
             }
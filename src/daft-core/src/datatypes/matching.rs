@@ -30,6 +30,7 @@ macro_rules! with_match_daft_types {(
         List(_) => __with_ty__! { ListType },
         Struct(_) => __with_ty__! { StructType },
         Extension(_, _, _) => __with_ty__! { ExtensionType },
+        Dictionary(..) => __with_ty__! { DictionaryType },
         #[cfg(feature = "python")]
         Python => __with_ty__! { PythonType },
         Embedding(..) => __with_ty__! { EmbeddingType },
@@ -78,6 +79,7 @@ macro_rules! with_match_physical_daft_types {(
         List(_) => __with_ty__! { ListType },
         Struct(_) => __with_ty__! { StructType },
         Extension(_, _, _) => __with_ty__! { ExtensionType },
+        Dictionary(..) => __with_ty__! { DictionaryType },
         #[cfg(feature = "python")]
         Python => __with_ty__! { PythonType },
         _ => panic!("{:?} not implemented for with_match_physical_daft_types", $key_type)
@@ -111,6 +113,7 @@ macro_rules! with_match_arrow_daft_types {(
         // Timestamp(_, _) => __with_ty__! { TimestampType },
         List(_) => __with_ty__! { ListType },
         Extension(_, _, _) => __with_ty__! { ExtensionType },
+        Dictionary(..) => __with_ty__! { DictionaryType },
         Utf8 => __with_ty__! { Utf8Type },
         _ => panic!("{:?} not implemented", $key_type)
     }
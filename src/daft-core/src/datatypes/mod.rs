@@ -165,6 +165,7 @@ impl_daft_arrow_datatype!(Float64Type, Float64);
 impl_daft_arrow_datatype!(BinaryType, Binary);
 impl_daft_arrow_datatype!(Utf8Type, Utf8);
 impl_daft_arrow_datatype!(ExtensionType, Unknown);
+impl_daft_arrow_datatype!(DictionaryType, Unknown);
 
 impl_nested_datatype!(FixedSizeListType, FixedSizeListArray);
 impl_nested_datatype!(StructType, StructArray);
@@ -327,6 +328,7 @@ pub type Float64Array = DataArray<Float64Type>;
 pub type BinaryArray = DataArray<BinaryType>;
 pub type Utf8Array = DataArray<Utf8Type>;
 pub type ExtensionArray = DataArray<ExtensionType>;
+pub type DictionaryArray = DataArray<DictionaryType>;
 
 #[cfg(feature = "python")]
 pub type PythonArray = DataArray<PythonType>;
@@ -10,6 +10,7 @@ pub mod kernels;
 pub mod python;
 pub mod schema;
 pub mod series;
+pub mod sql_dialect;
 pub mod utils;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
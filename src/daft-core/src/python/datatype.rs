@@ -216,6 +216,11 @@ impl PyDataType {
         .into())
     }
 
+    #[staticmethod]
+    pub fn dictionary(values_data_type: Self) -> PyResult<Self> {
+        Ok(DataType::Dictionary(Box::new(values_data_type.dtype)).into())
+    }
+
     #[staticmethod]
     pub fn embedding(data_type: Self, size: i64) -> PyResult<Self> {
         if size <= 0 {
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
@@ -11,6 +11,7 @@ use super::field::PyField;
 use crate::datatypes;
 use crate::impl_bincode_py_state_serialization;
 use crate::schema;
+use crate::sql_dialect::SqlDialect;
 
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,6 +59,12 @@ impl PySchema {
         })
     }
 
+    pub fn to_sql_ddl(&self, table_name: &str, dialect: &str) -> PyResult<String> {
+        Ok(self
+            .schema
+            .to_sql_ddl(table_name, SqlDialect::from_str(dialect)?)?)
+    }
+
     pub fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{}", self.schema))
     }
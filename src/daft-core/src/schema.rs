@@ -8,7 +8,7 @@ use std::{
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::datatypes::Field;
+use crate::{datatypes::Field, sql_dialect::SqlDialect};
 
 use common_error::{DaftError, DaftResult};
 
@@ -127,6 +127,29 @@ impl Schema {
             .collect::<Vec<String>>()
             .join(", ")
     }
+
+    /// Produces a `CREATE TABLE` statement for a table named `table_name` with this schema, in the provided SQL dialect.
+    ///
+    /// Column names are quoted, while `table_name` is emitted as-is so that it may be qualified, e.g. `db.table`. All
+    /// columns are nullable, as Daft columns may always contain nulls.
+    pub fn to_sql_ddl(&self, table_name: &str, dialect: SqlDialect) -> DaftResult<String> {
+        let columns = self
+            .fields
+            .iter()
+            .map(|(name, field)| {
+                Ok(format!(
+                    "  {} {}",
+                    dialect.quote_identifier(name),
+                    dialect.column_type(&field.dtype)?
+                ))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(format!(
+            "CREATE TABLE {} (\n{}\n)",
+            table_name,
+            columns.join(",\n")
+        ))
+    }
 }
 
 impl Eq for Schema {}
@@ -17,8 +17,9 @@ use crate::datatypes::logical::{
     ImageArray, TensorArray, TimestampArray,
 };
 use crate::datatypes::{
-    BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int16Array, Int32Array,
-    Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array, Utf8Array,
+    BinaryArray, BooleanArray, DictionaryArray, ExtensionArray, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
+    UInt64Array, UInt8Array, Utf8Array,
 };
 
 use super::{ArrayWrapper, IntoSeries, Series};
@@ -220,6 +221,7 @@ impl SeriesBinaryOps for ArrayWrapper<FixedSizeListArray> {}
 impl SeriesBinaryOps for ArrayWrapper<ListArray> {}
 impl SeriesBinaryOps for ArrayWrapper<StructArray> {}
 impl SeriesBinaryOps for ArrayWrapper<ExtensionArray> {}
+impl SeriesBinaryOps for ArrayWrapper<DictionaryArray> {}
 impl SeriesBinaryOps for ArrayWrapper<Decimal128Array> {}
 impl SeriesBinaryOps for ArrayWrapper<DateArray> {}
 impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
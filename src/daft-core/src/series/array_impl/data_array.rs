@@ -12,9 +12,9 @@ use crate::datatypes::PythonArray;
 use crate::series::array_impl::binary_ops::SeriesBinaryOps;
 use crate::{
     datatypes::{
-        BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int128Array,
-        Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
-        UInt64Array, UInt8Array, Utf8Array,
+        BinaryArray, BooleanArray, DictionaryArray, ExtensionArray, Float32Array, Float64Array,
+        Int128Array, Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array, Utf8Array,
     },
     series::series_like::SeriesLike,
     with_match_integer_daft_types,
@@ -215,5 +215,6 @@ impl_series_like_for_data_array!(Float32Array);
 impl_series_like_for_data_array!(Float64Array);
 impl_series_like_for_data_array!(Utf8Array);
 impl_series_like_for_data_array!(ExtensionArray);
+impl_series_like_for_data_array!(DictionaryArray);
 #[cfg(feature = "python")]
 impl_series_like_for_data_array!(PythonArray);
@@ -69,6 +69,10 @@ impl Series {
         self.inner.field()
     }
     pub fn as_physical(&self) -> DaftResult<Series> {
+        // Dictionary-encoded Series are decoded, since kernels operate on the values rather than their keys.
+        if let DataType::Dictionary(values_dtype) = self.data_type() {
+            return self.inner.cast(values_dtype)?.as_physical();
+        }
         let physical_dtype = self.data_type().to_physical();
         if &physical_dtype == self.data_type() {
             Ok(self.clone())
@@ -154,6 +154,13 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .unwrap()
                             .into_series())
                     }
+                    Dictionary(..) => {
+                        let values = map.next_value::<Series>()?;
+                        Ok(values
+                            .cast(&field.dtype)
+                            .map_err(serde::de::Error::custom)?
+                            .rename(&field.name))
+                    }
                     Struct(..) => {
                         let mut all_series = map.next_value::<Vec<Option<Series>>>()?;
                         let validity = all_series
@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+
+use crate::datatypes::{DataType, TimeUnit};
+
+/// SQL dialects that Daft schemas can be converted to DDL for.
+///
+/// | Postgres  - PostgreSQL.
+/// | MySql     - MySQL.
+/// | Snowflake - Snowflake.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    Postgres,
+    MySql,
+    Snowflake,
+}
+
+impl SqlDialect {
+    pub fn iterator() -> std::slice::Iter<'static, SqlDialect> {
+        use SqlDialect::*;
+
+        static SQL_DIALECTS: [SqlDialect; 3] = [Postgres, MySql, Snowflake];
+        SQL_DIALECTS.iter()
+    }
+
+    /// The SQL column type of this dialect that holds values of the provided Daft type.
+    pub fn column_type(&self, dtype: &DataType) -> DaftResult<String> {
+        use DataType::*;
+        use SqlDialect::*;
+
+        let column_type = match (self, dtype) {
+            (Postgres, Null | Utf8) => "TEXT".to_string(),
+            (MySql, Null | Utf8) => "LONGTEXT".to_string(),
+            (Snowflake, Null | Utf8) => "VARCHAR".to_string(),
+            (_, Boolean) => "BOOLEAN".to_string(),
+            (Postgres, Int8 | Int16 | UInt8) => "SMALLINT".to_string(),
+            (Postgres, Int32 | UInt16) => "INTEGER".to_string(),
+            (Postgres, Int64 | UInt32) => "BIGINT".to_string(),
+            (Postgres, UInt64) => "NUMERIC(20, 0)".to_string(),
+            (MySql, Int8) => "TINYINT".to_string(),
+            (MySql, Int16) => "SMALLINT".to_string(),
+            (MySql, Int32) => "INT".to_string(),
+            (MySql, Int64) => "BIGINT".to_string(),
+            (MySql, UInt8) => "TINYINT UNSIGNED".to_string(),
+            (MySql, UInt16) => "SMALLINT UNSIGNED".to_string(),
+            (MySql, UInt32) => "INT UNSIGNED".to_string(),
+            (MySql, UInt64) => "BIGINT UNSIGNED".to_string(),
+            (Snowflake, Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64) => {
+                "NUMBER(38, 0)".to_string()
+            }
+            (Postgres | MySql, Int128) => "DECIMAL(39, 0)".to_string(),
+            (Postgres, Float32) => "REAL".to_string(),
+            (Postgres, Float64) => "DOUBLE PRECISION".to_string(),
+            (MySql, Float32) => "FLOAT".to_string(),
+            (MySql, Float64) => "DOUBLE".to_string(),
+            (Snowflake, Float32 | Float64) => "FLOAT".to_string(),
+            (Postgres | MySql, Decimal128(precision, scale)) => {
+                format!("DECIMAL({precision}, {scale})")
+            }
+            (Snowflake, Decimal128(precision, scale)) if *precision <= 38 => {
+                format!("NUMBER({precision}, {scale})")
+            }
+            (Postgres, Timestamp(_, None)) => "TIMESTAMP".to_string(),
+            (Postgres, Timestamp(_, Some(_))) => "TIMESTAMPTZ".to_string(),
+            (MySql, Timestamp(unit, _)) => format!("DATETIME({})", fractional_digits(unit)),
+            (Snowflake, Timestamp(unit, None)) => {
+                format!("TIMESTAMP_NTZ({})", fractional_digits(unit))
+            }
+            (Snowflake, Timestamp(unit, Some(_))) => {
+                format!("TIMESTAMP_TZ({})", fractional_digits(unit))
+            }
+            (_, Date) => "DATE".to_string(),
+            (Postgres, Time(_)) => "TIME".to_string(),
+            (MySql | Snowflake, Time(unit)) => format!("TIME({})", fractional_digits(unit)),
+            (Postgres, Duration(_)) => "INTERVAL".to_string(),
+            (Postgres, Binary) => "BYTEA".to_string(),
+            (MySql, Binary) => "LONGBLOB".to_string(),
+            (Snowflake, Binary) => "BINARY".to_string(),
+            (Postgres, List(_) | FixedSizeList(..) | Struct(_)) => "JSONB".to_string(),
+            (MySql, List(_) | FixedSizeList(..) | Struct(_)) => "JSON".to_string(),
+            (Snowflake, List(_) | FixedSizeList(..)) => "ARRAY".to_string(),
+            (Snowflake, Struct(_)) => "OBJECT".to_string(),
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                    "Daft type {dtype} has no equivalent column type in the {self} SQL dialect"
+                )))
+            }
+        };
+        Ok(column_type)
+    }
+
+    /// Quotes an identifier, e.g. a column name, so that it may contain any character.
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", identifier.replace('`', "``")),
+            SqlDialect::Postgres | SqlDialect::Snowflake => {
+                format!("\"{}\"", identifier.replace('"', "\"\""))
+            }
+        }
+    }
+}
+
+/// Number of fractional second digits needed to hold a temporal value of the provided time unit.
+fn fractional_digits(unit: &TimeUnit) -> u8 {
+    match unit {
+        TimeUnit::Seconds => 0,
+        TimeUnit::Milliseconds => 3,
+        TimeUnit::Microseconds => 6,
+        TimeUnit::Nanoseconds => 9,
+    }
+}
+
+impl FromStr for SqlDialect {
+    type Err = DaftError;
+
+    fn from_str(dialect: &str) -> DaftResult<Self> {
+        use SqlDialect::*;
+
+        match dialect.to_lowercase().as_str() {
+            "postgres" | "postgresql" => Ok(Postgres),
+            "mysql" => Ok(MySql),
+            "snowflake" => Ok(Snowflake),
+            _ => Err(DaftError::ValueError(format!(
+                "SQL dialect {} is not supported; only the following dialects are supported: {:?}",
+                dialect,
+                SqlDialect::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for SqlDialect {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    use super::SqlDialect;
+
+    #[test]
+    fn test_iris_tiny_postgres_ddl() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("sepal.length", DataType::Float64),
+            Field::new("sepal.width", DataType::Float64),
+            Field::new("petal.length", DataType::Float64),
+            Field::new("petal.width", DataType::Float64),
+            Field::new("variety", DataType::Utf8),
+        ])?;
+
+        let ddl = schema.to_sql_ddl("iris", SqlDialect::Postgres)?;
+        assert_eq!(
+            ddl,
+            "CREATE TABLE iris (\n  \"sepal.length\" DOUBLE PRECISION,\n  \"sepal.width\" DOUBLE PRECISION,\n  \
+             \"petal.length\" DOUBLE PRECISION,\n  \"petal.width\" DOUBLE PRECISION,\n  \"variety\" TEXT\n)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_type_errors() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("image", DataType::Image(None))])?;
+
+        assert!(schema.to_sql_ddl("images", SqlDialect::MySql).is_err());
+
+        Ok(())
+    }
+}
@@ -76,6 +76,21 @@ fn coerce_to_daft_compatible_type(
                 Some(arrow2::datatypes::DataType::Struct(new_fields))
             }
         }
+        arrow2::datatypes::DataType::Dictionary(key, values, is_sorted) => {
+            let new_values_dtype = coerce_to_daft_compatible_type(values.as_ref());
+            if *key == arrow2::datatypes::IntegerType::UInt32
+                && !is_sorted
+                && new_values_dtype.is_none()
+            {
+                None
+            } else {
+                Some(arrow2::datatypes::DataType::Dictionary(
+                    arrow2::datatypes::IntegerType::UInt32,
+                    Box::new(new_values_dtype.unwrap_or_else(|| values.as_ref().clone())),
+                    false,
+                ))
+            }
+        }
         arrow2::datatypes::DataType::Extension(name, inner, metadata) => {
             let new_inner_dtype = coerce_to_daft_compatible_type(inner.as_ref())?;
             REGISTRY.lock().unwrap().insert(name.clone(), dtype.clone());
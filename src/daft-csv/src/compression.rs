@@ -1,18 +1,25 @@
 use async_compression::tokio::bufread::{
-    BrotliDecoder, BzDecoder, DeflateDecoder, GzipDecoder, LzmaDecoder, XzDecoder, ZlibDecoder,
-    ZstdDecoder,
+    BrotliDecoder, BrotliEncoder, BzDecoder, BzEncoder, DeflateDecoder, DeflateEncoder,
+    GzipDecoder, GzipEncoder, Lz4Decoder, Lz4Encoder, LzmaDecoder, LzmaEncoder, XzDecoder,
+    XzEncoder, ZlibDecoder, ZlibEncoder, ZstdDecoder, ZstdEncoder,
 };
+use common_error::{DaftError, DaftResult};
 use std::{path::PathBuf, pin::Pin};
 use tokio::io::{AsyncBufRead, AsyncRead};
+use tokio_util::io::StreamReader;
 use url::Url;
 
+use crate::snappy;
+
 #[derive(Debug)]
 pub enum CompressionCodec {
     Brotli,
     Bz,
     Deflate,
     Gzip,
+    Lz4,
     Lzma,
+    Snappy,
     Xz,
     Zlib,
     Zstd,
@@ -20,17 +27,23 @@ pub enum CompressionCodec {
 
 impl CompressionCodec {
     pub fn from_uri(uri: &str) -> Option<Self> {
-        let url = Url::parse(uri);
-        let path = match &url {
-            Ok(url) => url.path(),
-            _ => uri,
-        };
-        let extension = PathBuf::from(path)
-            .extension()?
-            .to_string_lossy()
-            .to_string();
-        Self::from_extension(extension.as_ref())
+        Self::from_extension(uri_extension(uri)?.as_ref())
     }
+
+    /// Like [`Self::from_uri`], but errors on the extensions of compressions that aren't supported, e.g. `.csv.lzo`,
+    /// rather than reading such files as uncompressed data. URIs without a compression extension are `None`.
+    pub fn try_from_uri(uri: &str) -> DaftResult<Option<Self>> {
+        match uri_extension(uri)
+            .as_deref()
+            .and_then(unsupported_compression)
+        {
+            Some(compression) => Err(DaftError::ValueError(format!(
+                "{compression} compression is not supported for CSV files, but got {uri}"
+            ))),
+            None => Ok(Self::from_uri(uri)),
+        }
+    }
+
     pub fn from_extension(extension: &str) -> Option<Self> {
         use CompressionCodec::*;
         match extension {
@@ -38,11 +51,32 @@ impl CompressionCodec {
             "bz2" => Some(Bz),
             "deflate" => Some(Deflate),
             "gz" => Some(Gzip),
+            "lz4" => Some(Lz4),
             "lzma" => Some(Lzma),
+            "snappy" | "sz" => Some(Snappy),
             "xz" => Some(Xz),
             "zl" => Some(Zlib),
             "zstd" | "zst" => Some(Zstd),
-            "snappy" => todo!("Snappy compression support not yet implemented"),
+            _ => None,
+        }
+    }
+
+    /// Detects the codec of a compressed stream from its leading bytes, e.g. for compressed files that lack a
+    /// compression extension. Brotli and raw deflate streams have no magic bytes, so they can't be detected.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Option<Self> {
+        use CompressionCodec::*;
+        match bytes {
+            [0x1f, 0x8b, ..] => Some(Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(Zstd),
+            [0x04, 0x22, 0x4d, 0x18, ..] => Some(Lz4),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(Xz),
+            // "BZh", the block size, and the magic of the first block, to avoid mistaking text for bzip2.
+            [b'B', b'Z', b'h', b'1'..=b'9', 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, ..] => Some(Bz),
+            [0x5d, 0x00, 0x00, ..] => Some(Lzma),
+            // The stream identifier chunk of the Snappy framing format.
+            [0xff, 0x06, 0x00, 0x00, b's', b'N', b'a', b'P', b'p', b'Y', ..] => Some(Snappy),
+            // Only the zlib headers whose second byte isn't printable, to avoid mistaking text starting with "x" for zlib.
+            [0x78, 0x01 | 0x9c | 0xda, ..] => Some(Zlib),
             _ => None,
         }
     }
@@ -56,11 +90,94 @@ impl CompressionCodec {
             Brotli => Box::pin(BrotliDecoder::new(reader)),
             Bz => Box::pin(BzDecoder::new(reader)),
             Deflate => Box::pin(DeflateDecoder::new(reader)),
-            Gzip => Box::pin(GzipDecoder::new(reader)),
+            Gzip => {
+                // Concatenated gzip files are decompressed member by member into one contiguous stream.
+                let mut decoder = GzipDecoder::new(reader);
+                decoder.multiple_members(true);
+                Box::pin(decoder)
+            }
+            Lz4 => Box::pin(Lz4Decoder::new(reader)),
             Lzma => Box::pin(LzmaDecoder::new(reader)),
+            Snappy => Box::pin(StreamReader::new(Box::pin(snappy::decode_framed(reader)))),
             Xz => Box::pin(XzDecoder::new(reader)),
             Zlib => Box::pin(ZlibDecoder::new(reader)),
             Zstd => Box::pin(ZstdDecoder::new(reader)),
         }
     }
+
+    pub fn to_encoder<T: AsyncBufRead + Send + 'static>(
+        &self,
+        reader: T,
+    ) -> Pin<Box<dyn AsyncRead + Send>> {
+        use CompressionCodec::*;
+        match self {
+            Brotli => Box::pin(BrotliEncoder::new(reader)),
+            Bz => Box::pin(BzEncoder::new(reader)),
+            Deflate => Box::pin(DeflateEncoder::new(reader)),
+            Gzip => Box::pin(GzipEncoder::new(reader)),
+            Lz4 => Box::pin(Lz4Encoder::new(reader)),
+            Lzma => Box::pin(LzmaEncoder::new(reader)),
+            Snappy => Box::pin(StreamReader::new(Box::pin(snappy::encode_framed(reader)))),
+            Xz => Box::pin(XzEncoder::new(reader)),
+            Zlib => Box::pin(ZlibEncoder::new(reader)),
+            Zstd => Box::pin(ZstdEncoder::new(reader)),
+        }
+    }
+}
+
+fn uri_extension(uri: &str) -> Option<String> {
+    let url = Url::parse(uri);
+    let path = match &url {
+        Ok(url) => url.path(),
+        _ => uri,
+    };
+    Some(
+        PathBuf::from(path)
+            .extension()?
+            .to_string_lossy()
+            .to_string(),
+    )
+}
+
+/// Name of the compression of a known compression extension that isn't supported.
+fn unsupported_compression(extension: &str) -> Option<&'static str> {
+    match extension {
+        "lzo" | "lzop" => Some("LZO"),
+        "Z" => Some("Unix compress"),
+        "zip" => Some("Zip"),
+        "7z" => Some("7-Zip"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use super::CompressionCodec;
+
+    #[test]
+    fn test_try_from_uri_supported_extension() -> DaftResult<()> {
+        assert!(matches!(
+            CompressionCodec::try_from_uri("s3://bucket/data.csv.gz")?,
+            Some(CompressionCodec::Gzip)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_uri_unsupported_extension() {
+        let err = CompressionCodec::try_from_uri("s3://bucket/data.csv.lzo").unwrap_err();
+        assert!(
+            matches!(&err, DaftError::ValueError(message) if message.contains("LZO compression is not supported")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_try_from_uri_plain_extension() -> DaftResult<()> {
+        assert!(CompressionCodec::try_from_uri("/tmp/data.csv")?.is_none());
+        assert!(CompressionCodec::try_from_uri("s3://bucket/data")?.is_none());
+        Ok(())
+    }
 }
@@ -9,6 +9,8 @@ use arrow2::{
 use chrono::{Datelike, Timelike};
 use csv_async::ByteRecord;
 
+use crate::{CsvParseOptions, NullByteHandling};
+
 pub(crate) const ISO8601: &str = "%+";
 pub(crate) const ISO8601_NO_TIME_ZONE: &str = "%Y-%m-%dT%H:%M:%S%.f";
 pub(crate) const ISO8601_NO_TIME_ZONE_NO_FRACTIONAL: &str = "%Y-%m-%dT%H:%M:%S";
@@ -65,48 +67,42 @@ where
     Box::new(PrimitiveArray::<T>::from_trusted_len_iter(iter).to(datatype))
 }
 
+/// Splits a plain decimal number, e.g. `-123.45`, into its sign, integer digits (without leading zeros), and
+/// fractional digits, or returns `None` if `bytes` isn't a plain decimal number.
 #[inline]
-fn significant_bytes(bytes: &[u8]) -> usize {
-    bytes.iter().map(|byte| (*byte != b'0') as usize).sum()
+pub(crate) fn split_decimal(bytes: &[u8]) -> Option<(bool, &[u8], &[u8])> {
+    let (negative, digits) = match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, bytes),
+    };
+    let mut parts = digits.splitn(2, |byte| *byte == b'.');
+    let lhs = parts.next().unwrap_or_default();
+    let rhs = parts.next().unwrap_or_default();
+    if (lhs.is_empty() && rhs.is_empty()) || !lhs.iter().chain(rhs).all(u8::is_ascii_digit) {
+        return None;
+    }
+    let leading_zeros = lhs.iter().take_while(|byte| **byte == b'0').count();
+    Some((negative, &lhs[leading_zeros..], rhs))
 }
 
-/// Deserializes bytes to a single i128 representing a decimal
-/// The decimal precision and scale are not checked.
+/// Deserializes bytes to a single i128 representing a decimal with the given precision and scale, i.e. the number
+/// scaled by `10^scale`. Returns `None` if the number doesn't fit the precision and scale.
 #[inline]
 fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
-    let mut a = bytes.split(|x| *x == b'.');
-    let lhs = a.next();
-    let rhs = a.next();
-    match (lhs, rhs) {
-        (Some(lhs), Some(rhs)) => lexical_core::parse::<i128>(lhs).ok().and_then(|x| {
-            lexical_core::parse::<i128>(rhs)
-                .ok()
-                .map(|y| (x, lhs, y, rhs))
-                .and_then(|(lhs, lhs_b, rhs, rhs_b)| {
-                    let lhs_s = significant_bytes(lhs_b);
-                    let rhs_s = significant_bytes(rhs_b);
-                    if lhs_s + rhs_s > precision || rhs_s > scale {
-                        None
-                    } else {
-                        Some((lhs, rhs, rhs_s))
-                    }
-                })
-                .map(|(lhs, rhs, rhs_s)| lhs * 10i128.pow(rhs_s as u32) + rhs)
-        }),
-        (None, Some(rhs)) => {
-            if rhs.len() != precision || rhs.len() != scale {
-                return None;
-            }
-            lexical_core::parse::<i128>(rhs).ok()
-        }
-        (Some(lhs), None) => {
-            if lhs.len() != precision || scale != 0 {
-                return None;
-            }
-            lexical_core::parse::<i128>(lhs).ok()
-        }
-        (None, None) => None,
+    let (negative, lhs, mut rhs) = split_decimal(bytes)?;
+    // Trailing zeros of the fractional part don't need to fit in the scale.
+    while rhs.len() > scale && rhs.last() == Some(&b'0') {
+        rhs = &rhs[..rhs.len() - 1];
+    }
+    if rhs.len() > scale || lhs.len() + scale > precision {
+        return None;
     }
+    let value = lhs.iter().chain(rhs).try_fold(0i128, |acc, digit| {
+        acc.checked_mul(10)?.checked_add((digit - b'0') as i128)
+    })?;
+    let value = value.checked_mul(10i128.checked_pow((scale - rhs.len()) as u32)?)?;
+    Some(if negative { -value } else { value })
 }
 
 #[inline]
@@ -163,7 +159,11 @@ fn deserialize_naive_datetime(string: &str, fmt_idx: &mut usize) -> Option<chron
             return Some(dt);
         }
     }
-    None
+    // Pure dates in a timestamp column are read as midnight of that date.
+    string
+        .parse::<chrono::NaiveDate>()
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
 }
 
 #[inline]
@@ -188,6 +188,292 @@ fn deserialize_datetime<T: chrono::TimeZone>(
     None
 }
 
+/// Tokens of boolean fields: the user-provided true and false tokens, e.g. `Y` and `N`, or a case-insensitive "true"
+/// and "false" if no tokens are provided.
+#[derive(Clone, Debug)]
+pub(crate) struct BooleanTokens {
+    true_values: Option<Vec<String>>,
+    false_values: Option<Vec<String>>,
+}
+
+impl BooleanTokens {
+    pub(crate) fn new(true_values: Option<Vec<String>>, false_values: Option<Vec<String>>) -> Self {
+        Self {
+            true_values,
+            false_values,
+        }
+    }
+
+    /// Parses `bytes` as a boolean, or returns `None` if they aren't one of the tokens.
+    pub(crate) fn parse(&self, bytes: &[u8]) -> Option<bool> {
+        if self.true_values.is_none() && self.false_values.is_none() {
+            return if bytes.eq_ignore_ascii_case(b"true") {
+                Some(true)
+            } else if bytes.eq_ignore_ascii_case(b"false") {
+                Some(false)
+            } else {
+                None
+            };
+        }
+        let matches = |tokens: &Option<Vec<String>>| {
+            tokens
+                .as_ref()
+                .map_or(false, |tokens| tokens.iter().any(|t| t.as_bytes() == bytes))
+        };
+        if matches(&self.true_values) {
+            Some(true)
+        } else if matches(&self.false_values) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Normalization applied to the raw bytes of numeric fields before they're parsed, e.g. for accounting exports that
+/// write `(1,234.56)` for `-1234.56`.
+#[derive(Clone, Debug)]
+pub(crate) struct NumericNormalizer {
+    thousands_separator: Option<u8>,
+    parentheses_as_negative: bool,
+}
+
+impl NumericNormalizer {
+    /// Returns a normalizer for the provided settings, or `None` if they would leave numeric fields unchanged.
+    pub(crate) fn try_new(
+        thousands_separator: Option<u8>,
+        parentheses_as_negative: bool,
+    ) -> Option<Self> {
+        if thousands_separator.is_none() && !parentheses_as_negative {
+            return None;
+        }
+        Some(Self {
+            thousands_separator,
+            parentheses_as_negative,
+        })
+    }
+
+    fn normalize(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut normalized = Vec::with_capacity(bytes.len());
+        let bytes = match bytes {
+            [b'(', inner @ .., b')'] if self.parentheses_as_negative => {
+                normalized.push(b'-');
+                inner
+            }
+            _ => bytes,
+        };
+        match self.thousands_separator {
+            Some(sep) => normalized.extend(bytes.iter().filter(|b| **b != sep)),
+            None => normalized.extend_from_slice(bytes),
+        }
+        normalized
+    }
+}
+
+/// User-provided chrono formats for temporal fields that the default formats don't recognize, e.g. `%Y/%m/%d %H:%M`.
+#[derive(Clone, Debug)]
+pub(crate) struct TemporalFormats {
+    pub(crate) date_format: Option<String>,
+    pub(crate) timestamp_format: Option<String>,
+}
+
+impl TemporalFormats {
+    /// Returns the formats for the provided settings, or `None` if no format is provided.
+    pub(crate) fn try_new(
+        date_format: Option<String>,
+        timestamp_format: Option<String>,
+    ) -> Option<Self> {
+        if date_format.is_none() && timestamp_format.is_none() {
+            return None;
+        }
+        Some(Self {
+            date_format,
+            timestamp_format,
+        })
+    }
+
+    /// Deserializes `column` of `rows` with the user-provided format for `datatype`, if any. Fields that match
+    /// neither the user-provided format nor the default formats are read as null.
+    fn deserialize_column<B: ByteRecordGeneric>(
+        &self,
+        rows: &[B],
+        column: usize,
+        datatype: &DataType,
+        null_values: Option<&[String]>,
+    ) -> Option<Box<dyn Array>> {
+        match (datatype, &self.date_format, &self.timestamp_format) {
+            (DataType::Date32, Some(fmt), _) => Some(deserialize_primitive(
+                rows,
+                column,
+                datatype.clone(),
+                |bytes| {
+                    to_non_null_utf8(bytes, null_values)
+                        .and_then(|s| {
+                            chrono::NaiveDate::parse_from_str(s, fmt)
+                                .ok()
+                                .or_else(|| s.parse::<chrono::NaiveDate>().ok())
+                        })
+                        .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE)
+                },
+            )),
+            (DataType::Timestamp(time_unit, None), _, Some(fmt)) => {
+                let mut last_fmt_idx = 0;
+                Some(deserialize_primitive(
+                    rows,
+                    column,
+                    datatype.clone(),
+                    |bytes| {
+                        to_non_null_utf8(bytes, null_values)
+                            .and_then(|s| {
+                                chrono::NaiveDateTime::parse_from_str(s, fmt)
+                                    .ok()
+                                    .or_else(|| deserialize_naive_datetime(s, &mut last_fmt_idx))
+                            })
+                            .and_then(|dt| naive_datetime_to_timestamp(dt, *time_unit))
+                    },
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[inline]
+fn to_non_null_utf8<'a>(bytes: &'a [u8], null_values: Option<&[String]>) -> Option<&'a str> {
+    if is_null_value(bytes, null_values) {
+        None
+    } else {
+        to_utf8(bytes)
+    }
+}
+
+#[inline]
+fn naive_datetime_to_timestamp(dt: chrono::NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    match time_unit {
+        TimeUnit::Second => Some(dt.timestamp()),
+        TimeUnit::Millisecond => Some(dt.timestamp_millis()),
+        TimeUnit::Microsecond => Some(dt.timestamp_micros()),
+        TimeUnit::Nanosecond => dt.timestamp_nanos_opt(),
+    }
+}
+
+#[inline]
+fn is_numeric(datatype: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        datatype,
+        Int8 | Int16
+            | Int32
+            | Int64
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Float32
+            | Float64
+            | Decimal(..)
+    )
+}
+
+/// A single normalized field, exposed as a one-field record so that it can be fed to [`deserialize_column`].
+struct NormalizedField(Option<Vec<u8>>);
+
+impl ByteRecordGeneric for NormalizedField {
+    #[inline]
+    fn get(&self, _index: usize) -> Option<&[u8]> {
+        self.0.as_deref()
+    }
+}
+
+/// Returns whether `bytes` is exactly one of the `null_values` tokens.
+pub(crate) fn is_null_value(bytes: &[u8], null_values: Option<&[String]>) -> bool {
+    null_values.map_or(false, |tokens| {
+        tokens.iter().any(|token| token.as_bytes() == bytes)
+    })
+}
+
+/// Deserializes `column` of `rows` like [`deserialize_column`], but honoring the null byte handling of `parse_options`
+/// and the `boolean_tokens`, reading fields that exactly match one of the `null_values` tokens as null, first applying `normalizer` to the
+/// fields of numeric columns, and parsing temporal columns with the user-provided `temporal_formats`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn deserialize_column_with_options<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    datatype: DataType,
+    parse_options: &CsvParseOptions,
+    boolean_tokens: &BooleanTokens,
+    null_values: Option<&[String]>,
+    normalizer: Option<&NumericNormalizer>,
+    temporal_formats: Option<&TemporalFormats>,
+    line_number: usize,
+) -> Result<Box<dyn Array>> {
+    if let Some(row) = rows
+        .iter()
+        .find(|row| row.get(column).map_or(false, |bytes| bytes.contains(&0)))
+    {
+        if parse_options.null_byte_handling == NullByteHandling::Error {
+            return Err(Error::ExternalFormat(format!(
+                "CSV field {:?} in column {} contains a null byte; set null_byte_handling to strip or replace null bytes",
+                String::from_utf8_lossy(row.get(column).unwrap_or_default()),
+                column
+            )));
+        }
+        // Clean the column once, so that the cleaned fields go through the regular deserialization below.
+        let cleaned = rows
+            .iter()
+            .map(|row| {
+                NormalizedField(
+                    row.get(column)
+                        .map(|bytes| parse_options.null_byte_handling.clean(bytes)),
+                )
+            })
+            .collect::<Vec<_>>();
+        return deserialize_column_with_options(
+            cleaned.as_slice(),
+            0,
+            datatype,
+            parse_options,
+            boolean_tokens,
+            null_values,
+            normalizer,
+            temporal_formats,
+            line_number,
+        );
+    }
+    if datatype == DataType::Boolean {
+        return Ok(deserialize_boolean(rows, column, |bytes| {
+            if is_null_value(bytes, null_values) {
+                None
+            } else {
+                boolean_tokens.parse(bytes)
+            }
+        }));
+    }
+    if let Some(array) = temporal_formats
+        .and_then(|formats| formats.deserialize_column(rows, column, &datatype, null_values))
+    {
+        return Ok(array);
+    }
+    let normalizer = normalizer.filter(|_| is_numeric(&datatype));
+    if null_values.is_none() && normalizer.is_none() {
+        return deserialize_column(rows, column, datatype, line_number);
+    }
+    let normalized = rows
+        .iter()
+        .map(|row| {
+            NormalizedField(
+                row.get(column)
+                    .filter(|bytes| !is_null_value(bytes, null_values))
+                    .map(|bytes| match normalizer {
+                        Some(normalizer) => normalizer.normalize(bytes),
+                        None => bytes.to_vec(),
+                    }),
+            )
+        })
+        .collect::<Vec<_>>();
+    deserialize_column(normalized.as_slice(), 0, datatype, line_number)
+}
+
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
 #[inline]
 pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
@@ -275,12 +561,7 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
             deserialize_primitive(rows, column, datatype, |bytes| {
                 to_utf8(bytes)
                     .and_then(|s| deserialize_naive_datetime(s, &mut last_fmt_idx))
-                    .and_then(|dt| match time_unit {
-                        TimeUnit::Second => Some(dt.timestamp()),
-                        TimeUnit::Millisecond => Some(dt.timestamp_millis()),
-                        TimeUnit::Microsecond => Some(dt.timestamp_micros()),
-                        TimeUnit::Nanosecond => dt.timestamp_nanos_opt(),
-                    })
+                    .and_then(|dt| naive_datetime_to_timestamp(dt, time_unit))
             })
         }
         Timestamp(time_unit, Some(ref tz)) => {
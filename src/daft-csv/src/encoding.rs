@@ -0,0 +1,152 @@
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use tokio::io::AsyncRead;
+use tokio_util::io::{ReaderStream, StreamReader};
+
+use crate::{CsvEncoding, CsvParseOptions};
+
+pub(crate) const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+/// Wraps `reader` to transcode it from the `encoding` of `parse_options` to UTF-8, stripping any leading byte order
+/// mark, e.g. the one that Excel writes at the start of UTF-8 CSVs, which would otherwise end up in the first field.
+pub(crate) fn transcode_to_utf8<'a, R>(
+    reader: R,
+    parse_options: &CsvParseOptions,
+) -> Pin<Box<dyn AsyncRead + Send + 'a>>
+where
+    R: AsyncRead + Unpin + Send + 'a,
+{
+    let encoding = parse_options.encoding.unwrap_or_default();
+    // Transcode chunk by chunk, and flush the transcoder once the reader is exhausted.
+    let chunks = futures::stream::unfold(
+        (ReaderStream::new(reader), Transcoder::new(encoding), false),
+        |(mut chunks, mut transcoder, finished)| async move {
+            if finished {
+                return None;
+            }
+            match chunks.next().await {
+                Some(Ok(chunk)) => {
+                    let transcoded = transcoder.transcode(chunk);
+                    Some((Ok(transcoded), (chunks, transcoder, false)))
+                }
+                Some(Err(err)) => Some((Err(err), (chunks, transcoder, true))),
+                None => {
+                    let transcoded = transcoder.finish();
+                    Some((Ok(transcoded), (chunks, transcoder, true)))
+                }
+            }
+        },
+    );
+    Box::pin(StreamReader::new(chunks))
+}
+
+/// Transcodes a stream of bytes to UTF-8, chunk by chunk. UTF-8 input is passed through without copying.
+///
+/// A leading byte order mark is stripped; for UTF-16, it also takes precedence over the configured byte order.
+/// Malformed UTF-16 input, e.g. an unpaired surrogate, is replaced with U+FFFD.
+struct Transcoder {
+    encoding: CsvEncoding,
+    // Whether the start of the stream, where a byte order mark may be, hasn't been seen yet.
+    at_start: bool,
+    // Bytes of an incomplete byte order mark, UTF-16 code unit or surrogate pair at the end of the last chunk, which
+    // are carried over to the next chunk.
+    pending: Vec<u8>,
+}
+
+impl Transcoder {
+    fn new(encoding: CsvEncoding) -> Self {
+        Self {
+            encoding,
+            at_start: true,
+            pending: vec![],
+        }
+    }
+
+    fn transcode(&mut self, bytes: Bytes) -> Bytes {
+        match self.encoding {
+            CsvEncoding::Utf8 if !self.at_start => bytes,
+            CsvEncoding::Utf8 => {
+                self.pending.extend_from_slice(&bytes);
+                self.strip_utf8_bom()
+            }
+            CsvEncoding::Latin1 => {
+                // Latin-1 bytes are the first 256 Unicode code points.
+                let mut transcoded = String::with_capacity(bytes.len());
+                transcoded.extend(bytes.iter().map(|&b| b as char));
+                Bytes::from(transcoded)
+            }
+            CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+                self.pending.extend_from_slice(&bytes);
+                Bytes::from(self.transcode_utf16())
+            }
+        }
+    }
+
+    fn strip_utf8_bom(&mut self) -> Bytes {
+        if self.pending.len() < UTF8_BOM.len() && UTF8_BOM.starts_with(&self.pending) {
+            return Bytes::new();
+        }
+        self.at_start = false;
+        let mut bytes = Bytes::from(std::mem::take(&mut self.pending));
+        if bytes.starts_with(UTF8_BOM) {
+            bytes = bytes.slice(UTF8_BOM.len()..);
+        }
+        bytes
+    }
+
+    fn transcode_utf16(&mut self) -> Vec<u8> {
+        if self.at_start {
+            if self.pending.len() < 2 {
+                return vec![];
+            }
+            self.at_start = false;
+            let byte_order = match self.pending[..2] {
+                [0xFF, 0xFE] => Some(CsvEncoding::Utf16Le),
+                [0xFE, 0xFF] => Some(CsvEncoding::Utf16Be),
+                _ => None,
+            };
+            if let Some(byte_order) = byte_order {
+                self.encoding = byte_order;
+                self.pending.drain(..2);
+            }
+        }
+        let mut units = self
+            .pending
+            .chunks_exact(2)
+            .map(|unit| match self.encoding {
+                CsvEncoding::Utf16Be => u16::from_be_bytes([unit[0], unit[1]]),
+                _ => u16::from_le_bytes([unit[0], unit[1]]),
+            })
+            .collect::<Vec<_>>();
+        let mut consumed = units.len() * 2;
+        // Hold back a high surrogate at the end of the chunk, as its low surrogate may be in the next chunk.
+        if let Some(&last) = units.last()
+            && (0xD800..0xDC00).contains(&last)
+        {
+            units.pop();
+            consumed -= 2;
+        }
+        self.pending.drain(..consumed);
+        char::decode_utf16(units)
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    /// Transcodes what's left at the end of the stream, which is malformed UTF-16 if it's not empty.
+    fn finish(&mut self) -> Bytes {
+        match self.encoding {
+            _ if self.pending.is_empty() => Bytes::new(),
+            // A stream that ends with a prefix of the byte order mark, which is kept as-is.
+            CsvEncoding::Utf8 | CsvEncoding::Latin1 => {
+                Bytes::from(std::mem::take(&mut self.pending))
+            }
+            CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+                self.pending.clear();
+                Bytes::from(char::REPLACEMENT_CHARACTER.to_string())
+            }
+        }
+    }
+}
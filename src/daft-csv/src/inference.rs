@@ -3,7 +3,45 @@ use std::collections::HashSet;
 use arrow2::datatypes::TimeUnit;
 use chrono::Timelike;
 
-use crate::deserialize::{ALL_NAIVE_TIMESTAMP_FMTS, ALL_TIMESTAMP_FMTS};
+use crate::deserialize::{
+    split_decimal, BooleanTokens, TemporalFormats, ALL_NAIVE_TIMESTAMP_FMTS, ALL_TIMESTAMP_FMTS,
+};
+use crate::Error;
+
+/// Disambiguates repeated column names by suffixing the repeats with `_1`, `_2`, etc., e.g. `id,id,value` becomes
+/// `id,id_1,value`. Suffixes already taken by other columns are skipped, so the names are always unique.
+pub(crate) fn dedup_column_names(names: &mut [String]) {
+    let mut taken = names.iter().cloned().collect::<HashSet<_>>();
+    let mut seen = HashSet::with_capacity(names.len());
+    for name in names.iter_mut() {
+        if seen.insert(name.clone()) {
+            continue;
+        }
+        let deduped = (1..)
+            .map(|suffix| format!("{name}_{suffix}"))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap();
+        taken.insert(deduped.clone());
+        seen.insert(deduped.clone());
+        *name = deduped;
+    }
+}
+
+/// Disambiguates repeated column names if `dedup` is set, and otherwise errors on the first repeated name.
+pub(crate) fn resolve_duplicate_column_names(
+    names: &mut [String],
+    dedup: bool,
+) -> Result<(), Error> {
+    if dedup {
+        dedup_column_names(names);
+        return Ok(());
+    }
+    let mut seen = HashSet::with_capacity(names.len());
+    match names.iter().find(|name| !seen.insert(name.as_str())) {
+        Some(name) => Err(Error::DuplicateColumnName { name: name.clone() }),
+        None => Ok(()),
+    }
+}
 
 pub fn merge_schema(
     headers: &[String],
@@ -28,29 +66,80 @@ fn merge_fields(
     }
     // determine data type based on possible types
     // if there are incompatible types, use DataType::Utf8
-    let data_type = match possibilities.len() {
-        1 => possibilities.drain().next().unwrap(),
-        2 => {
-            if possibilities.contains(&DataType::Int64)
-                && possibilities.contains(&DataType::Float64)
-            {
-                // we have an integer and double, fall down to double
-                DataType::Float64
-            } else {
-                // default to Utf8 for conflicting datatypes (e.g bool and int)
-                DataType::Utf8
+    let data_type = if possibilities
+        .iter()
+        .any(|dtype| matches!(dtype, DataType::Decimal(..)))
+    {
+        merge_decimal_possibilities(possibilities)
+    } else {
+        match possibilities.len() {
+            1 => possibilities.drain().next().unwrap(),
+            2 => {
+                if possibilities.contains(&DataType::Int64)
+                    && possibilities.contains(&DataType::Float64)
+                {
+                    // we have an integer and double, fall down to double
+                    DataType::Float64
+                } else if possibilities.contains(&DataType::Date32)
+                    && possibilities
+                        .iter()
+                        .any(|dtype| matches!(dtype, DataType::Timestamp(_, None)))
+                {
+                    // we have pure dates mixed with naive timestamps, read the dates as timestamps at midnight
+                    possibilities
+                        .drain()
+                        .find(|dtype| *dtype != DataType::Date32)
+                        .unwrap()
+                } else {
+                    // default to Utf8 for conflicting datatypes (e.g bool and int)
+                    DataType::Utf8
+                }
             }
+            _ => DataType::Utf8,
         }
-        _ => DataType::Utf8,
     };
     arrow2::datatypes::Field::new(field_name, data_type, true)
 }
 
+/// Merges possibilities that include at least one decimal. Decimals with a consistent scale merge to the widest
+/// precision seen, while decimals mixed with other numbers fall back to [`DataType::Float64`].
+fn merge_decimal_possibilities(
+    possibilities: &HashSet<arrow2::datatypes::DataType>,
+) -> arrow2::datatypes::DataType {
+    use arrow2::datatypes::DataType;
+
+    let mut merged: Option<(usize, usize)> = None;
+    let mut all_decimals_with_same_scale = true;
+    for dtype in possibilities.iter() {
+        match (dtype, merged) {
+            (DataType::Decimal(precision, scale), None) => merged = Some((*precision, *scale)),
+            (DataType::Decimal(precision, scale), Some((merged_precision, merged_scale)))
+                if *scale == merged_scale =>
+            {
+                merged = Some(((*precision).max(merged_precision), merged_scale));
+            }
+            (DataType::Decimal(..) | DataType::Int64 | DataType::Float64, _) => {
+                all_decimals_with_same_scale = false;
+            }
+            _ => return DataType::Utf8,
+        }
+    }
+    match merged {
+        Some((precision, scale)) if all_decimals_with_same_scale => {
+            DataType::Decimal(precision, scale)
+        }
+        _ => DataType::Float64,
+    }
+}
+
 /// Infers [`DataType`] from `bytes`
 /// # Implementation
 /// * empty slice to [`DataType::Null`]
-/// * case insensitive "true" or "false" are mapped to [`DataType::Boolean`]
+/// * the configured true/false tokens (by default, case insensitive "true" or "false") are mapped to
+///   [`DataType::Boolean`]
 /// * parsable to integer is mapped to [`DataType::Int64`]
+/// * if `infer_decimals` is set, plain decimal numbers with fractional digits are mapped to [`DataType::Decimal`] with
+///   the number's precision and scale
 /// * parsable to float is mapped to [`DataType::Float64`]
 /// * parsable to date is mapped to [`DataType::Date32`]
 /// * parsable to time is mapped to [`DataType::Time32(TimeUnit::Millisecond)`]
@@ -58,14 +147,20 @@ fn merge_fields(
 /// * parsable to time-aware datetime is mapped to [`DataType::Timestamp`] of milliseconds and parsed offset.
 /// * other utf8 is mapped to [`DataType::Utf8`]
 /// * invalid utf8 is mapped to [`DataType::Binary`]
-pub fn infer(bytes: &[u8]) -> arrow2::datatypes::DataType {
+pub fn infer(
+    bytes: &[u8],
+    boolean_tokens: &BooleanTokens,
+    infer_decimals: bool,
+) -> arrow2::datatypes::DataType {
     use arrow2::datatypes::DataType;
     if is_null(bytes) {
         DataType::Null
-    } else if is_boolean(bytes) {
+    } else if boolean_tokens.parse(bytes).is_some() {
         DataType::Boolean
     } else if is_integer(bytes) {
         DataType::Int64
+    } else if infer_decimals && let Some((precision, scale)) = decimal_precision_and_scale(bytes) {
+        DataType::Decimal(precision, scale)
     } else if is_float(bytes) {
         DataType::Float64
     } else if let Ok(string) = simdutf8::basic::from_utf8(bytes) {
@@ -86,12 +181,49 @@ pub fn infer(bytes: &[u8]) -> arrow2::datatypes::DataType {
     }
 }
 
-fn is_null(bytes: &[u8]) -> bool {
-    bytes.is_empty()
+/// Infers [`DataType::Date32`] or [`DataType::Timestamp`] without a timezone for `bytes` that match the user-provided
+/// date or timestamp format, respectively.
+pub(crate) fn infer_with_temporal_formats(
+    bytes: &[u8],
+    formats: &TemporalFormats,
+) -> Option<arrow2::datatypes::DataType> {
+    use arrow2::datatypes::DataType;
+    let string = simdutf8::basic::from_utf8(bytes).ok()?;
+    if let Some(fmt) = &formats.date_format
+        && chrono::NaiveDate::parse_from_str(string, fmt).is_ok()
+    {
+        Some(DataType::Date32)
+    } else if let Some(fmt) = &formats.timestamp_format
+        && let Ok(dt) = chrono::NaiveDateTime::parse_from_str(string, fmt)
+    {
+        Some(DataType::Timestamp(
+            nanoseconds_to_time_unit(dt.nanosecond()),
+            None,
+        ))
+    } else {
+        None
+    }
 }
 
-fn is_boolean(bytes: &[u8]) -> bool {
-    bytes.eq_ignore_ascii_case(b"true") | bytes.eq_ignore_ascii_case(b"false")
+/// Drops the string possibility of a column that has values matching a user-provided temporal format, so that its
+/// values that don't match the format are read as null rather than demoting the column to strings.
+pub(crate) fn drop_unparseable_temporal_values(
+    possibilities: &mut HashSet<arrow2::datatypes::DataType>,
+    formats: &TemporalFormats,
+) {
+    use arrow2::datatypes::DataType;
+    let has_formatted_values = possibilities.iter().any(|dtype| match dtype {
+        DataType::Date32 => formats.date_format.is_some(),
+        DataType::Timestamp(_, None) => formats.timestamp_format.is_some(),
+        _ => false,
+    });
+    if has_formatted_values {
+        possibilities.remove(&DataType::Utf8);
+    }
+}
+
+fn is_null(bytes: &[u8]) -> bool {
+    bytes.is_empty()
 }
 
 fn is_float(bytes: &[u8]) -> bool {
@@ -102,6 +234,30 @@ fn is_integer(bytes: &[u8]) -> bool {
     lexical_core::parse::<i64>(bytes).is_ok()
 }
 
+/// Maximum precision of a 128-bit decimal.
+const MAX_DECIMAL128_PRECISION: usize = 38;
+
+fn decimal_precision_and_scale(bytes: &[u8]) -> Option<(usize, usize)> {
+    let (_, lhs, rhs) = split_decimal(bytes)?;
+    let scale = rhs.len();
+    let precision = (lhs.len() + scale).max(1);
+    (scale > 0 && precision <= MAX_DECIMAL128_PRECISION).then_some((precision, scale))
+}
+
+/// Infers [`DataType::Decimal`] with the hinted `scale` for `bytes` that are a plain decimal number, with the precision
+/// needed for its integer digits. Fractional digits beyond the scale and precisions beyond what a 128-bit decimal can
+/// hold aren't reflected in the inferred type, so that such values are read as null rather than demoting the column.
+pub(crate) fn infer_with_decimal_scale(
+    bytes: &[u8],
+    scale: usize,
+) -> Option<arrow2::datatypes::DataType> {
+    let (_, lhs, _) = split_decimal(bytes)?;
+    (scale <= MAX_DECIMAL128_PRECISION).then(|| {
+        let precision = (lhs.len() + scale).clamp(1, MAX_DECIMAL128_PRECISION);
+        arrow2::datatypes::DataType::Decimal(precision, scale)
+    })
+}
+
 fn is_date(string: &str) -> bool {
     string.parse::<chrono::NaiveDate>().is_ok()
 }
@@ -5,11 +5,21 @@ use snafu::Snafu;
 
 mod compression;
 mod deserialize;
+mod encoding;
 mod inference;
 pub mod metadata;
+pub mod options;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
+mod snappy;
+mod trim;
+pub mod write;
+pub use metadata::{infer_csv_schema, CsvReadStats, RecordSizeHistogram};
+pub use options::{
+    CsvConvertOptions, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvWriteOptions,
+    NullByteHandling, ParseThreadPool, ProgressCallback,
+};
 #[cfg(feature = "python")]
 pub use python::register_modules;
 
@@ -21,6 +31,20 @@ pub enum Error {
     CSVError { source: csv_async::Error },
     #[snafu(display("{source}"))]
     ArrowError { source: arrow2::error::Error },
+    #[snafu(display(
+        "found record with {found} fields, but the previous record has {expected} fields (record {record_index})"
+    ))]
+    FieldCountMismatch {
+        expected: usize,
+        found: usize,
+        // Index of the mismatched record in the file, counting the header row, if any.
+        record_index: u64,
+    },
+    #[snafu(display(
+        "CSV header has duplicate column name \"{name}\"; enable dedup_column_names to read repeated names with \
+         numeric suffixes"
+    ))]
+    DuplicateColumnName { name: String },
     #[snafu(display("Error joining spawned task: {}", source))]
     JoinError { source: tokio::task::JoinError },
     #[snafu(display(
@@ -36,6 +60,7 @@ impl From<Error> for DaftError {
     fn from(err: Error) -> DaftError {
         match err {
             Error::IOError { source } => source.into(),
+            Error::DuplicateColumnName { .. } => DaftError::ValueError(err.to_string()),
             _ => DaftError::External(err.into()),
         }
     }
@@ -46,3 +71,38 @@ impl From<daft_io::Error> for Error {
         Error::IOError { source: err }
     }
 }
+
+impl From<csv_async::Error> for Error {
+    fn from(err: csv_async::Error) -> Self {
+        match err.kind() {
+            csv_async::ErrorKind::UnequalLengths {
+                pos,
+                expected_len,
+                len,
+            } => Error::FieldCountMismatch {
+                expected: *expected_len as usize,
+                found: *len as usize,
+                record_index: pos.as_ref().map_or(0, |pos| pos.record()),
+            },
+            _ => Error::CSVError { source: err },
+        }
+    }
+}
+
+impl Error {
+    /// Converts an error raised by arrow2 while reading CSV records, unwrapping the CSV reader errors that arrow2 wraps
+    /// so that e.g. field count mismatches surface as such.
+    pub(crate) fn from_record_read(err: arrow2::error::Error) -> Self {
+        match err {
+            arrow2::error::Error::External(msg, source) => {
+                match source.downcast::<csv_async::Error>() {
+                    Ok(err) => (*err).into(),
+                    Err(source) => Error::ArrowError {
+                        source: arrow2::error::Error::External(msg, source),
+                    },
+                }
+            }
+            err => Error::ArrowError { source: err },
+        }
+    }
+}
@@ -1,39 +1,175 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use arrow2::io::csv::read_async::{AsyncReader, AsyncReaderBuilder};
 use async_compat::CompatExt;
 use common_error::DaftResult;
 use csv_async::ByteRecord;
 use daft_core::schema::Schema;
-use daft_io::{get_runtime, GetResult, IOClient, IOStatsRef};
+use daft_io::{
+    get_runtime, join_all_bounded, GetResult, IOClient, IOStatsRef, DEFAULT_MAX_CONCURRENT_IO,
+};
+use snafu::ResultExt;
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncRead, BufReader},
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, BufReader},
 };
 use tokio_util::io::StreamReader;
 
-use crate::inference::merge_schema;
+use crate::deserialize::{is_null_value, BooleanTokens, TemporalFormats};
+use crate::inference::{
+    drop_unparseable_temporal_values, infer_with_decimal_scale, infer_with_temporal_formats,
+    merge_schema, resolve_duplicate_column_names,
+};
+use crate::read::skip_lines;
 use crate::{compression::CompressionCodec, inference::infer};
+use crate::{encoding::transcode_to_utf8, trim::maybe_trim_fields};
+use crate::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 
 const DEFAULT_COLUMN_PREFIX: &str = "column_";
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CsvReadStats {
+    pub total_bytes_read: usize,
+    pub total_records_read: usize,
+    pub mean_record_size_bytes: f64,
+    pub stddev_record_size_bytes: f64,
+    /// Estimated total number of records in the file, if known; see [`infer_csv_schema`].
+    pub estimated_total_records: Option<usize>,
+    /// Whether schema inference read the whole file, rather than stopping at its byte or record limit, in which case
+    /// the inferred types may not hold for the rest of the file.
+    pub read_whole_file: bool,
+    /// Columns whose types were inferred from at most one non-null value, which callers may want to widen the sample
+    /// for.
+    pub borderline_columns: Vec<String>,
+    /// Percentiles of the sizes of the records read during schema inference, if any were read.
+    pub record_size_histogram: Option<RecordSizeHistogram>,
+}
+
+/// Coarse percentiles of record sizes in bytes, which describe heavy-tailed sizes better than their mean and standard
+/// deviation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecordSizeHistogram {
+    pub p50: usize,
+    pub p85: usize,
+    pub p95: usize,
+    pub max: usize,
+}
+
+impl RecordSizeHistogram {
+    /// Computes the percentiles of `sizes` by nearest rank, or returns `None` if there are no sizes.
+    pub fn from_sizes(mut sizes: Vec<usize>) -> Option<Self> {
+        if sizes.is_empty() {
+            return None;
+        }
+        sizes.sort_unstable();
+        let percentile = |p: f64| {
+            let rank = (p * sizes.len() as f64).ceil() as usize;
+            sizes[rank.clamp(1, sizes.len()) - 1]
+        };
+        Some(Self {
+            p50: percentile(0.5),
+            p85: percentile(0.85),
+            p95: percentile(0.95),
+            max: *sizes.last().unwrap(),
+        })
+    }
+}
+
+impl CsvReadStats {
+    pub fn new(
+        total_bytes_read: usize,
+        total_records_read: usize,
+        mean_record_size_bytes: f64,
+        stddev_record_size_bytes: f64,
+    ) -> Self {
+        Self {
+            total_bytes_read,
+            total_records_read,
+            mean_record_size_bytes,
+            stddev_record_size_bytes,
+            estimated_total_records: None,
+            read_whole_file: false,
+            borderline_columns: vec![],
+            record_size_histogram: None,
+        }
+    }
+}
+
+/// Infers the schema of a CSV file, along with statistics of the records read during inference and an estimate of
+/// the total number of records in the file, without reading the whole file.
+///
+/// If inference reaches the end of the file, the estimate is the exact number of records read. Otherwise, it's
+/// extrapolated from the size of the file and the mean size of the records read during inference. No estimate is
+/// made for files compressed according to their extension, as their size doesn't reflect their decompressed size.
+pub fn infer_csv_schema(
+    uri: &str,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(Schema, CsvReadStats)> {
+    // Read at most 1 MiB when doing schema inference.
+    let max_bytes = 1024 * 1024;
+    let max_rows = read_options.and_then(|opts| opts.inference_max_rows);
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        let (schema, read_stats) = read_csv_schema_single(
+            uri,
+            parse_options.unwrap_or_default(),
+            Some(max_bytes),
+            max_rows,
+            CsvConvertOptions::default(),
+            io_client.clone(),
+            io_stats.clone(),
+        )
+        .await?;
+        let estimated_total_records = if read_stats.read_whole_file {
+            Some(read_stats.total_records_read)
+        } else if CompressionCodec::from_uri(uri).is_none()
+            && read_stats.mean_record_size_bytes > 0.0
+        {
+            let size = io_client
+                .single_url_get_size(uri.to_string(), io_stats)
+                .await?;
+            // The record sizes only account for field bytes, so add a delimiter or terminator byte per field.
+            let record_size = read_stats.mean_record_size_bytes + schema.fields.len() as f64;
+            Some((size as f64 / record_size).round() as usize)
+        } else {
+            None
+        };
+        Ok((
+            schema,
+            CsvReadStats {
+                estimated_total_records,
+                ..read_stats
+            },
+        ))
+    })
+}
+
 pub fn read_csv_schema(
     uri: &str,
-    has_header: bool,
-    delimiter: Option<u8>,
+    parse_options: Option<CsvParseOptions>,
     max_bytes: Option<usize>,
+    max_rows: Option<usize>,
+    convert_options: Option<CsvConvertOptions>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
-) -> DaftResult<(Schema, usize, usize, f64, f64)> {
+) -> DaftResult<(Schema, CsvReadStats)> {
     let runtime_handle = get_runtime(true)?;
     let _rt_guard = runtime_handle.enter();
     runtime_handle.block_on(async {
         read_csv_schema_single(
             uri,
-            has_header,
-            delimiter,
+            parse_options.unwrap_or_default(),
             // Default to 1 MiB.
             max_bytes.or(Some(1024 * 1024)),
+            max_rows,
+            convert_options.unwrap_or_default(),
             io_client,
             io_stats,
         )
@@ -41,15 +177,103 @@ pub fn read_csv_schema(
     })
 }
 
+/// Infers the schema of every file of `uris`, in order, with at most `max_concurrent_io` inferences at once, which
+/// defaults to [`DEFAULT_MAX_CONCURRENT_IO`].
+///
+/// If `merge_schemas` is set, the schemas of all files are merged with [`merge_csv_schemas`], and every file is
+/// returned with the merged schema alongside its own read stats.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_csv_schema_bulk(
+    uris: &[&str],
+    parse_options: Option<CsvParseOptions>,
+    max_bytes: Option<usize>,
+    max_rows: Option<usize>,
+    convert_options: Option<CsvConvertOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_concurrent_io: Option<usize>,
+    merge_schemas: bool,
+) -> DaftResult<Vec<(Schema, CsvReadStats)>> {
+    let parse_options = parse_options.unwrap_or_default();
+    let convert_options = convert_options.unwrap_or_default();
+    let futures = uris.iter().map(|uri| {
+        let owned_string = uri.to_string();
+        let owned_parse_options = parse_options.clone();
+        let owned_convert_options = convert_options.clone();
+        let owned_client = io_client.clone();
+        let owned_io_stats = io_stats.clone();
+        async move {
+            read_csv_schema_single(
+                &owned_string,
+                owned_parse_options,
+                // Default to 1 MiB.
+                max_bytes.or(Some(1024 * 1024)),
+                max_rows,
+                owned_convert_options,
+                owned_client,
+                owned_io_stats,
+            )
+            .await
+        }
+    });
+    // Limit the number of concurrent schema inferences, while preserving the input URI order.
+    let all_schemas = join_all_bounded(
+        futures,
+        max_concurrent_io.unwrap_or(DEFAULT_MAX_CONCURRENT_IO),
+    )
+    .await
+    .context(super::JoinSnafu {})?;
+    let all_schemas = all_schemas.into_iter().collect::<DaftResult<Vec<_>>>()?;
+    if !merge_schemas || all_schemas.is_empty() {
+        return Ok(all_schemas);
+    }
+    let merged_schema = merge_csv_schemas(
+        all_schemas
+            .iter()
+            .map(|(schema, _)| schema.clone())
+            .collect(),
+    )?;
+    Ok(all_schemas
+        .into_iter()
+        .map(|(_, read_stats)| (merged_schema.clone(), read_stats))
+        .collect())
+}
+
+/// Merges the schemas inferred from multiple CSV files into a single schema that can hold all of the files.
+///
+/// Columns are ordered by their first appearance across the schemas, and columns missing from some files are kept
+/// (as nullable columns). The types of a column are promoted with the same rules used when inferring a single file,
+/// e.g. `Int64` and `Float64` widen to `Float64`, `Null` defers to the other type, and otherwise conflicting types
+/// fall back to `Utf8`.
+pub fn merge_csv_schemas(schemas: Vec<Schema>) -> DaftResult<Schema> {
+    let mut headers: Vec<String> = vec![];
+    let mut column_types: Vec<HashSet<arrow2::datatypes::DataType>> = vec![];
+    let mut header_to_idx: HashMap<String, usize> = HashMap::new();
+    for schema in schemas.iter() {
+        for (name, field) in schema.fields.iter() {
+            let idx = *header_to_idx.entry(name.clone()).or_insert_with(|| {
+                headers.push(name.clone());
+                column_types.push(HashSet::new());
+                headers.len() - 1
+            });
+            column_types[idx].insert(field.dtype.to_arrow()?);
+        }
+    }
+    let fields = merge_schema(&headers, &mut column_types);
+    let schema: arrow2::datatypes::Schema = fields.into();
+    Schema::try_from(&schema)
+}
+
 pub(crate) async fn read_csv_schema_single(
     uri: &str,
-    has_header: bool,
-    delimiter: Option<u8>,
+    parse_options: CsvParseOptions,
     max_bytes: Option<usize>,
+    max_rows: Option<usize>,
+    convert_options: CsvConvertOptions,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
-) -> DaftResult<(Schema, usize, usize, f64, f64)> {
-    let compression_codec = CompressionCodec::from_uri(uri);
+) -> DaftResult<(Schema, CsvReadStats)> {
+    let compression_codec = CompressionCodec::try_from_uri(uri)?;
     match io_client
         .single_url_get(uri.to_string(), None, io_stats)
         .await?
@@ -58,9 +282,10 @@ pub(crate) async fn read_csv_schema_single(
             read_csv_schema_from_compressed_reader(
                 BufReader::new(File::open(file.path).await?),
                 compression_codec,
-                has_header,
-                delimiter,
+                parse_options,
                 max_bytes,
+                max_rows,
+                convert_options,
             )
             .await
         }
@@ -68,10 +293,11 @@ pub(crate) async fn read_csv_schema_single(
             read_csv_schema_from_compressed_reader(
                 StreamReader::new(stream),
                 compression_codec,
-                has_header,
-                delimiter,
+                parse_options,
                 // Truncate max_bytes to size if both are set.
                 max_bytes.map(|m| size.map(|s| m.min(s)).unwrap_or(m)),
+                max_rows,
+                convert_options,
             )
             .await
         }
@@ -79,90 +305,150 @@ pub(crate) async fn read_csv_schema_single(
 }
 
 async fn read_csv_schema_from_compressed_reader<R>(
-    reader: R,
+    mut reader: R,
     compression_codec: Option<CompressionCodec>,
-    has_header: bool,
-    delimiter: Option<u8>,
+    parse_options: CsvParseOptions,
     max_bytes: Option<usize>,
-) -> DaftResult<(Schema, usize, usize, f64, f64)>
+    max_rows: Option<usize>,
+    convert_options: CsvConvertOptions,
+) -> DaftResult<(Schema, CsvReadStats)>
 where
     R: AsyncBufRead + Unpin + Send + 'static,
 {
+    // Fall back to sniffing the leading bytes if the URI has no compression extension.
+    let compression_codec = match compression_codec {
+        Some(compression) => Some(compression),
+        None => CompressionCodec::from_magic_bytes(reader.fill_buf().await?),
+    };
     match compression_codec {
         Some(compression) => {
             read_csv_schema_from_uncompressed_reader(
                 compression.to_decoder(reader),
-                has_header,
-                delimiter,
+                parse_options,
                 max_bytes,
+                max_rows,
+                convert_options,
             )
             .await
         }
         None => {
-            read_csv_schema_from_uncompressed_reader(reader, has_header, delimiter, max_bytes).await
+            read_csv_schema_from_uncompressed_reader(
+                reader,
+                parse_options,
+                max_bytes,
+                max_rows,
+                convert_options,
+            )
+            .await
         }
     }
 }
 
-async fn read_csv_schema_from_uncompressed_reader<R>(
+pub(crate) async fn read_csv_schema_from_uncompressed_reader<R>(
     reader: R,
-    has_header: bool,
-    delimiter: Option<u8>,
+    parse_options: CsvParseOptions,
     max_bytes: Option<usize>,
-) -> DaftResult<(Schema, usize, usize, f64, f64)>
+    max_rows: Option<usize>,
+    convert_options: CsvConvertOptions,
+) -> DaftResult<(Schema, CsvReadStats)>
 where
     R: AsyncRead + Unpin + Send,
 {
-    let (schema, total_bytes_read, num_records_read, mean_size, std_size) =
-        read_csv_arrow_schema_from_uncompressed_reader(reader, has_header, delimiter, max_bytes)
-            .await?;
-    Ok((
-        Schema::try_from(&schema)?,
-        total_bytes_read,
-        num_records_read,
-        mean_size,
-        std_size,
-    ))
+    let (schema, read_stats) = read_csv_arrow_schema_from_uncompressed_reader(
+        reader,
+        parse_options,
+        max_bytes,
+        max_rows,
+        convert_options,
+    )
+    .await?;
+    Ok((Schema::try_from(&schema)?, read_stats))
 }
 
 async fn read_csv_arrow_schema_from_uncompressed_reader<R>(
     reader: R,
-    has_header: bool,
-    delimiter: Option<u8>,
+    parse_options: CsvParseOptions,
     max_bytes: Option<usize>,
-) -> DaftResult<(arrow2::datatypes::Schema, usize, usize, f64, f64)>
+    max_rows: Option<usize>,
+    convert_options: CsvConvertOptions,
+) -> DaftResult<(arrow2::datatypes::Schema, CsvReadStats)>
 where
     R: AsyncRead + Unpin + Send,
 {
+    let mut stream_reader = BufReader::new(maybe_trim_fields(
+        transcode_to_utf8(reader, &parse_options),
+        &parse_options,
+    ));
+    skip_lines(&mut stream_reader, parse_options.skip_rows).await?;
     let mut reader = AsyncReaderBuilder::new()
-        .has_headers(has_header)
-        .delimiter(delimiter.unwrap_or(b','))
+        .has_headers(parse_options.has_header)
+        .delimiter(parse_options.delimiter)
+        .escape(parse_options.escape)
+        .comment(parse_options.comment)
+        .terminator(parse_options.terminator())
+        .double_quote(parse_options.double_quote)
+        .flexible(parse_options.flexible)
         .buffer_capacity(max_bytes.unwrap_or(1 << 20).min(1 << 20))
-        .create_reader(reader.compat());
-    let (fields, total_bytes_read, num_records_read, mean_size, std_size) =
-        infer_schema(&mut reader, None, max_bytes, has_header).await?;
-    Ok((
-        fields.into(),
-        total_bytes_read,
-        num_records_read,
-        mean_size,
-        std_size,
-    ))
+        .create_reader(stream_reader.compat());
+    let (fields, read_stats) = infer_schema(
+        &mut reader,
+        max_rows,
+        max_bytes,
+        &parse_options,
+        &convert_options,
+    )
+    .await?;
+    Ok((fields.into(), read_stats))
+}
+
+/// Infers the type of a single field, treating any of the `null_values` tokens as null, and trying the hinted
+/// `decimal_scale` of its column and the user-provided `temporal_formats` before the default inference.
+fn infer_field(
+    bytes: &[u8],
+    boolean_tokens: &BooleanTokens,
+    infer_decimals: bool,
+    null_values: Option<&[String]>,
+    temporal_formats: Option<&TemporalFormats>,
+    decimal_scale: Option<usize>,
+) -> arrow2::datatypes::DataType {
+    if is_null_value(bytes, null_values) {
+        arrow2::datatypes::DataType::Null
+    } else if let Some(dtype) =
+        decimal_scale.and_then(|scale| infer_with_decimal_scale(bytes, scale))
+    {
+        dtype
+    } else if let Some(dtype) =
+        temporal_formats.and_then(|formats| infer_with_temporal_formats(bytes, formats))
+    {
+        dtype
+    } else {
+        infer(bytes, boolean_tokens, infer_decimals)
+    }
 }
 
 async fn infer_schema<R>(
     reader: &mut AsyncReader<R>,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
-    has_header: bool,
-) -> arrow2::error::Result<(Vec<arrow2::datatypes::Field>, usize, usize, f64, f64)>
+    parse_options: &CsvParseOptions,
+    convert_options: &CsvConvertOptions,
+) -> Result<(Vec<arrow2::datatypes::Field>, CsvReadStats), super::Error>
 where
     R: futures::AsyncRead + Unpin + Send,
 {
+    let null_values = convert_options.null_values.as_deref();
+    let boolean_tokens = BooleanTokens::new(
+        convert_options.true_values.clone(),
+        convert_options.false_values.clone(),
+    );
+    let temporal_formats = TemporalFormats::try_new(
+        convert_options.date_format.clone(),
+        convert_options.timestamp_format.clone(),
+    );
     let mut record = ByteRecord::new();
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
-    let (headers, did_read_record): (Vec<String>, bool) = if has_header {
+    let (mut headers, did_read_record): (Vec<String>, bool) = if parse_options.has_header {
         (
             reader
                 .headers()
@@ -175,7 +461,13 @@ where
     } else {
         // Save the csv reader position before reading headers
         if !reader.read_byte_record(&mut record).await? {
-            return Ok((vec![], 0, 0, 0f64, 0f64));
+            return Ok((
+                vec![],
+                CsvReadStats {
+                    read_whole_file: true,
+                    ..Default::default()
+                },
+            ));
         }
         let first_record_count = record.len();
         (
@@ -185,9 +477,13 @@ where
             true,
         )
     };
+    resolve_duplicate_column_names(&mut headers, convert_options.dedup_column_names)?;
     // keep track of inferred field types
     let mut column_types: Vec<HashSet<arrow2::datatypes::DataType>> =
         vec![HashSet::new(); headers.len()];
+    // Number of non-null values seen per column, to flag columns inferred from too few values.
+    let mut non_null_counts = vec![0usize; headers.len()];
+    let mut record_sizes = vec![];
     let mut records_count = 0;
     let mut total_bytes = 0;
     let mut mean = 0f64;
@@ -195,6 +491,7 @@ where
     if did_read_record {
         records_count += 1;
         let record_size = record.as_slice().len();
+        record_sizes.push(record_size);
         total_bytes += record_size;
         let delta = (record_size as f64) - mean;
         mean += delta / (records_count as f64);
@@ -202,18 +499,41 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                let dtype = infer_field(
+                    string,
+                    &boolean_tokens,
+                    convert_options.infer_decimals,
+                    null_values,
+                    temporal_formats.as_ref(),
+                    convert_options.decimal_scale_of(&headers[i]),
+                );
+                if dtype != arrow2::datatypes::DataType::Null {
+                    non_null_counts[i] += 1;
+                }
+                column.insert(dtype);
             }
         }
     }
     let max_records = max_rows.unwrap_or(usize::MAX);
     let max_bytes = max_bytes.unwrap_or(usize::MAX);
+    let mut read_whole_file = false;
     while records_count < max_records && total_bytes < max_bytes {
         if !reader.read_byte_record(&mut record).await? {
+            read_whole_file = true;
             break;
         }
+        // Without a header, flexible reads take the width of the widest record as the number of columns.
+        if parse_options.flexible && !parse_options.has_header && record.len() > headers.len() {
+            headers.extend(
+                (headers.len()..record.len())
+                    .map(|i| format!("{}{}", DEFAULT_COLUMN_PREFIX, i + 1)),
+            );
+            column_types.resize(record.len(), HashSet::new());
+            non_null_counts.resize(record.len(), 0);
+        }
         records_count += 1;
         let record_size = record.as_slice().len();
+        record_sizes.push(record_size);
         total_bytes += record_size;
         let delta = (record_size as f64) - mean;
         mean += delta / (records_count as f64);
@@ -221,13 +541,46 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                let dtype = infer_field(
+                    string,
+                    &boolean_tokens,
+                    convert_options.infer_decimals,
+                    null_values,
+                    temporal_formats.as_ref(),
+                    convert_options.decimal_scale_of(&headers[i]),
+                );
+                if dtype != arrow2::datatypes::DataType::Null {
+                    non_null_counts[i] += 1;
+                }
+                column.insert(dtype);
             }
         }
     }
+    // Values that don't match the user-provided temporal formats are read as null, rather than demoting their columns
+    // to strings.
+    if let Some(temporal_formats) = &temporal_formats {
+        for possibilities in column_types.iter_mut() {
+            drop_unparseable_temporal_values(possibilities, temporal_formats);
+        }
+    }
+    let borderline_columns = headers
+        .iter()
+        .zip(non_null_counts.iter())
+        .filter(|(_, non_null_count)| **non_null_count <= 1)
+        .map(|(header, _)| header.clone())
+        .collect();
     let fields = merge_schema(&headers, &mut column_types);
-    let std = (m2 / ((records_count - 1) as f64)).sqrt();
-    Ok((fields, total_bytes, records_count, mean, std))
+    // Empty and header-only files have no records to take the stats of.
+    let std = (m2 / (records_count.saturating_sub(1) as f64)).sqrt();
+    Ok((
+        fields,
+        CsvReadStats {
+            read_whole_file,
+            borderline_columns,
+            record_size_histogram: RecordSizeHistogram::from_sizes(record_sizes),
+            ..CsvReadStats::new(total_bytes, records_count, mean, std)
+        },
+    ))
 }
 
 #[cfg(test)]
@@ -235,11 +588,16 @@ mod tests {
     use std::sync::Arc;
 
     use common_error::{DaftError, DaftResult};
-    use daft_core::{datatypes::Field, schema::Schema, DataType};
-    use daft_io::{IOClient, IOConfig};
+    use daft_core::{
+        datatypes::{Field, TimeUnit},
+        schema::Schema,
+        DataType,
+    };
+    use daft_io::{get_runtime, IOClient, IOConfig};
     use rstest::rstest;
 
-    use super::read_csv_schema;
+    use super::{infer_csv_schema, merge_csv_schemas, read_csv_schema, read_csv_schema_bulk};
+    use crate::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 
     #[rstest]
     fn test_csv_schema_local(
@@ -254,8 +612,12 @@ mod tests {
             Some("deflate"),
             // gzip
             Some("gz"),
+            // lz4
+            Some("lz4"),
             // lzma
             Some("lzma"),
+            // snappy
+            Some("sz"),
             // xz
             Some("xz"),
             // zlib
@@ -275,8 +637,15 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -287,151 +656,246 @@ mod tests {
                 Field::new("variety", DataType::Utf8),
             ])?,
         );
-        assert_eq!(total_bytes_read, 328);
-        assert_eq!(num_records_read, 20);
+        assert_eq!(read_stats.total_bytes_read, 328);
+        assert_eq!(read_stats.total_records_read, 20);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_delimiter() -> DaftResult<()> {
-        let file = format!(
-            "{}/test/iris_tiny_bar_delimiter.csv",
-            env!("CARGO_MANIFEST_DIR"),
-        );
+    fn test_csv_schema_local_infer_decimals() -> DaftResult<()> {
+        let file = format!("{}/test/decimal_prices.csv", env!("CARGO_MANIFEST_DIR"),);
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, total_bytes_read, num_records_read, _, _) = read_csv_schema(
+        let (schema, _) = read_csv_schema(
             file.as_ref(),
-            true,
-            Some(b'|'),
             None,
+            None,
+            None,
+            Some(CsvConvertOptions::default().with_infer_decimals(true)),
             io_client.clone(),
             None,
         )?;
         assert_eq!(
             schema,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
+                Field::new("item", DataType::Utf8),
+                Field::new("price", DataType::Decimal128(5, 2)),
+                Field::new("weight", DataType::Float64),
             ])?,
         );
-        assert_eq!(total_bytes_read, 328);
-        assert_eq!(num_records_read, 20);
+
+        // Decimal inference is opt-in.
+        let (schema, _) = read_csv_schema(file.as_ref(), None, None, None, None, io_client, None)?;
+        assert_eq!(schema.get_field("price")?.dtype, DataType::Float64);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_read_stats() -> DaftResult<()> {
+    fn test_infer_csv_schema_estimates_total_records() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (_, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
-        assert_eq!(total_bytes_read, 328);
-        assert_eq!(num_records_read, 20);
+        // Inference reads the whole file, so the record count is exact.
+        let (schema, read_stats) =
+            infer_csv_schema(file.as_ref(), None, None, io_client.clone(), None)?;
+        assert_eq!(schema.fields.len(), 5);
+        assert_eq!(read_stats.estimated_total_records, Some(20));
+
+        // Inference only reads the first 5 records, so the record count is extrapolated from the file size.
+        let (_, read_stats) = infer_csv_schema(
+            file.as_ref(),
+            None,
+            Some(CsvReadOptions::default().with_inference_max_rows(Some(5))),
+            io_client,
+            None,
+        )?;
+        assert_eq!(read_stats.total_records_read, 5);
+        let estimated_total_records = read_stats.estimated_total_records.unwrap();
+        assert!(
+            (18..=24).contains(&estimated_total_records),
+            "{}",
+            estimated_total_records
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_no_headers() -> DaftResult<()> {
-        let file = format!(
-            "{}/test/iris_tiny_no_headers.csv",
-            env!("CARGO_MANIFEST_DIR"),
-        );
+    fn test_csv_schema_bulk_local() -> DaftResult<()> {
+        let files = ["iris_tiny.csv", "iris_tiny_reordered_columns.csv"]
+            .iter()
+            .map(|name| format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), name))
+            .collect::<Vec<_>>();
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), false, None, None, io_client.clone(), None)?;
+        let runtime_handle = get_runtime(true)?;
+        let schemas = runtime_handle.block_on(read_csv_schema_bulk(
+            uris.as_slice(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            Some(2),
+            false,
+        ))?;
+        assert_eq!(schemas.len(), 2);
+        // Schemas should be returned in input order.
         assert_eq!(
-            schema,
+            schemas[0].0,
             Schema::new(vec![
-                Field::new("column_1", DataType::Float64),
-                Field::new("column_2", DataType::Float64),
-                Field::new("column_3", DataType::Float64),
-                Field::new("column_4", DataType::Float64),
-                Field::new("column_5", DataType::Utf8),
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(
+            schemas[1].0,
+            Schema::new(vec![
+                Field::new("variety", DataType::Utf8),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
             ])?,
         );
-        assert_eq!(total_bytes_read, 328);
-        assert_eq!(num_records_read, 20);
+        assert_ne!(schemas[0].0, schemas[1].0);
+        assert_eq!(schemas[0].1.total_records_read, 20);
+        assert_eq!(schemas[1].1.total_records_read, 20);
+
+        // An unreadable file should fail the whole bulk inference.
+        let missing = format!("{}/test/does_not_exist.csv", env!("CARGO_MANIFEST_DIR"));
+        let result = runtime_handle.block_on(read_csv_schema_bulk(
+            &[uris[0], missing.as_str()],
+            None,
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            Some(2),
+            false,
+        ));
+        assert!(result.is_err());
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_empty_lines_skipped() -> DaftResult<()> {
-        let file = format!(
-            "{}/test/iris_tiny_empty_lines.csv",
-            env!("CARGO_MANIFEST_DIR"),
-        );
+    fn test_csv_schema_bulk_local_merge_schemas() -> DaftResult<()> {
+        let files = [
+            "merge_schemas_ints.csv",
+            "merge_schemas_floats_extra_column.csv",
+        ]
+        .iter()
+        .map(|name| format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), name))
+        .collect::<Vec<_>>();
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
 
-        let mut io_config = IOConfig::default();
-        io_config.s3.anonymous = true;
-        let io_client = Arc::new(IOClient::new(io_config.into())?);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let runtime_handle = get_runtime(true)?;
+        let schemas = runtime_handle.block_on(read_csv_schema_bulk(
+            uris.as_slice(),
+            None,
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            None,
+            true,
+        ))?;
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("price", DataType::Float64),
+            Field::new("note", DataType::Utf8),
+        ])?;
+        assert_eq!(schemas.len(), 2);
+        for (schema, _) in schemas.iter() {
+            assert_eq!(*schema, expected);
+        }
+        // The read stats are still those of each file.
+        assert_eq!(schemas[0].1.total_records_read, 2);
+        assert_eq!(schemas[1].1.total_records_read, 2);
 
-        let (schema, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_csv_schemas_widens_int_to_float() -> DaftResult<()> {
+        let merged = merge_csv_schemas(vec![
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ])?,
+            Schema::new(vec![
+                Field::new("a", DataType::Float64),
+                Field::new("b", DataType::Utf8),
+            ])?,
+        ])?;
         assert_eq!(
-            schema,
+            merged,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
+                Field::new("a", DataType::Float64),
+                Field::new("b", DataType::Utf8),
             ])?,
         );
-        assert_eq!(total_bytes_read, 49);
-        assert_eq!(num_records_read, 3);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_nulls() -> DaftResult<()> {
-        let file = format!("{}/test/iris_tiny_nulls.csv", env!("CARGO_MANIFEST_DIR"),);
-
-        let mut io_config = IOConfig::default();
-        io_config.s3.anonymous = true;
-        let io_client = Arc::new(IOClient::new(io_config.into())?);
-
-        let (schema, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
+    fn test_merge_csv_schemas_missing_and_conflicting_columns() -> DaftResult<()> {
+        let merged = merge_csv_schemas(vec![
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Boolean),
+            ])?,
+            // Extra trailing column, and a column that's all null in this file.
+            Schema::new(vec![
+                Field::new("a", DataType::Null),
+                Field::new("b", DataType::Int64),
+                Field::new("c", DataType::Date),
+            ])?,
+            // Missing column, and columns in a different order.
+            Schema::new(vec![
+                Field::new("c", DataType::Date),
+                Field::new("a", DataType::Int64),
+            ])?,
+        ])?;
         assert_eq!(
-            schema,
+            merged,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
+                Field::new("a", DataType::Int64),
+                // Conflicting types fall back to strings.
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Date),
             ])?,
         );
-        assert_eq!(total_bytes_read, 82);
-        assert_eq!(num_records_read, 6);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_conflicting_types_utf8_fallback() -> DaftResult<()> {
+    fn test_csv_schema_local_compression_from_magic_bytes() -> DaftResult<()> {
         let file = format!(
-            "{}/test/iris_tiny_conflicting_dtypes.csv",
+            "{}/test/iris_tiny_gzip_no_extension",
             env!("CARGO_MANIFEST_DIR"),
         );
 
@@ -439,38 +903,40 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, total_bytes_read, num_records_read, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
+        let (schema, read_stats) =
+            read_csv_schema(file.as_ref(), None, None, None, None, io_client, None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
-                // All conflicting dtypes fall back to UTF8.
-                Field::new("sepal.length", DataType::Utf8),
-                Field::new("sepal.width", DataType::Utf8),
-                Field::new("petal.length", DataType::Utf8),
-                Field::new("petal.width", DataType::Utf8),
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
                 Field::new("variety", DataType::Utf8),
             ])?,
         );
-        assert_eq!(total_bytes_read, 33);
-        assert_eq!(num_records_read, 2);
+        assert_eq!(read_stats.total_records_read, 20);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_max_bytes() -> DaftResult<()> {
-        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+    fn test_csv_schema_local_delimiter() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_bar_delimiter.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, total_bytes_read, num_records_read, _, _) = read_csv_schema(
+        let (schema, read_stats) = read_csv_schema(
             file.as_ref(),
-            true,
+            Some(CsvParseOptions::default().with_delimiter(b'|')),
+            None,
+            None,
             None,
-            Some(100),
             io_client.clone(),
             None,
         )?;
@@ -484,58 +950,615 @@ mod tests {
                 Field::new("variety", DataType::Utf8),
             ])?,
         );
-        // Max bytes doesn't include header, so add 15 bytes to upper bound.
-        assert!(total_bytes_read <= 100 + 15, "{}", total_bytes_read);
-        assert!(num_records_read <= 10, "{}", num_records_read);
+        assert_eq!(read_stats.total_bytes_read, 328);
+        assert_eq!(read_stats.total_records_read, 20);
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_invalid_column_header_mismatch() -> DaftResult<()> {
-        let file = format!(
-            "{}/test/iris_tiny_invalid_header_cols_mismatch.csv",
-            env!("CARGO_MANIFEST_DIR"),
-        );
+    fn test_csv_schema_local_read_stats() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let err = read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None);
-        assert!(err.is_err());
-        let err = err.unwrap_err();
-        assert!(matches!(err, DaftError::ArrowError(_)), "{}", err);
-        assert!(
-            err.to_string()
-                .contains("found record with 4 fields, but the previous record has 5 fields"),
-            "{}",
-            err
-        );
+        let (_, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(read_stats.total_bytes_read, 328);
+        assert_eq!(read_stats.total_records_read, 20);
+        assert!(read_stats.read_whole_file);
+        assert!(read_stats.borderline_columns.is_empty());
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_schema_local_invalid_no_header_variable_num_cols() -> DaftResult<()> {
-        let file = format!(
-            "{}/test/iris_tiny_invalid_no_header_variable_num_cols.csv",
-            env!("CARGO_MANIFEST_DIR"),
-        );
-
+    fn test_csv_schema_local_sample_limit_flags() -> DaftResult<()> {
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let err = read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None);
-        assert!(err.is_err());
-        let err = err.unwrap_err();
-        assert!(matches!(err, DaftError::ArrowError(_)), "{}", err);
-        assert!(
-            err.to_string()
-                .contains("found record with 5 fields, but the previous record has 4 fields"),
-            "{}",
-            err
+        // The file is larger than the sample, so inference stops before its end.
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+        let (_, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            Some(100),
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert!(!read_stats.read_whole_file);
+
+        // Types inferred from a single record are borderline.
+        let (_, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            Some(1),
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert!(!read_stats.read_whole_file);
+        assert_eq!(
+            read_stats.borderline_columns,
+            vec![
+                "sepal.length",
+                "sepal.width",
+                "petal.length",
+                "petal.width",
+                "variety"
+            ]
+        );
+
+        // As are the types of columns without any non-null values.
+        let file = format!(
+            "{}/test/iris_tiny_all_null_column.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+        let (_, read_stats) =
+            read_csv_schema(file.as_ref(), None, None, None, None, io_client, None)?;
+        assert!(read_stats.read_whole_file);
+        assert_eq!(read_stats.borderline_columns, vec!["petal.length"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_no_headers() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_no_headers.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            Some(CsvParseOptions::default().with_has_header(false)),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("column_1", DataType::Float64),
+                Field::new("column_2", DataType::Float64),
+                Field::new("column_3", DataType::Float64),
+                Field::new("column_4", DataType::Float64),
+                Field::new("column_5", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_bytes_read, 328);
+        assert_eq!(read_stats.total_records_read, 20);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_boolean_tokens() -> DaftResult<()> {
+        let file = format!("{}/test/yes_no_flags.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Without tokens, Y/N is inferred as a string column.
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("name", DataType::Utf8),
+                Field::new("flag", DataType::Utf8),
+            ])?,
+        );
+
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            Some(
+                CsvConvertOptions::default()
+                    .with_true_values(Some(vec!["Y".into()]))
+                    .with_false_values(Some(vec!["N".into()])),
+            ),
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("name", DataType::Utf8),
+                Field::new("flag", DataType::Boolean),
+            ])?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_comment_lines_skipped() -> DaftResult<()> {
+        let file = format!("{}/test/commented_lines.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Comment lines before the header are skipped, so the header is read from the first non-comment line.
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            Some(CsvParseOptions::default().with_comment(Some(b'#'))),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("label", DataType::Utf8),
+                Field::new("value", DataType::Float64),
+            ])?,
+        );
+        assert_eq!(read_stats.total_records_read, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_max_rows() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (_, full_read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            Some(5),
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        // Inference stops at the row cap well before the default 1 MiB byte budget is exhausted.
+        assert_eq!(read_stats.total_records_read, 5);
+        assert!(read_stats.total_bytes_read < full_read_stats.total_bytes_read);
+        assert!(read_stats.total_bytes_read < 1024 * 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_skip_rows() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_banner_rows.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            Some(CsvParseOptions::default().with_skip_rows(3)),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_records_read, 20);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_null_values() -> DaftResult<()> {
+        let file = format!("{}/test/null_tokens.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            Some(CsvConvertOptions::default().with_null_values(Some(vec![
+                "NA".into(),
+                "N/A".into(),
+                "null".into(),
+            ]))),
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("score", DataType::Float64),
+                Field::new("label", DataType::Utf8),
+            ])?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_dates() -> DaftResult<()> {
+        let file = format!("{}/test/dates.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        // A column of pure dates infers as a date, while dates mixed with date-times infer as a timestamp.
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("pure_date", DataType::Date),
+                Field::new("mixed", DataType::Timestamp(TimeUnit::Seconds, None)),
+            ])?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_empty_lines_skipped() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_empty_lines.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_bytes_read, 49);
+        assert_eq!(read_stats.total_records_read, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_empty_file() -> DaftResult<()> {
+        let file = format!("{}/test/empty.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let (schema, read_stats) =
+            read_csv_schema(file.as_ref(), None, None, None, None, io_client, None)?;
+        assert_eq!(schema, Schema::empty());
+        assert_eq!(read_stats.total_records_read, 0);
+        assert!(read_stats.read_whole_file);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_header_only() -> DaftResult<()> {
+        let file = format!("{}/test/header_only.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let (schema, read_stats) =
+            read_csv_schema(file.as_ref(), None, None, None, None, io_client, None)?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Utf8),
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_records_read, 0);
+        assert!(read_stats.read_whole_file);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_nulls() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny_nulls.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_bytes_read, 82);
+        assert_eq!(read_stats.total_records_read, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_conflicting_types_utf8_fallback() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_conflicting_dtypes.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                // All conflicting dtypes fall back to UTF8.
+                Field::new("sepal.length", DataType::Utf8),
+                Field::new("sepal.width", DataType::Utf8),
+                Field::new("petal.length", DataType::Utf8),
+                Field::new("petal.width", DataType::Utf8),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_bytes_read, 33);
+        assert_eq!(read_stats.total_records_read, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_max_bytes() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            None,
+            Some(100),
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        // Max bytes doesn't include header, so add 15 bytes to upper bound.
+        assert!(
+            read_stats.total_bytes_read <= 100 + 15,
+            "{}",
+            read_stats.total_bytes_read
+        );
+        assert!(
+            read_stats.total_records_read <= 10,
+            "{}",
+            read_stats.total_records_read
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_invalid_column_header_mismatch() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_invalid_header_cols_mismatch.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        );
+        assert!(err.is_err());
+        let err = err.unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DaftError::External(source) if matches!(
+                    source.downcast_ref::<crate::Error>(),
+                    Some(crate::Error::FieldCountMismatch { expected: 5, found: 4, .. })
+                )
+            ),
+            "{}",
+            err
+        );
+        assert!(
+            err.to_string()
+                .contains("found record with 4 fields, but the previous record has 5 fields"),
+            "{}",
+            err
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_schema_local_invalid_no_header_variable_num_cols() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_invalid_no_header_variable_num_cols.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        );
+        assert!(err.is_err());
+        let err = err.unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DaftError::External(source) if matches!(
+                    source.downcast_ref::<crate::Error>(),
+                    Some(crate::Error::FieldCountMismatch { expected: 4, found: 5, .. })
+                )
+            ),
+            "{}",
+            err
+        );
+        assert!(
+            err.to_string()
+                .contains("found record with 5 fields, but the previous record has 4 fields"),
+            "{}",
+            err
         );
 
         Ok(())
@@ -574,8 +1597,15 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let (schema, _, _, _, _) =
-            read_csv_schema(file.as_ref(), true, None, None, io_client.clone(), None)?;
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+        )?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -0,0 +1,1229 @@
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::Field,
+    impl_bincode_py_state_serialization,
+    schema::{Schema, SchemaRef},
+    DataType,
+};
+use daft_dsl::Expr;
+use serde::{Deserialize, Serialize};
+
+use crate::inference::resolve_duplicate_column_names;
+#[cfg(feature = "python")]
+use {
+    daft_core::python::{datatype::PyDataType, schema::PySchema},
+    daft_dsl::python::PyExpr,
+    pyo3::{
+        exceptions::PyValueError, pyclass, pymethods, types::PyBytes, IntoPy, PyObject, PyResult,
+        PyTypeInfo, Python, ToPyObject,
+    },
+};
+
+/// Options for converting CSV data to Daft data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct CsvConvertOptions {
+    pub limit: Option<usize>,
+    pub include_columns: Option<Vec<String>>,
+    pub column_names: Option<Vec<String>>,
+    pub schema: Option<SchemaRef>,
+    pub include_columns_as: Option<Vec<(String, String)>>,
+    pub thousands_separator: Option<u8>,
+    pub parentheses_as_negative: bool,
+    pub null_values: Option<Vec<String>>,
+    pub true_values: Option<Vec<String>>,
+    pub false_values: Option<Vec<String>>,
+    pub all_strings: bool,
+    pub dtype_overrides: Option<Vec<(String, DataType)>>,
+    pub predicate: Option<Vec<Expr>>,
+    pub include_column_indices: Option<Vec<usize>>,
+    pub date_format: Option<String>,
+    pub timestamp_format: Option<String>,
+    pub case_insensitive_columns: bool,
+    pub dedup_column_names: bool,
+    pub infer_decimals: bool,
+    pub decimal_scales: Option<Vec<(String, usize)>>,
+    pub non_nullable_columns: Option<Vec<String>>,
+}
+
+impl CsvConvertOptions {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_internal(
+        limit: Option<usize>,
+        include_columns: Option<Vec<String>>,
+        column_names: Option<Vec<String>>,
+        schema: Option<SchemaRef>,
+        include_columns_as: Option<Vec<(String, String)>>,
+        thousands_separator: Option<u8>,
+        parentheses_as_negative: bool,
+        null_values: Option<Vec<String>>,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        all_strings: bool,
+        dtype_overrides: Option<Vec<(String, DataType)>>,
+        predicate: Option<Vec<Expr>>,
+        include_column_indices: Option<Vec<usize>>,
+        date_format: Option<String>,
+        timestamp_format: Option<String>,
+        case_insensitive_columns: bool,
+        dedup_column_names: bool,
+        infer_decimals: bool,
+        decimal_scales: Option<Vec<(String, usize)>>,
+        non_nullable_columns: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            limit,
+            include_columns,
+            column_names,
+            schema,
+            include_columns_as,
+            thousands_separator,
+            parentheses_as_negative,
+            null_values,
+            true_values,
+            false_values,
+            all_strings,
+            dtype_overrides,
+            predicate,
+            include_column_indices,
+            date_format,
+            timestamp_format,
+            case_insensitive_columns,
+            dedup_column_names,
+            infer_decimals,
+            decimal_scales,
+            non_nullable_columns,
+        }
+    }
+
+    pub fn with_limit(self, limit: Option<usize>) -> Self {
+        Self { limit, ..self }
+    }
+
+    pub fn with_include_columns(self, include_columns: Option<Vec<String>>) -> Self {
+        Self {
+            include_columns,
+            ..self
+        }
+    }
+
+    pub fn with_column_names(self, column_names: Option<Vec<String>>) -> Self {
+        Self {
+            column_names,
+            ..self
+        }
+    }
+
+    pub fn with_schema(self, schema: Option<SchemaRef>) -> Self {
+        Self { schema, ..self }
+    }
+
+    pub fn with_include_columns_as(
+        self,
+        include_columns_as: Option<Vec<(String, String)>>,
+    ) -> Self {
+        Self {
+            include_columns_as,
+            ..self
+        }
+    }
+
+    pub fn with_null_values(self, null_values: Option<Vec<String>>) -> Self {
+        Self {
+            null_values,
+            ..self
+        }
+    }
+
+    pub fn with_true_values(self, true_values: Option<Vec<String>>) -> Self {
+        Self {
+            true_values,
+            ..self
+        }
+    }
+
+    pub fn with_false_values(self, false_values: Option<Vec<String>>) -> Self {
+        Self {
+            false_values,
+            ..self
+        }
+    }
+
+    pub fn with_all_strings(self, all_strings: bool) -> Self {
+        Self {
+            all_strings,
+            ..self
+        }
+    }
+
+    pub fn with_dtype_overrides(self, dtype_overrides: Option<Vec<(String, DataType)>>) -> Self {
+        Self {
+            dtype_overrides,
+            ..self
+        }
+    }
+
+    pub fn with_predicate(self, predicate: Option<Vec<Expr>>) -> Self {
+        Self { predicate, ..self }
+    }
+
+    pub fn with_include_column_indices(self, include_column_indices: Option<Vec<usize>>) -> Self {
+        Self {
+            include_column_indices,
+            ..self
+        }
+    }
+
+    pub fn with_date_format(self, date_format: Option<String>) -> Self {
+        Self {
+            date_format,
+            ..self
+        }
+    }
+
+    pub fn with_timestamp_format(self, timestamp_format: Option<String>) -> Self {
+        Self {
+            timestamp_format,
+            ..self
+        }
+    }
+
+    pub fn with_case_insensitive_columns(self, case_insensitive_columns: bool) -> Self {
+        Self {
+            case_insensitive_columns,
+            ..self
+        }
+    }
+
+    pub fn with_dedup_column_names(self, dedup_column_names: bool) -> Self {
+        Self {
+            dedup_column_names,
+            ..self
+        }
+    }
+
+    pub fn with_infer_decimals(self, infer_decimals: bool) -> Self {
+        Self {
+            infer_decimals,
+            ..self
+        }
+    }
+
+    pub fn with_decimal_scales(self, decimal_scales: Option<Vec<(String, usize)>>) -> Self {
+        Self {
+            decimal_scales,
+            ..self
+        }
+    }
+
+    pub fn with_non_nullable_columns(self, non_nullable_columns: Option<Vec<String>>) -> Self {
+        Self {
+            non_nullable_columns,
+            ..self
+        }
+    }
+
+    /// Returns the decimal scale hinted for the column named `name`, if any.
+    pub(crate) fn decimal_scale_of(&self, name: &str) -> Option<usize> {
+        self.decimal_scales
+            .as_ref()?
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, scale)| *scale)
+    }
+
+    /// Returns the schema of a headerless CSV read with `all_strings` set, with a string column for each of the
+    /// provided column names, or `None` if the schema has to be inferred.
+    pub fn all_strings_schema(
+        &self,
+        parse_options: &CsvParseOptions,
+    ) -> DaftResult<Option<Schema>> {
+        match &self.column_names {
+            Some(column_names) if self.all_strings && !parse_options.has_header => {
+                let mut column_names = column_names.clone();
+                resolve_duplicate_column_names(&mut column_names, self.dedup_column_names)?;
+                Ok(Some(Schema::new(
+                    column_names
+                        .iter()
+                        .map(|name| Field::new(name, DataType::Utf8))
+                        .collect(),
+                )?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvConvertOptions {
+    /// Create conversion settings for the CSV reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Only read this many rows.
+    /// * `include_columns` - The names of the columns that should be kept, e.g. via a projection.
+    /// * `column_names` - The names for the CSV columns.
+    /// * `schema` - The names and dtypes for the CSV columns.
+    /// * `include_columns_as` - Pairs of (source name, output name) to both project and rename
+    ///   columns in a single pass; the output follows the order of this list.
+    /// * `thousands_separator` - Character grouping digits in numeric columns, e.g. "," for `1,234.56`.
+    /// * `parentheses_as_negative` - Whether to read a parenthesized value in a numeric column, e.g. `(123.45)`,
+    ///   as a negative number.
+    /// * `null_values` - Tokens that should be read as null, e.g. `["NA", "N/A"]`; only whole fields are matched.
+    /// * `true_values` - Tokens that should be read as `true` in boolean columns, e.g. `["Y", "yes"]`.
+    /// * `false_values` - Tokens that should be read as `false` in boolean columns, e.g. `["N", "no"]`.
+    /// * `all_strings` - For headerless CSVs with `column_names`, read every column as a string without inferring
+    ///   the schema.
+    /// * `dtype_overrides` - Pairs of (column name, dtype) forcing the dtypes of some columns, e.g. to read a zip code
+    ///   column as strings so that leading zeros are kept; all other columns keep their inferred dtypes.
+    /// * `predicate` - Filter expressions that rows must all satisfy to be kept; `limit` applies to the rows that
+    ///   satisfy them.
+    /// * `include_column_indices` - The (0-based) positions of the columns that should be kept, as an alternative to
+    ///   `include_columns`, e.g. for headerless CSVs without `column_names`.
+    /// * `date_format` - A chrono format string for dates that aren't in ISO 8601 format, e.g. "%d/%m/%Y"; values of a
+    ///   date column that don't match it are read as null.
+    /// * `timestamp_format` - A chrono format string for timestamps without a timezone that aren't recognized by
+    ///   default, e.g. "%Y/%m/%d %H:%M"; values of a timestamp column that don't match it are read as null.
+    /// * `case_insensitive_columns` - Whether `include_columns` and `include_columns_as` match the CSV column names
+    ///   regardless of case, e.g. for files with inconsistently cased headers; names that match several columns
+    ///   differing only by case are an error.
+    /// * `dedup_column_names` - Whether repeated column names are disambiguated with numeric suffixes, e.g. a header
+    ///   of `id,id,value` is read as columns `id`, `id_1` and `value`; otherwise, repeated names are an error.
+    /// * `infer_decimals` - Whether to infer columns of decimal numbers with a consistent number of fractional digits,
+    ///   e.g. `123.45`, as exact decimals rather than as floats.
+    /// * `decimal_scales` - Pairs of (column name, scale) inferring those columns as decimals with the given number of
+    ///   fractional digits, e.g. to read prices exactly; values that overflow the inferred precision or have more
+    ///   fractional digits than the scale are read as null.
+    /// * `non_nullable_columns` - The names of the columns that are declared non-nullable, e.g. to enforce a schema's
+    ///   constraints; empty cells, null tokens and unparseable values in them are an error rather than read as null.
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (
+        limit=None,
+        include_columns=None,
+        column_names=None,
+        schema=None,
+        include_columns_as=None,
+        thousands_separator=None,
+        parentheses_as_negative=false,
+        null_values=None,
+        true_values=None,
+        false_values=None,
+        all_strings=false,
+        dtype_overrides=None,
+        predicate=None,
+        include_column_indices=None,
+        date_format=None,
+        timestamp_format=None,
+        case_insensitive_columns=false,
+        dedup_column_names=true,
+        infer_decimals=false,
+        decimal_scales=None,
+        non_nullable_columns=None,
+    ))]
+    pub fn new(
+        limit: Option<usize>,
+        include_columns: Option<Vec<String>>,
+        column_names: Option<Vec<String>>,
+        schema: Option<PySchema>,
+        include_columns_as: Option<Vec<(String, String)>>,
+        thousands_separator: Option<&str>,
+        parentheses_as_negative: bool,
+        null_values: Option<Vec<String>>,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        all_strings: bool,
+        dtype_overrides: Option<Vec<(String, PyDataType)>>,
+        predicate: Option<Vec<PyExpr>>,
+        include_column_indices: Option<Vec<usize>>,
+        date_format: Option<String>,
+        timestamp_format: Option<String>,
+        case_insensitive_columns: bool,
+        dedup_column_names: bool,
+        infer_decimals: bool,
+        decimal_scales: Option<Vec<(String, usize)>>,
+        non_nullable_columns: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        Ok(Self::new_internal(
+            limit,
+            include_columns,
+            column_names,
+            schema.map(|s| s.schema),
+            include_columns_as,
+            str_to_byte(thousands_separator, "thousands_separator")?,
+            parentheses_as_negative,
+            null_values,
+            true_values,
+            false_values,
+            all_strings,
+            dtype_overrides.map(|overrides| {
+                overrides
+                    .into_iter()
+                    .map(|(name, dtype)| (name, dtype.into()))
+                    .collect()
+            }),
+            predicate.map(|exprs| exprs.into_iter().map(|e| e.into()).collect()),
+            include_column_indices,
+            date_format,
+            timestamp_format,
+            case_insensitive_columns,
+            dedup_column_names,
+            infer_decimals,
+            decimal_scales,
+            non_nullable_columns,
+        ))
+    }
+
+    #[getter]
+    pub fn get_limit(&self) -> PyResult<Option<usize>> {
+        Ok(self.limit)
+    }
+
+    #[getter]
+    pub fn get_include_columns(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.include_columns.clone())
+    }
+
+    #[getter]
+    pub fn get_column_names(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.column_names.clone())
+    }
+
+    #[getter]
+    pub fn get_schema(&self) -> PyResult<Option<PySchema>> {
+        Ok(self.schema.as_ref().map(|s| s.clone().into()))
+    }
+
+    #[getter]
+    pub fn get_include_columns_as(&self) -> PyResult<Option<Vec<(String, String)>>> {
+        Ok(self.include_columns_as.clone())
+    }
+
+    #[getter]
+    pub fn get_thousands_separator(&self) -> PyResult<Option<String>> {
+        Ok(self
+            .thousands_separator
+            .map(|sep| char::from(sep).to_string()))
+    }
+
+    #[getter]
+    pub fn get_parentheses_as_negative(&self) -> PyResult<bool> {
+        Ok(self.parentheses_as_negative)
+    }
+
+    #[getter]
+    pub fn get_null_values(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.null_values.clone())
+    }
+
+    #[getter]
+    pub fn get_true_values(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.true_values.clone())
+    }
+
+    #[getter]
+    pub fn get_false_values(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.false_values.clone())
+    }
+
+    #[getter]
+    pub fn get_all_strings(&self) -> PyResult<bool> {
+        Ok(self.all_strings)
+    }
+
+    #[getter]
+    pub fn get_dtype_overrides(&self) -> PyResult<Option<Vec<(String, PyDataType)>>> {
+        Ok(self.dtype_overrides.as_ref().map(|overrides| {
+            overrides
+                .iter()
+                .map(|(name, dtype)| (name.clone(), dtype.clone().into()))
+                .collect()
+        }))
+    }
+
+    #[getter]
+    pub fn get_predicate(&self) -> PyResult<Option<Vec<PyExpr>>> {
+        Ok(self
+            .predicate
+            .as_ref()
+            .map(|exprs| exprs.iter().map(|e| e.clone().into()).collect()))
+    }
+
+    #[getter]
+    pub fn get_include_column_indices(&self) -> PyResult<Option<Vec<usize>>> {
+        Ok(self.include_column_indices.clone())
+    }
+
+    #[getter]
+    pub fn get_date_format(&self) -> PyResult<Option<String>> {
+        Ok(self.date_format.clone())
+    }
+
+    #[getter]
+    pub fn get_timestamp_format(&self) -> PyResult<Option<String>> {
+        Ok(self.timestamp_format.clone())
+    }
+
+    #[getter]
+    pub fn get_case_insensitive_columns(&self) -> PyResult<bool> {
+        Ok(self.case_insensitive_columns)
+    }
+
+    #[getter]
+    pub fn get_dedup_column_names(&self) -> PyResult<bool> {
+        Ok(self.dedup_column_names)
+    }
+
+    #[getter]
+    pub fn get_infer_decimals(&self) -> PyResult<bool> {
+        Ok(self.infer_decimals)
+    }
+
+    #[getter]
+    pub fn get_decimal_scales(&self) -> PyResult<Option<Vec<(String, usize)>>> {
+        Ok(self.decimal_scales.clone())
+    }
+
+    #[getter]
+    pub fn get_non_nullable_columns(&self) -> PyResult<Option<Vec<String>>> {
+        Ok(self.non_nullable_columns.clone())
+    }
+}
+
+impl Default for CsvConvertOptions {
+    fn default() -> Self {
+        Self::new_internal(
+            None, None, None, None, None, None, false, None, None, None, false, None, None, None,
+            None, None, false, true, false, None, None,
+        )
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvConvertOptions);
+
+/// How the CSV reader handles null bytes (`\0`) embedded in fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NullByteHandling {
+    /// Fail the read on fields containing null bytes.
+    #[default]
+    Error,
+    /// Remove null bytes from fields.
+    Strip,
+    /// Replace each null byte in a field with the provided character.
+    Replace(char),
+}
+
+impl NullByteHandling {
+    /// Strips or replaces the null bytes of `bytes`.
+    pub(crate) fn clean(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut replacement = [0u8; 4];
+        let replacement: &[u8] = match self {
+            Self::Replace(c) => c.encode_utf8(&mut replacement).as_bytes(),
+            Self::Error | Self::Strip => &[],
+        };
+        let mut cleaned = Vec::with_capacity(bytes.len());
+        for b in bytes {
+            if *b == 0 {
+                cleaned.extend_from_slice(replacement);
+            } else {
+                cleaned.push(*b);
+            }
+        }
+        cleaned
+    }
+}
+
+/// Null byte handling is exposed to Python as "error", "strip", or the single replacement character.
+#[cfg(feature = "python")]
+impl NullByteHandling {
+    fn from_py_str(value: &str) -> PyResult<Self> {
+        let mut chars = value.chars();
+        match (value, chars.next(), chars.next()) {
+            ("error", ..) => Ok(Self::Error),
+            ("strip", ..) => Ok(Self::Strip),
+            (_, Some(c), None) => Ok(Self::Replace(c)),
+            _ => Err(PyValueError::new_err(format!(
+                "null_byte_handling must be \"error\", \"strip\", or a single replacement character, but got {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+impl IntoPy<PyObject> for NullByteHandling {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            Self::Error => "error".to_string(),
+            Self::Strip => "strip".to_string(),
+            Self::Replace(c) => c.to_string(),
+        }
+        .into_py(py)
+    }
+}
+
+/// Character encoding of CSV files, which are transcoded to UTF-8 as they're read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CsvEncoding {
+    #[default]
+    Utf8,
+    /// UTF-16 in little-endian byte order, unless a byte order mark says otherwise.
+    Utf16Le,
+    /// UTF-16 in big-endian byte order, unless a byte order mark says otherwise.
+    Utf16Be,
+    /// ISO-8859-1.
+    Latin1,
+}
+
+/// Encodings are exposed to Python by their usual names, e.g. "utf-16le".
+#[cfg(feature = "python")]
+impl CsvEncoding {
+    fn from_py_str(value: &str) -> PyResult<Self> {
+        match value.to_lowercase().replace('_', "-").as_str() {
+            "utf-8" | "utf8" => Ok(Self::Utf8),
+            "utf-16le" | "utf-16-le" => Ok(Self::Utf16Le),
+            "utf-16be" | "utf-16-be" => Ok(Self::Utf16Be),
+            "latin-1" | "latin1" | "iso-8859-1" => Ok(Self::Latin1),
+            _ => Err(PyValueError::new_err(format!(
+                "encoding must be one of \"utf-8\", \"utf-16le\", \"utf-16be\" or \"latin-1\", but got {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+impl IntoPy<PyObject> for CsvEncoding {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+            Self::Latin1 => "latin-1",
+        }
+        .into_py(py)
+    }
+}
+
+/// Options for parsing CSV files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct CsvParseOptions {
+    pub has_header: bool,
+    pub delimiter: u8,
+    pub escape: Option<u8>,
+    pub comment: Option<u8>,
+    pub skip_rows: usize,
+    pub line_terminator: Option<u8>,
+    pub double_quote: bool,
+    pub null_byte_handling: NullByteHandling,
+    pub trim: bool,
+    pub flexible: bool,
+    pub encoding: Option<CsvEncoding>,
+}
+
+impl CsvParseOptions {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_internal(
+        has_header: bool,
+        delimiter: u8,
+        escape: Option<u8>,
+        comment: Option<u8>,
+        skip_rows: usize,
+        line_terminator: Option<u8>,
+        double_quote: bool,
+        null_byte_handling: NullByteHandling,
+        trim: bool,
+        flexible: bool,
+        encoding: Option<CsvEncoding>,
+    ) -> Self {
+        Self {
+            has_header,
+            delimiter,
+            escape,
+            comment,
+            skip_rows,
+            line_terminator,
+            double_quote,
+            null_byte_handling,
+            trim,
+            flexible,
+            encoding,
+        }
+    }
+
+    pub fn with_has_header(self, has_header: bool) -> Self {
+        Self { has_header, ..self }
+    }
+
+    pub fn with_delimiter(self, delimiter: u8) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    pub fn with_escape(self, escape: Option<u8>) -> Self {
+        Self { escape, ..self }
+    }
+
+    pub fn with_comment(self, comment: Option<u8>) -> Self {
+        Self { comment, ..self }
+    }
+
+    pub fn with_skip_rows(self, skip_rows: usize) -> Self {
+        Self { skip_rows, ..self }
+    }
+
+    pub fn with_line_terminator(self, line_terminator: Option<u8>) -> Self {
+        Self {
+            line_terminator,
+            ..self
+        }
+    }
+
+    pub fn with_double_quote(self, double_quote: bool) -> Self {
+        Self {
+            double_quote,
+            ..self
+        }
+    }
+
+    pub fn with_null_byte_handling(self, null_byte_handling: NullByteHandling) -> Self {
+        Self {
+            null_byte_handling,
+            ..self
+        }
+    }
+
+    pub fn with_trim(self, trim: bool) -> Self {
+        Self { trim, ..self }
+    }
+
+    pub fn with_flexible(self, flexible: bool) -> Self {
+        Self { flexible, ..self }
+    }
+
+    pub fn with_encoding(self, encoding: Option<CsvEncoding>) -> Self {
+        Self { encoding, ..self }
+    }
+
+    /// The record terminator of the CSV parser: the configured line terminator byte, or CRLF-tolerant parsing by
+    /// default, where any of `\r`, `\n` or `\r\n` ends a record.
+    pub(crate) fn terminator(&self) -> csv_async::Terminator {
+        self.line_terminator
+            .map_or(csv_async::Terminator::CRLF, csv_async::Terminator::Any)
+    }
+}
+
+impl Default for CsvParseOptions {
+    fn default() -> Self {
+        Self::new_internal(
+            true,
+            b',',
+            None,
+            None,
+            0,
+            None,
+            true,
+            NullByteHandling::default(),
+            false,
+            false,
+            None,
+        )
+    }
+}
+
+#[cfg(feature = "python")]
+fn str_to_byte(value: Option<&str>, name: &str) -> PyResult<Option<u8>> {
+    value
+        .map(|s| match s.as_bytes() {
+            &[c] => Ok(c),
+            _ => Err(PyValueError::new_err(format!(
+                "{} must be a single-character string, but got {}",
+                name, s
+            ))),
+        })
+        .transpose()
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvParseOptions {
+    /// Create parsing settings for the CSV reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `has_header` - Whether the CSV has a header row; if so, it will be skipped during data parsing.
+    /// * `delimiter` - The character delmiting individual cells in the CSV data.
+    /// * `escape` - The character used to escape quotes within quoted cells, e.g. a backslash; if not provided, quotes are
+    ///   escaped by doubling them.
+    /// * `comment` - If provided, lines starting with this character, e.g. `#`, are skipped.
+    /// * `skip_rows` - Number of raw lines to discard at the start of the file, before the header row (if any).
+    /// * `line_terminator` - The character terminating records, e.g. `\r`; if not provided, any of `\r`, `\n` or `\r\n`
+    ///   terminates a record. Terminators within quoted cells don't terminate the record.
+    /// * `double_quote` - Whether two consecutive quotes within a quoted cell are read as a single escaped quote; if
+    ///   disabled, the first of them ends the quoted part of the cell, and the rest of the cell is read literally.
+    /// * `null_byte_handling` - How null bytes (`\0`) embedded in cells are handled: "error" to fail the read, "strip"
+    ///   to remove them, or a single character to replace them with.
+    /// * `trim` - Whether to trim leading and trailing whitespace from cells, e.g. to read ` 1.5 ` as a number; the
+    ///   contents of quoted cells are kept as-is.
+    /// * `flexible` - Whether to tolerate records with a different number of cells than the schema, padding short
+    ///   records with nulls and dropping the extra trailing cells of long records, rather than failing the read.
+    /// * `encoding` - The character encoding of the CSV, one of "utf-8", "utf-16le", "utf-16be" or "latin-1"; if not
+    ///   provided, the CSV is read as UTF-8. A leading byte order mark is stripped.
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (has_header=true, delimiter=None, escape=None, comment=None, skip_rows=0, line_terminator=None, double_quote=true, null_byte_handling="error", trim=false, flexible=false, encoding=None))]
+    pub fn new(
+        has_header: bool,
+        delimiter: Option<&str>,
+        escape: Option<&str>,
+        comment: Option<&str>,
+        skip_rows: usize,
+        line_terminator: Option<&str>,
+        double_quote: bool,
+        null_byte_handling: &str,
+        trim: bool,
+        flexible: bool,
+        encoding: Option<&str>,
+    ) -> PyResult<Self> {
+        Ok(Self::new_internal(
+            has_header,
+            str_to_byte(delimiter, "delimiter")?.unwrap_or(b','),
+            str_to_byte(escape, "escape")?,
+            str_to_byte(comment, "comment")?,
+            skip_rows,
+            str_to_byte(line_terminator, "line_terminator")?,
+            double_quote,
+            NullByteHandling::from_py_str(null_byte_handling)?,
+            trim,
+            flexible,
+            encoding.map(CsvEncoding::from_py_str).transpose()?,
+        ))
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvParseOptions);
+
+/// Callback invoked with the cumulative number of bytes read from a CSV file as the read progresses, e.g. to drive a
+/// progress bar.
+#[derive(Clone)]
+pub struct ProgressCallback(pub Arc<dyn Fn(usize) + Send + Sync>);
+
+impl ProgressCallback {
+    pub(crate) fn report(&self, bytes_read: usize) {
+        (self.0)(bytes_read)
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+// Callbacks are compared and hashed by identity.
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ProgressCallback {}
+
+impl Hash for ProgressCallback {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const () as usize).hash(state)
+    }
+}
+
+/// Rayon thread pool that the chunks of CSV reads are parsed on, e.g. to confine parsing to a dedicated pool when Daft
+/// is embedded in another application.
+#[derive(Clone)]
+pub struct ParseThreadPool(pub Arc<rayon::ThreadPool>);
+
+impl ParseThreadPool {
+    pub(crate) fn spawn<F: FnOnce() + Send + 'static>(&self, f: F) {
+        self.0.spawn(f)
+    }
+}
+
+impl Debug for ParseThreadPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ParseThreadPool")
+    }
+}
+
+// Thread pools are compared and hashed by identity.
+impl PartialEq for ParseThreadPool {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ParseThreadPool {}
+
+impl Hash for ParseThreadPool {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as usize).hash(state)
+    }
+}
+
+/// Options for reading CSV files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct CsvReadOptions {
+    pub buffer_size: Option<usize>,
+    pub chunk_size: Option<usize>,
+    pub inference_max_rows: Option<usize>,
+    pub max_bytes: Option<usize>,
+    pub max_chunks_in_flight: Option<usize>,
+    pub mean_row_size_hint: Option<usize>,
+    pub stddev_row_size_hint: Option<usize>,
+    pub start_byte: Option<usize>,
+    pub reuse_record_buffers: bool,
+    pub reuse_first_schema: bool,
+    pub strict_schema: bool,
+    pub skip_empty_files: bool,
+    pub compute_statistics: bool,
+    // Progress callbacks are process-local, so they're dropped when the options are serialized.
+    #[serde(skip)]
+    pub progress: Option<ProgressCallback>,
+    // Thread pools are process-local too, so serialized options parse on the global pool.
+    #[serde(skip)]
+    pub thread_pool: Option<ParseThreadPool>,
+}
+
+impl CsvReadOptions {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_internal(
+        buffer_size: Option<usize>,
+        chunk_size: Option<usize>,
+        inference_max_rows: Option<usize>,
+        max_bytes: Option<usize>,
+        max_chunks_in_flight: Option<usize>,
+        mean_row_size_hint: Option<usize>,
+        stddev_row_size_hint: Option<usize>,
+        start_byte: Option<usize>,
+        reuse_record_buffers: bool,
+        reuse_first_schema: bool,
+        strict_schema: bool,
+        skip_empty_files: bool,
+        compute_statistics: bool,
+    ) -> Self {
+        Self {
+            buffer_size,
+            chunk_size,
+            inference_max_rows,
+            max_bytes,
+            max_chunks_in_flight,
+            mean_row_size_hint,
+            stddev_row_size_hint,
+            start_byte,
+            reuse_record_buffers,
+            reuse_first_schema,
+            strict_schema,
+            skip_empty_files,
+            compute_statistics,
+            progress: None,
+            thread_pool: None,
+        }
+    }
+
+    pub fn with_buffer_size(self, buffer_size: Option<usize>) -> Self {
+        Self {
+            buffer_size,
+            ..self
+        }
+    }
+
+    pub fn with_chunk_size(self, chunk_size: Option<usize>) -> Self {
+        Self { chunk_size, ..self }
+    }
+
+    pub fn with_inference_max_rows(self, inference_max_rows: Option<usize>) -> Self {
+        Self {
+            inference_max_rows,
+            ..self
+        }
+    }
+
+    pub fn with_max_bytes(self, max_bytes: Option<usize>) -> Self {
+        Self { max_bytes, ..self }
+    }
+
+    pub fn with_max_chunks_in_flight(self, max_chunks_in_flight: Option<usize>) -> Self {
+        Self {
+            max_chunks_in_flight,
+            ..self
+        }
+    }
+
+    pub fn with_mean_row_size_hint(self, mean_row_size_hint: Option<usize>) -> Self {
+        Self {
+            mean_row_size_hint,
+            ..self
+        }
+    }
+
+    pub fn with_stddev_row_size_hint(self, stddev_row_size_hint: Option<usize>) -> Self {
+        Self {
+            stddev_row_size_hint,
+            ..self
+        }
+    }
+
+    pub fn with_start_byte(self, start_byte: Option<usize>) -> Self {
+        Self { start_byte, ..self }
+    }
+
+    pub fn with_reuse_record_buffers(self, reuse_record_buffers: bool) -> Self {
+        Self {
+            reuse_record_buffers,
+            ..self
+        }
+    }
+
+    pub fn with_reuse_first_schema(self, reuse_first_schema: bool) -> Self {
+        Self {
+            reuse_first_schema,
+            ..self
+        }
+    }
+
+    pub fn with_strict_schema(self, strict_schema: bool) -> Self {
+        Self {
+            strict_schema,
+            ..self
+        }
+    }
+
+    pub fn with_skip_empty_files(self, skip_empty_files: bool) -> Self {
+        Self {
+            skip_empty_files,
+            ..self
+        }
+    }
+
+    pub fn with_compute_statistics(self, compute_statistics: bool) -> Self {
+        Self {
+            compute_statistics,
+            ..self
+        }
+    }
+
+    pub fn with_progress(self, progress: Option<Arc<dyn Fn(usize) + Send + Sync>>) -> Self {
+        Self {
+            progress: progress.map(ProgressCallback),
+            ..self
+        }
+    }
+
+    pub fn with_thread_pool(self, thread_pool: Option<Arc<rayon::ThreadPool>>) -> Self {
+        Self {
+            thread_pool: thread_pool.map(ParseThreadPool),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvReadOptions {
+    /// Create reading settings for the CSV reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_size` - Size of the buffer (in bytes) used by the streaming reader.
+    /// * `chunk_size` - Size of the chunks (in bytes) deserialized in parallel by the streaming reader.
+    /// * `inference_max_rows` - Maximum number of rows read during schema inference, in addition to the byte budget.
+    /// * `max_bytes` - Maximum number of (uncompressed) bytes read from the file, e.g. to sample a large file. Only the
+    ///   records that end within that many bytes are read.
+    /// * `max_chunks_in_flight` - Maximum number of chunks being read or parsed at any given time, which defaults to
+    ///   twice the number of cores.
+    /// * `mean_row_size_hint` - Expected mean size (in bytes) of the rows, used to size chunks when a schema is
+    ///   provided, as no rows are sampled for schema inference then.
+    /// * `stddev_row_size_hint` - Expected standard deviation (in bytes) of the row sizes, used alongside
+    ///   `mean_row_size_hint`.
+    /// * `start_byte` - Offset (in bytes) of the (uncompressed) data to start reading from, e.g. the end of a chunk of
+    ///   a previous streaming read to resume it. Unless it's 0, the read starts at the first line at or after it, and
+    ///   the file's header and skipped rows aren't read.
+    /// * `reuse_record_buffers` - Whether the buffers of parsed chunks are recycled for later chunks of the read (and of
+    ///   the other files of a bulk read), rather than allocated afresh for every chunk, e.g. to reduce allocations when
+    ///   reading many small files.
+    /// * `reuse_first_schema` - Whether a read of multiple files infers the schema of the first file only, and reads
+    ///   all files with it, rather than inferring the schema of every file. Defaults to true.
+    /// * `strict_schema` - Whether a read that reuses the schema of the first file checks it against the inferred
+    ///   schemas of a sample of the other files, and fails on a mismatch.
+    /// * `skip_empty_files` - Whether a read of multiple files skips the files that are empty, rather than failing on
+    ///   them, since they have no header to infer a schema from. Defaults to true. With a provided schema, empty files
+    ///   are read as zero rows instead.
+    /// * `compute_statistics` - Whether the bounds and the null and distinct counts of the columns that are read are
+    ///   computed, which takes another pass over the data.
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (
+        buffer_size=None,
+        chunk_size=None,
+        inference_max_rows=None,
+        max_bytes=None,
+        max_chunks_in_flight=None,
+        mean_row_size_hint=None,
+        stddev_row_size_hint=None,
+        start_byte=None,
+        reuse_record_buffers=false,
+        reuse_first_schema=true,
+        strict_schema=false,
+        skip_empty_files=true,
+        compute_statistics=false
+    ))]
+    pub fn new(
+        buffer_size: Option<usize>,
+        chunk_size: Option<usize>,
+        inference_max_rows: Option<usize>,
+        max_bytes: Option<usize>,
+        max_chunks_in_flight: Option<usize>,
+        mean_row_size_hint: Option<usize>,
+        stddev_row_size_hint: Option<usize>,
+        start_byte: Option<usize>,
+        reuse_record_buffers: bool,
+        reuse_first_schema: bool,
+        strict_schema: bool,
+        skip_empty_files: bool,
+        compute_statistics: bool,
+    ) -> Self {
+        Self::new_internal(
+            buffer_size,
+            chunk_size,
+            inference_max_rows,
+            max_bytes,
+            max_chunks_in_flight,
+            mean_row_size_hint,
+            stddev_row_size_hint,
+            start_byte,
+            reuse_record_buffers,
+            reuse_first_schema,
+            strict_schema,
+            skip_empty_files,
+            compute_statistics,
+        )
+    }
+
+    #[getter]
+    pub fn get_buffer_size(&self) -> PyResult<Option<usize>> {
+        Ok(self.buffer_size)
+    }
+
+    #[getter]
+    pub fn get_chunk_size(&self) -> PyResult<Option<usize>> {
+        Ok(self.chunk_size)
+    }
+
+    #[getter]
+    pub fn get_inference_max_rows(&self) -> PyResult<Option<usize>> {
+        Ok(self.inference_max_rows)
+    }
+
+    #[getter]
+    pub fn get_max_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.max_bytes)
+    }
+
+    #[getter]
+    pub fn get_max_chunks_in_flight(&self) -> PyResult<Option<usize>> {
+        Ok(self.max_chunks_in_flight)
+    }
+
+    #[getter]
+    pub fn get_mean_row_size_hint(&self) -> PyResult<Option<usize>> {
+        Ok(self.mean_row_size_hint)
+    }
+
+    #[getter]
+    pub fn get_stddev_row_size_hint(&self) -> PyResult<Option<usize>> {
+        Ok(self.stddev_row_size_hint)
+    }
+
+    #[getter]
+    pub fn get_start_byte(&self) -> PyResult<Option<usize>> {
+        Ok(self.start_byte)
+    }
+
+    #[getter]
+    pub fn get_reuse_record_buffers(&self) -> PyResult<bool> {
+        Ok(self.reuse_record_buffers)
+    }
+
+    #[getter]
+    pub fn get_reuse_first_schema(&self) -> PyResult<bool> {
+        Ok(self.reuse_first_schema)
+    }
+
+    #[getter]
+    pub fn get_strict_schema(&self) -> PyResult<bool> {
+        Ok(self.strict_schema)
+    }
+
+    #[getter]
+    pub fn get_skip_empty_files(&self) -> PyResult<bool> {
+        Ok(self.skip_empty_files)
+    }
+
+    #[getter]
+    pub fn get_compute_statistics(&self) -> PyResult<bool> {
+        Ok(self.compute_statistics)
+    }
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self::new_internal(
+            None, None, None, None, None, None, None, None, false, true, false, true, false,
+        )
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvReadOptions);
+
+/// Options for writing Daft data to CSV.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct CsvWriteOptions {
+    pub float_precision: Option<usize>,
+}
+
+impl CsvWriteOptions {
+    pub fn new_internal(float_precision: Option<usize>) -> Self {
+        Self { float_precision }
+    }
+
+    pub fn with_float_precision(self, float_precision: Option<usize>) -> Self {
+        Self {
+            float_precision,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvWriteOptions {
+    /// Create writing settings for the CSV writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `float_precision` - Number of decimal places written for float columns, or None to write floats with full
+    ///   round-trip precision. Values are rounded when a precision is set, so they may not read back identically.
+    #[new]
+    #[pyo3(signature = (float_precision=None))]
+    pub fn new(float_precision: Option<usize>) -> Self {
+        Self::new_internal(float_precision)
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvWriteOptions);
@@ -4,37 +4,22 @@ pub mod pylib {
     use std::sync::Arc;
 
     use daft_core::python::schema::PySchema;
-    use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
+    use daft_io::{get_io_client, get_runtime, python::IOConfig, IOStatsContext};
     use daft_table::python::PyTable;
-    use pyo3::{exceptions::PyValueError, pyfunction, PyResult, Python};
+    use pyo3::{pyfunction, PyResult, Python};
 
-    fn str_delimiter_to_byte(delimiter: Option<&str>) -> PyResult<Option<u8>> {
-        delimiter
-            .map(|s| match s.as_bytes() {
-                &[c] => Ok(c),
-                _ => Err(PyValueError::new_err(format!(
-                    "Delimiter must be a single-character string, but got {}",
-                    s
-                ))),
-            })
-            .transpose()
-    }
+    use crate::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 
     #[pyfunction]
     #[allow(clippy::too_many_arguments)]
     pub fn read_csv(
         py: Python,
         uri: &str,
-        column_names: Option<Vec<&str>>,
-        include_columns: Option<Vec<&str>>,
-        num_rows: Option<usize>,
-        has_header: Option<bool>,
-        delimiter: Option<&str>,
+        convert_options: Option<CsvConvertOptions>,
+        parse_options: Option<CsvParseOptions>,
+        read_options: Option<CsvReadOptions>,
         io_config: Option<IOConfig>,
         multithreaded_io: Option<bool>,
-        schema: Option<PySchema>,
-        buffer_size: Option<usize>,
-        chunk_size: Option<usize>,
     ) -> PyResult<PyTable> {
         py.allow_threads(|| {
             let io_stats = IOStatsContext::new(format!("read_csv: for uri {uri}"));
@@ -45,17 +30,12 @@ pub mod pylib {
             )?;
             Ok(crate::read::read_csv(
                 uri,
-                column_names,
-                include_columns,
-                num_rows,
-                has_header.unwrap_or(true),
-                str_delimiter_to_byte(delimiter)?,
+                convert_options,
+                parse_options,
+                read_options,
                 io_client,
                 Some(io_stats),
                 multithreaded_io.unwrap_or(true),
-                schema.map(|s| s.schema),
-                buffer_size,
-                chunk_size,
                 None,
             )?
             .into())
@@ -63,12 +43,14 @@ pub mod pylib {
     }
 
     #[pyfunction]
+    #[allow(clippy::too_many_arguments)]
     pub fn read_csv_schema(
         py: Python,
         uri: &str,
-        has_header: Option<bool>,
-        delimiter: Option<&str>,
+        parse_options: Option<CsvParseOptions>,
         max_bytes: Option<usize>,
+        max_rows: Option<usize>,
+        null_values: Option<Vec<String>>,
         io_config: Option<IOConfig>,
         multithreaded_io: Option<bool>,
     ) -> PyResult<PySchema> {
@@ -79,21 +61,95 @@ pub mod pylib {
                 multithreaded_io.unwrap_or(true),
                 io_config.unwrap_or_default().config.into(),
             )?;
-            let (schema, _, _, _, _) = crate::metadata::read_csv_schema(
+            let (schema, _) = crate::metadata::read_csv_schema(
                 uri,
-                has_header.unwrap_or(true),
-                str_delimiter_to_byte(delimiter)?,
+                parse_options,
                 max_bytes,
+                max_rows,
+                Some(CsvConvertOptions::default().with_null_values(null_values)),
                 io_client,
                 Some(io_stats),
             )?;
             Ok(Arc::new(schema).into())
         })
     }
+
+    #[pyfunction]
+    #[allow(clippy::too_many_arguments)]
+    pub fn read_csv_schema_bulk(
+        py: Python,
+        uris: Vec<&str>,
+        parse_options: Option<CsvParseOptions>,
+        max_bytes: Option<usize>,
+        max_rows: Option<usize>,
+        convert_options: Option<CsvConvertOptions>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+        merge_schemas: Option<bool>,
+    ) -> PyResult<Vec<PySchema>> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_csv_schema_bulk: for uris {uris:?}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let runtime_handle = get_runtime(multithreaded_io.unwrap_or(true))?;
+            let _rt_guard = runtime_handle.enter();
+            let schemas = runtime_handle.block_on(crate::metadata::read_csv_schema_bulk(
+                uris.as_slice(),
+                parse_options,
+                max_bytes,
+                max_rows,
+                convert_options,
+                io_client,
+                Some(io_stats),
+                None,
+                merge_schemas.unwrap_or(false),
+            ))?;
+            Ok(schemas
+                .into_iter()
+                .map(|(schema, _)| Arc::new(schema).into())
+                .collect())
+        })
+    }
+
+    #[pyfunction]
+    pub fn infer_csv_schema(
+        py: Python,
+        uri: &str,
+        parse_options: Option<CsvParseOptions>,
+        read_options: Option<CsvReadOptions>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<(PySchema, Option<usize>)> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("infer_csv_schema: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let (schema, read_stats) = crate::metadata::infer_csv_schema(
+                uri,
+                parse_options,
+                read_options,
+                io_client,
+                Some(io_stats),
+            )?;
+            Ok((Arc::new(schema).into(), read_stats.estimated_total_records))
+        })
+    }
 }
 
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_class::<crate::CsvConvertOptions>()?;
+    parent.add_class::<crate::CsvParseOptions>()?;
+    parent.add_class::<crate::CsvReadOptions>()?;
+    parent.add_class::<crate::CsvWriteOptions>()?;
     parent.add_wrapped(wrap_pyfunction!(pylib::read_csv))?;
     parent.add_wrapped(wrap_pyfunction!(pylib::read_csv_schema))?;
+    parent.add_wrapped(wrap_pyfunction!(pylib::read_csv_schema_bulk))?;
+    parent.add_wrapped(wrap_pyfunction!(pylib::infer_csv_schema))?;
     Ok(())
 }
@@ -1,7 +1,9 @@
 use std::{
     collections::{HashMap, HashSet},
+    io::SeekFrom,
     num::NonZeroUsize,
-    sync::Arc,
+    pin::Pin,
+    sync::{Arc, Mutex},
 };
 
 use arrow2::{
@@ -9,44 +11,50 @@ use arrow2::{
     io::csv::read_async::{read_rows, AsyncReaderBuilder, ByteRecord},
 };
 use async_compat::{Compat, CompatExt};
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use csv_async::AsyncReader;
-use daft_core::{
-    schema::{Schema, SchemaRef},
-    utils::arrow::cast_array_for_daft_if_needed,
-    Series,
-};
+use daft_core::{schema::Schema, utils::arrow::cast_array_for_daft_if_needed, Series};
+use daft_dsl::{Expr, Operator};
 use daft_io::{get_runtime, GetResult, IOClient, IOStatsRef};
 use daft_table::Table;
-use futures::TryStreamExt;
+use futures::{
+    future::BoxFuture, stream::BoxStream, FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
 use rayon::prelude::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
 };
-use snafu::{futures::TryFutureExt, ResultExt};
+use snafu::ResultExt;
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncRead, BufReader},
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader},
 };
 use tokio_util::io::StreamReader;
 
-use crate::deserialize::deserialize_column;
-use crate::metadata::read_csv_schema_single;
-use crate::{compression::CompressionCodec, ArrowSnafu};
+use crate::deserialize::{
+    deserialize_column_with_options, BooleanTokens, NumericNormalizer, TemporalFormats,
+};
+use crate::metadata::{
+    read_csv_schema_from_uncompressed_reader, read_csv_schema_single, CsvReadStats,
+};
+use crate::{compression::CompressionCodec, inference::resolve_duplicate_column_names, Error};
+use crate::{
+    encoding::{transcode_to_utf8, UTF8_BOM},
+    trim::maybe_trim_fields,
+};
+use crate::{
+    CsvConvertOptions, CsvEncoding, CsvParseOptions, CsvReadOptions, ParseThreadPool,
+    ProgressCallback,
+};
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_csv(
     uri: &str,
-    column_names: Option<Vec<&str>>,
-    include_columns: Option<Vec<&str>>,
-    num_rows: Option<usize>,
-    has_header: bool,
-    delimiter: Option<u8>,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     multithreaded_io: bool,
-    schema: Option<SchemaRef>,
-    buffer_size: Option<usize>,
-    chunk_size: Option<usize>,
     max_chunks_in_flight: Option<usize>,
 ) -> DaftResult<Table> {
     let runtime_handle = get_runtime(multithreaded_io)?;
@@ -54,84 +62,662 @@ pub fn read_csv(
     runtime_handle.block_on(async {
         read_csv_single(
             uri,
-            column_names,
-            include_columns,
-            num_rows,
-            has_header,
-            delimiter.unwrap_or(b','),
+            convert_options.unwrap_or_default(),
+            parse_options.unwrap_or_default(),
+            read_options,
             io_client,
             io_stats,
-            schema,
-            buffer_size,
-            chunk_size,
             max_chunks_in_flight,
+            None,
         )
         .await
     })
 }
 
+/// Reads a CSV file like [`read_csv`], along with the [`ColumnReadStats`] gathered while parsing it, e.g. to profile
+/// which columns dominate the parsing cost.
 #[allow(clippy::too_many_arguments)]
-async fn read_csv_single(
+pub fn read_csv_with_stats(
     uri: &str,
-    column_names: Option<Vec<&str>>,
-    include_columns: Option<Vec<&str>>,
-    num_rows: Option<usize>,
-    has_header: bool,
-    delimiter: u8,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
-    schema: Option<SchemaRef>,
-    buffer_size: Option<usize>,
-    chunk_size: Option<usize>,
+    multithreaded_io: bool,
     max_chunks_in_flight: Option<usize>,
-) -> DaftResult<Table> {
-    let (schema, estimated_mean_row_size, estimated_std_row_size) = match schema {
-        Some(schema) => (schema.to_arrow()?, None, None),
-        None => {
-            let (schema, _, _, mean, std) = read_csv_schema_single(
+) -> DaftResult<(Table, ColumnReadStats)> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let column_stats = ColumnStatsCollector::default();
+    let table = runtime_handle.block_on(read_csv_single(
+        uri,
+        convert_options.unwrap_or_default(),
+        parse_options.unwrap_or_default(),
+        read_options,
+        io_client,
+        io_stats,
+        max_chunks_in_flight,
+        Some(column_stats.clone()),
+    ))?;
+    let column_stats = column_stats.into_read_stats(&table.column_names());
+    Ok((table, column_stats))
+}
+
+/// Reads a CSV file as an iterator of Tables, one per parsed chunk, so that callers can process the file
+/// incrementally rather than holding all of it in memory at once.
+///
+/// Each Table comes with the [`ByteRange`] of the chunk it was parsed from, so that callers can checkpoint their
+/// progress through the file.
+#[allow(clippy::too_many_arguments)]
+pub fn read_csv_streaming(
+    uri: &str,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    max_chunks_in_flight: Option<usize>,
+) -> DaftResult<impl Iterator<Item = DaftResult<(Table, ByteRange)>>> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let max_chunks_in_flight =
+        resolve_max_chunks_in_flight(max_chunks_in_flight, read_options.as_ref());
+    let (convert_options, predicate) = split_off_predicate(convert_options.unwrap_or_default());
+    let record_buffer_pool = RecordBufferPool::for_read(read_options.as_ref());
+    let (chunk_stream, projected_fields) = runtime_handle.block_on(read_csv_single_into_stream(
+        uri,
+        convert_options,
+        parse_options.unwrap_or_default(),
+        read_options,
+        io_client,
+        io_stats,
+        record_buffer_pool,
+        None,
+    ))?;
+    let mut remaining_rows = predicate.as_ref().and_then(|predicate| predicate.limit);
+    let mut table_stream = chunk_stream
+        // Limit the number of chunks we have in flight at any given time.
+        .try_buffered(max_chunks_in_flight)
+        .map(move |chunk| {
+            let (chunk, byte_range) = chunk?;
+            let table = chunks_into_table(vec![chunk], projected_fields.clone())?;
+            let table = match &predicate {
+                Some(predicate) => predicate.post_filter(predicate.filter_chunk(table)?)?,
+                None => table,
+            };
+            Ok((table, byte_range))
+        })
+        // The last chunk of a file is empty, and filtered chunks may be empty, so skip empty tables rather than
+        // yielding them.
+        .try_filter(|(table, _)| futures::future::ready(!table.is_empty()))
+        .boxed();
+    Ok(std::iter::from_fn(move || {
+        if remaining_rows == Some(0) {
+            return None;
+        }
+        let _rt_guard = runtime_handle.enter();
+        let table = runtime_handle.block_on(table_stream.next())?;
+        // A limit with a predicate applies across the filtered tables, so truncate the table that reaches it.
+        Some(
+            table.and_then(|(table, byte_range)| match remaining_rows.as_mut() {
+                Some(remaining) => {
+                    let table = table.head(table.len().min(*remaining))?;
+                    *remaining -= table.len();
+                    Ok((table, byte_range))
+                }
+                None => Ok((table, byte_range)),
+            }),
+        )
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_csv_bulk(
+    uris: &[&str],
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    max_chunks_in_flight: Option<usize>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let max_chunks_in_flight =
+        resolve_max_chunks_in_flight(max_chunks_in_flight, read_options.as_ref());
+    let (convert_options, predicate) = split_off_predicate(convert_options.unwrap_or_default());
+    let parse_options = parse_options.unwrap_or_default();
+    // A single pool is shared across all files, so buffers are recycled from one file to the next.
+    let record_buffer_pool = RecordBufferPool::for_read(read_options.as_ref());
+    runtime_handle.block_on(async move {
+        // Open a chunk stream per URI, throttling the number of concurrent schema inferences and file opens to
+        // num_parallel_tasks. This preserves the input URI order.
+        let streams = futures::stream::iter(uris.iter().map(|uri| {
+            read_csv_single_into_stream(
                 uri,
-                has_header,
-                Some(delimiter),
-                // Read at most 1 MiB when doing schema inference.
-                Some(1024 * 1024),
+                convert_options.clone(),
+                parse_options.clone(),
+                read_options.clone(),
                 io_client.clone(),
                 io_stats.clone(),
+                record_buffer_pool.clone(),
+                None,
             )
-            .await?;
-            (schema.to_arrow()?, Some(mean), Some(std))
+            .map_ok(|(stream, fields)| (without_byte_ranges(stream), fields))
+        }))
+        .buffered(num_parallel_tasks)
+        .try_collect::<Vec<_>>()
+        .await?;
+        let (streams, projected_fields): (Vec<_>, Vec<_>) = streams.into_iter().unzip();
+        // Share a single chunk budget across all files, so the memory used doesn't grow with the number of files.
+        let chunks = collect_column_array_chunks(streams, max_chunks_in_flight).await?;
+        chunks
+            .into_iter()
+            .zip(projected_fields)
+            .map(|(chunks, projected_fields)| {
+                let table = chunks_into_table(chunks, projected_fields)?;
+                match &predicate {
+                    Some(predicate) => predicate.post_filter(predicate.filter_chunk(table)?),
+                    None => Ok(table),
+                }
+            })
+            .collect()
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn read_csv_single(
+    uri: &str,
+    convert_options: CsvConvertOptions,
+    parse_options: CsvParseOptions,
+    read_options: Option<CsvReadOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_chunks_in_flight: Option<usize>,
+    column_stats: Option<ColumnStatsCollector>,
+) -> DaftResult<Table> {
+    let max_chunks_in_flight =
+        resolve_max_chunks_in_flight(max_chunks_in_flight, read_options.as_ref());
+    let (convert_options, predicate) = split_off_predicate(convert_options);
+    let record_buffer_pool = RecordBufferPool::for_read(read_options.as_ref());
+    let (chunk_stream, projected_fields) = read_csv_single_into_stream(
+        uri,
+        convert_options,
+        parse_options,
+        read_options,
+        io_client,
+        io_stats,
+        record_buffer_pool,
+        column_stats,
+    )
+    .await?;
+    collect_chunk_stream(
+        chunk_stream,
+        projected_fields,
+        predicate,
+        max_chunks_in_flight,
+    )
+    .await
+}
+
+/// Reads a CSV from an already-open `reader`, e.g. in-memory bytes or a custom transport, rather than from a URI
+/// through an [`IOClient`].
+///
+/// Compressed data is detected from its leading bytes. Unless a schema is provided, the schema is inferred from the
+/// start of the data, which is buffered so that it can be read again, as the reader can only be read once.
+pub fn read_csv_from_reader<R>(
+    reader: R,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+) -> DaftResult<Table>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        let max_chunks_in_flight = resolve_max_chunks_in_flight(None, read_options.as_ref());
+        let (convert_options, predicate) = split_off_predicate(convert_options.unwrap_or_default());
+        let parse_options = parse_options.unwrap_or_default();
+        let mut reader = BufReader::new(reader);
+        let reader: Pin<Box<dyn AsyncRead + Send>> =
+            match CompressionCodec::from_magic_bytes(reader.fill_buf().await?) {
+                Some(compression) => compression.to_decoder(reader),
+                None => Box::pin(reader),
+            };
+        let (
+            reader,
+            schema,
+            (estimated_mean_row_size, estimated_std_row_size, estimated_p85_row_size),
+        ) = match provided_schema(&convert_options, &parse_options)? {
+            Some(schema) => (reader, schema, row_size_hints(read_options.as_ref())),
+            None => {
+                let mut prefix = vec![];
+                let mut reader = reader;
+                (&mut reader)
+                    .take(INFERENCE_MAX_BYTES as u64)
+                    .read_to_end(&mut prefix)
+                    .await?;
+                // Only infer from the complete lines of the prefix, unless it holds all of the data.
+                let mut inference_bytes = prefix.as_slice();
+                if prefix.len() == INFERENCE_MAX_BYTES
+                    && parse_options.encoding.unwrap_or_default() == CsvEncoding::Utf8
+                    && let Some(last_terminator) = prefix
+                        .iter()
+                        .rposition(|byte| *byte == parse_options.line_terminator.unwrap_or(b'\n'))
+                {
+                    inference_bytes = &prefix[..=last_terminator];
+                }
+                let (schema, read_stats) = read_csv_schema_from_uncompressed_reader(
+                    std::io::Cursor::new(inference_bytes.to_vec()),
+                    parse_options.clone(),
+                    Some(INFERENCE_MAX_BYTES),
+                    read_options
+                        .as_ref()
+                        .and_then(|opts| opts.inference_max_rows),
+                    convert_options.clone(),
+                )
+                .await?;
+                // Read the buffered prefix again, followed by the rest of the data.
+                let reader: Pin<Box<dyn AsyncRead + Send>> =
+                    Box::pin(std::io::Cursor::new(prefix).chain(reader));
+                (reader, schema.to_arrow()?, row_size_estimates(&read_stats))
+            }
+        };
+        let read_options = read_options.unwrap_or_default();
+        let (chunk_stream, projected_fields) = read_csv_from_uncompressed_reader(
+            reader,
+            convert_options,
+            parse_options,
+            schema,
+            read_options.buffer_size.unwrap_or(512 * 1024),
+            read_options.chunk_size.unwrap_or(64 * 1024),
+            read_options.max_bytes,
+            read_options.start_byte.unwrap_or(0),
+            0,
+            read_options.progress.clone(),
+            RecordBufferPool::for_read(Some(&read_options)),
+            None,
+            read_options.thread_pool.clone(),
+            estimated_mean_row_size,
+            estimated_std_row_size,
+            estimated_p85_row_size,
+        )
+        .await?;
+        collect_chunk_stream(
+            chunk_stream,
+            projected_fields,
+            predicate,
+            max_chunks_in_flight,
+        )
+        .await
+    })
+}
+
+/// Collects the parsed chunks of a file into a table, applying the pushed predicate, if any.
+async fn collect_chunk_stream(
+    chunk_stream: RangedColumnArrayChunkStream,
+    projected_fields: Vec<Field>,
+    predicate: Option<PushedPredicate>,
+    max_chunks_in_flight: usize,
+) -> DaftResult<Table> {
+    // Limit the number of chunks we have in flight at any given time.
+    let mut chunk_stream = without_byte_ranges(chunk_stream).try_buffered(max_chunks_in_flight);
+    let Some(predicate) = predicate else {
+        let chunks = chunk_stream.try_collect::<Vec<_>>().await?;
+        return chunks_into_table(chunks, projected_fields);
+    };
+    // Filter each chunk as soon as it's parsed, so that non-matching rows are dropped before concatenation rather
+    // than after the whole file has been materialized.
+    let mut tables = vec![];
+    let mut num_rows = 0;
+    while let Some(chunk) = chunk_stream.try_next().await? {
+        let table =
+            predicate.filter_chunk(chunks_into_table(vec![chunk], projected_fields.clone())?)?;
+        num_rows += table.len();
+        tables.push(table);
+        // Stop reading once we have enough rows, unless the post-filters may still drop some of them.
+        if predicate.post_filters.is_empty()
+            && predicate.limit.map_or(false, |limit| num_rows >= limit)
+        {
+            break;
+        }
+    }
+    let table = Table::concat(&tables.iter().collect::<Vec<_>>())?;
+    predicate.post_filter(table)
+}
+
+/// Predicate pushed into a CSV read, split into the filters evaluated on each parsed chunk, and the filters evaluated
+/// on the read table.
+struct PushedPredicate {
+    chunk_filters: Vec<Expr>,
+    post_filters: Vec<Expr>,
+    // The limit of the read, which applies to the rows that satisfy the predicate.
+    limit: Option<usize>,
+}
+
+impl PushedPredicate {
+    fn filter_chunk(&self, table: Table) -> DaftResult<Table> {
+        table.filter(&self.chunk_filters)
+    }
+
+    /// Applies the post-filters and the limit to a table whose chunks have been filtered by the chunk filters.
+    fn post_filter(&self, table: Table) -> DaftResult<Table> {
+        let table = table.filter(&self.post_filters)?;
+        match self.limit {
+            Some(limit) if table.len() > limit => table.head(limit),
+            _ => Ok(table),
         }
+    }
+}
+
+/// Splits the predicate off of `convert_options`, if any.
+///
+/// As the limit of a read applies to the rows that satisfy the predicate, the limit moves to the predicate, rather
+/// than being pushed into the reader. Only comparisons between a column and a literal are evaluated on each chunk for
+/// now; any other filters fall back to being evaluated on the read table.
+fn split_off_predicate(
+    convert_options: CsvConvertOptions,
+) -> (CsvConvertOptions, Option<PushedPredicate>) {
+    let Some(filters) = convert_options.predicate.clone() else {
+        return (convert_options, None);
+    };
+    let (chunk_filters, post_filters) = filters.into_iter().partition(is_column_comparison);
+    let predicate = PushedPredicate {
+        chunk_filters,
+        post_filters,
+        limit: convert_options.limit,
     };
-    let compression_codec = CompressionCodec::from_uri(uri);
+    (convert_options.with_limit(None), Some(predicate))
+}
+
+fn is_column_comparison(expr: &Expr) -> bool {
+    match expr {
+        Expr::BinaryOp {
+            op:
+                Operator::Eq
+                | Operator::NotEq
+                | Operator::Lt
+                | Operator::LtEq
+                | Operator::Gt
+                | Operator::GtEq,
+            left,
+            right,
+        } => matches!(
+            (left.as_ref(), right.as_ref()),
+            (Expr::Column(_), Expr::Literal(_)) | (Expr::Literal(_), Expr::Column(_))
+        ),
+        _ => false,
+    }
+}
+
+/// Resolves the max number of chunks in flight of a read, preferring the one passed to the read over the one of its
+/// read options, and falling back to the default.
+fn resolve_max_chunks_in_flight(
+    max_chunks_in_flight: Option<usize>,
+    read_options: Option<&CsvReadOptions>,
+) -> usize {
+    max_chunks_in_flight
+        .or_else(|| read_options.and_then(|opts| opts.max_chunks_in_flight))
+        .unwrap_or_else(default_max_chunks_in_flight)
+}
+
+/// Default max chunks in flight is set to 2x the number of cores, which should ensure pipelining of reading chunks
+/// with the parsing of chunks on the rayon threadpool.
+fn default_max_chunks_in_flight() -> usize {
+    std::thread::available_parallelism()
+        .unwrap_or(NonZeroUsize::new(2).unwrap())
+        .checked_mul(2.try_into().unwrap())
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+/// Chunk of parsed column arrays, one array per projected column.
+type ColumnArrayChunk = Vec<Box<dyn arrow2::array::Array>>;
+
+/// Stream of chunk parsing tasks for a single CSV file, in file order.
+type ColumnArrayChunkStream =
+    BoxStream<'static, DaftResult<BoxFuture<'static, DaftResult<ColumnArrayChunk>>>>;
+
+/// Range of bytes that a chunk was parsed from, as offsets into the CSV data after decompression, transcoding and
+/// trimming; the first chunk of a file starts after the skipped rows, and includes the header, if any.
+///
+/// The chunks of a file are contiguous: each chunk ends where the next one starts, just after its last record, so a
+/// read can be resumed from the end of a chunk with the `start_byte` read option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Statistics of a single column of a CSV read, gathered while its chunks are parsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// Total size (in bytes) of the column's fields, excluding delimiters and enclosing quotes.
+    pub num_bytes: usize,
+    /// Number of null values parsed for the column, before any predicate is applied.
+    pub null_count: usize,
+}
+
+/// Per-column statistics of a CSV read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnReadStats {
+    /// Statistics of each read column, in the order of the columns of the read table.
+    pub columns: Vec<(String, ColumnStats)>,
+}
+
+impl ColumnReadStats {
+    /// Returns the statistics of the column named `name`, if it was read.
+    pub fn get(&self, name: &str) -> Option<&ColumnStats> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, stats)| stats)
+    }
+}
+
+/// Accumulates the [`ColumnStats`] of each projected column across the parsed chunks of a read.
+#[derive(Clone, Default)]
+struct ColumnStatsCollector(Arc<Mutex<Vec<ColumnStats>>>);
+
+impl ColumnStatsCollector {
+    /// Adds the stats of a parsed chunk, given the records it was parsed from.
+    fn record(
+        &self,
+        chunk: &ColumnArrayChunk,
+        projection_indices: &[usize],
+        records: &[ByteRecord],
+    ) {
+        let chunk_stats = chunk
+            .iter()
+            .zip(projection_indices)
+            .map(|(array, idx)| ColumnStats {
+                num_bytes: records
+                    .iter()
+                    .map(|record| record.get(*idx).map_or(0, <[u8]>::len))
+                    .sum(),
+                null_count: array.null_count(),
+            })
+            .collect::<Vec<_>>();
+        let mut stats = self.0.lock().unwrap();
+        stats.resize(chunk_stats.len(), ColumnStats::default());
+        for (stats, chunk_stats) in stats.iter_mut().zip(chunk_stats) {
+            stats.num_bytes += chunk_stats.num_bytes;
+            stats.null_count += chunk_stats.null_count;
+        }
+    }
+
+    /// Returns the accumulated stats, labeled with the names of the read columns.
+    fn into_read_stats(self, column_names: &[String]) -> ColumnReadStats {
+        let stats = self.0.lock().unwrap();
+        ColumnReadStats {
+            columns: column_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.clone(), stats.get(i).copied().unwrap_or_default()))
+                .collect(),
+        }
+    }
+}
+
+/// Stream of chunk parsing tasks for a single CSV file, in file order, along with the byte range of each chunk.
+type RangedColumnArrayChunkStream =
+    BoxStream<'static, DaftResult<BoxFuture<'static, DaftResult<(ColumnArrayChunk, ByteRange)>>>>;
+
+/// Drops the byte ranges of the chunks of `stream`, for reads that don't report them.
+fn without_byte_ranges(stream: RangedColumnArrayChunkStream) -> ColumnArrayChunkStream {
+    stream
+        .map_ok(|chunk| chunk.map_ok(|(chunk, _)| chunk).boxed())
+        .boxed()
+}
+
+/// Read at most 1 MiB when doing schema inference.
+const INFERENCE_MAX_BYTES: usize = 1024 * 1024;
+
+/// Estimates of the mean, standard deviation and 85th percentile of the row sizes (in bytes), which chunks are sized
+/// off.
+type RowSizeEstimates = (Option<f64>, Option<f64>, Option<usize>);
+
+/// Returns the schema of the read if it's provided, so that it doesn't need to be inferred.
+fn provided_schema(
+    convert_options: &CsvConvertOptions,
+    parse_options: &CsvParseOptions,
+) -> DaftResult<Option<arrow2::datatypes::Schema>> {
+    match &convert_options.schema {
+        Some(schema) => Ok(Some(schema.to_arrow()?)),
+        // Headerless all-string reads build their schema from the column names, without any inference IO.
+        None => convert_options
+            .all_strings_schema(parse_options)?
+            .map(|schema| schema.to_arrow())
+            .transpose(),
+    }
+}
+
+/// Without inference, chunks are sized off the row size hints, if any.
+fn row_size_hints(read_options: Option<&CsvReadOptions>) -> RowSizeEstimates {
+    (
+        read_options
+            .and_then(|opts| opts.mean_row_size_hint)
+            .map(|size| size as f64),
+        read_options
+            .and_then(|opts| opts.stddev_row_size_hint)
+            .map(|size| size as f64),
+        None,
+    )
+}
+
+/// Row size estimates from the records sampled during schema inference.
+fn row_size_estimates(read_stats: &CsvReadStats) -> RowSizeEstimates {
+    (
+        Some(read_stats.mean_record_size_bytes),
+        Some(read_stats.stddev_record_size_bytes),
+        read_stats
+            .record_size_histogram
+            .as_ref()
+            .map(|histogram| histogram.p85),
+    )
+}
+
+/// Infers the schema of the CSV file (if not provided), and opens a stream of chunk parsing tasks for the file,
+/// along with the fields of the parsed columns.
+#[allow(clippy::too_many_arguments)]
+async fn read_csv_single_into_stream(
+    uri: &str,
+    convert_options: CsvConvertOptions,
+    parse_options: CsvParseOptions,
+    read_options: Option<CsvReadOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    record_buffer_pool: Option<RecordBufferPool>,
+    column_stats: Option<ColumnStatsCollector>,
+) -> DaftResult<(RangedColumnArrayChunkStream, Vec<Field>)> {
+    let (schema, (estimated_mean_row_size, estimated_std_row_size, estimated_p85_row_size)) =
+        match provided_schema(&convert_options, &parse_options)? {
+            Some(schema) => (schema, row_size_hints(read_options.as_ref())),
+            None => {
+                let (schema, read_stats) = read_csv_schema_single(
+                    uri,
+                    parse_options.clone(),
+                    Some(INFERENCE_MAX_BYTES),
+                    read_options
+                        .as_ref()
+                        .and_then(|opts| opts.inference_max_rows),
+                    convert_options.clone(),
+                    io_client.clone(),
+                    io_stats.clone(),
+                )
+                .await?;
+                (schema.to_arrow()?, row_size_estimates(&read_stats))
+            }
+        };
+    let read_options = read_options.unwrap_or_default();
+    // Default buffer size of 512 KiB.
+    let buffer_size = read_options.buffer_size.unwrap_or(512 * 1024);
+    // Default chunk size of 64 KiB.
+    let chunk_size = read_options.chunk_size.unwrap_or(64 * 1024);
+    let start_byte = read_options.start_byte.unwrap_or(0);
+    let compression_codec = CompressionCodec::try_from_uri(uri)?;
     match io_client
         .single_url_get(uri.to_string(), None, io_stats)
         .await?
     {
         GetResult::File(file) => {
+            let mut reader = BufReader::new(File::open(file.path).await?);
+            // Plain UTF-8 files are read as-is, so they can seek to just before the start byte rather than reading
+            // up to it.
+            let leading_bytes = reader.fill_buf().await?;
+            let seekable = start_byte > 1
+                && compression_codec.is_none()
+                && CompressionCodec::from_magic_bytes(leading_bytes).is_none()
+                && parse_options.encoding.unwrap_or_default() == CsvEncoding::Utf8
+                && !parse_options.trim
+                && !leading_bytes.starts_with(UTF8_BOM);
+            if seekable {
+                let seeked_bytes = start_byte - 1;
+                reader.seek(SeekFrom::Start(seeked_bytes as u64)).await?;
+                return read_csv_from_uncompressed_reader(
+                    reader,
+                    convert_options,
+                    parse_options,
+                    schema,
+                    buffer_size,
+                    chunk_size,
+                    read_options.max_bytes,
+                    start_byte,
+                    seeked_bytes,
+                    read_options.progress,
+                    record_buffer_pool,
+                    column_stats,
+                    read_options.thread_pool,
+                    estimated_mean_row_size,
+                    estimated_std_row_size,
+                    estimated_p85_row_size,
+                )
+                .await;
+            }
             read_csv_from_compressed_reader(
-                BufReader::new(File::open(file.path).await?),
+                reader,
                 compression_codec,
-                column_names,
-                include_columns,
-                num_rows,
-                has_header,
-                delimiter,
+                convert_options,
+                parse_options,
                 schema,
-                // Default buffer size of 512 KiB.
-                buffer_size.unwrap_or(512 * 1024),
-                // Default chunk size of 64 KiB.
-                chunk_size.unwrap_or(64 * 1024),
-                // Default max chunks in flight is set to 2x the number of cores, which should ensure pipelining of reading chunks
-                // with the parsing of chunks on the rayon threadpool.
-                max_chunks_in_flight.unwrap_or(
-                    std::thread::available_parallelism()
-                        .unwrap_or(NonZeroUsize::new(2).unwrap())
-                        .checked_mul(2.try_into().unwrap())
-                        .unwrap()
-                        .try_into()
-                        .unwrap(),
-                ),
+                buffer_size,
+                chunk_size,
+                read_options.max_bytes,
+                start_byte,
+                read_options.progress,
+                record_buffer_pool,
+                column_stats,
+                read_options.thread_pool,
                 estimated_mean_row_size,
                 estimated_std_row_size,
+                estimated_p85_row_size,
             )
             .await
         }
@@ -139,28 +725,20 @@ async fn read_csv_single(
             read_csv_from_compressed_reader(
                 StreamReader::new(stream),
                 compression_codec,
-                column_names,
-                include_columns,
-                num_rows,
-                has_header,
-                delimiter,
+                convert_options,
+                parse_options,
                 schema,
-                // Default buffer size of 512 KiB.
-                buffer_size.unwrap_or(512 * 1024),
-                // Default chunk size of 64 KiB.
-                chunk_size.unwrap_or(64 * 1024),
-                // Default max chunks in flight is set to 2x the number of cores, which should ensure pipelining of reading chunks
-                // with the parsing of chunks on the rayon threadpool.
-                max_chunks_in_flight.unwrap_or(
-                    std::thread::available_parallelism()
-                        .unwrap_or(NonZeroUsize::new(2).unwrap())
-                        .checked_mul(2.try_into().unwrap())
-                        .unwrap()
-                        .try_into()
-                        .unwrap(),
-                ),
+                buffer_size,
+                chunk_size,
+                read_options.max_bytes,
+                start_byte,
+                read_options.progress,
+                record_buffer_pool,
+                column_stats,
+                read_options.thread_pool,
                 estimated_mean_row_size,
                 estimated_std_row_size,
+                estimated_p85_row_size,
             )
             .await
         }
@@ -169,55 +747,71 @@ async fn read_csv_single(
 
 #[allow(clippy::too_many_arguments)]
 async fn read_csv_from_compressed_reader<R>(
-    reader: R,
+    mut reader: R,
     compression_codec: Option<CompressionCodec>,
-    column_names: Option<Vec<&str>>,
-    include_columns: Option<Vec<&str>>,
-    num_rows: Option<usize>,
-    has_header: bool,
-    delimiter: u8,
+    convert_options: CsvConvertOptions,
+    parse_options: CsvParseOptions,
     schema: arrow2::datatypes::Schema,
     buffer_size: usize,
     chunk_size: usize,
-    max_chunks_in_flight: usize,
+    max_bytes: Option<usize>,
+    start_byte: usize,
+    progress: Option<ProgressCallback>,
+    record_buffer_pool: Option<RecordBufferPool>,
+    column_stats: Option<ColumnStatsCollector>,
+    thread_pool: Option<ParseThreadPool>,
     estimated_mean_row_size: Option<f64>,
     estimated_std_row_size: Option<f64>,
-) -> DaftResult<Table>
+    estimated_p85_row_size: Option<usize>,
+) -> DaftResult<(RangedColumnArrayChunkStream, Vec<Field>)>
 where
     R: AsyncBufRead + Unpin + Send + 'static,
 {
+    // Fall back to sniffing the leading bytes if the URI has no compression extension.
+    let compression_codec = match compression_codec {
+        Some(compression) => Some(compression),
+        None => CompressionCodec::from_magic_bytes(reader.fill_buf().await?),
+    };
     match compression_codec {
         Some(compression) => {
             read_csv_from_uncompressed_reader(
                 compression.to_decoder(reader),
-                column_names,
-                include_columns,
-                num_rows,
-                has_header,
-                delimiter,
+                convert_options,
+                parse_options,
                 schema,
                 buffer_size,
                 chunk_size,
-                max_chunks_in_flight,
+                max_bytes,
+                start_byte,
+                0,
+                progress,
+                record_buffer_pool,
+                column_stats,
+                thread_pool,
                 estimated_mean_row_size,
                 estimated_std_row_size,
+                estimated_p85_row_size,
             )
             .await
         }
         None => {
             read_csv_from_uncompressed_reader(
                 reader,
-                column_names,
-                include_columns,
-                num_rows,
-                has_header,
-                delimiter,
+                convert_options,
+                parse_options,
                 schema,
                 buffer_size,
                 chunk_size,
-                max_chunks_in_flight,
+                max_bytes,
+                start_byte,
+                0,
+                progress,
+                record_buffer_pool,
+                column_stats,
+                thread_pool,
                 estimated_mean_row_size,
                 estimated_std_row_size,
+                estimated_p85_row_size,
             )
             .await
         }
@@ -227,59 +821,162 @@ where
 #[allow(clippy::too_many_arguments)]
 async fn read_csv_from_uncompressed_reader<R>(
     stream_reader: R,
-    column_names: Option<Vec<&str>>,
-    include_columns: Option<Vec<&str>>,
-    num_rows: Option<usize>,
-    has_header: bool,
-    delimiter: u8,
+    convert_options: CsvConvertOptions,
+    parse_options: CsvParseOptions,
     schema: arrow2::datatypes::Schema,
     buffer_size: usize,
     chunk_size: usize,
-    max_chunks_in_flight: usize,
+    max_bytes: Option<usize>,
+    start_byte: usize,
+    seeked_bytes: usize,
+    progress: Option<ProgressCallback>,
+    record_buffer_pool: Option<RecordBufferPool>,
+    column_stats: Option<ColumnStatsCollector>,
+    thread_pool: Option<ParseThreadPool>,
     estimated_mean_row_size: Option<f64>,
     estimated_std_row_size: Option<f64>,
-) -> DaftResult<Table>
+    estimated_p85_row_size: Option<usize>,
+) -> DaftResult<(RangedColumnArrayChunkStream, Vec<Field>)>
 where
-    R: AsyncRead + Unpin + Send,
+    R: AsyncRead + Unpin + Send + 'static,
 {
+    let mut stream_reader = BufReader::new(maybe_trim_fields(
+        transcode_to_utf8(stream_reader, &parse_options),
+        &parse_options,
+    ));
+    // Offset of the data read by the CSV reader, which the byte ranges of the chunks are relative to.
+    let mut byte_offset = seeked_bytes as u64;
+    if start_byte == 0 {
+        byte_offset += skip_lines(&mut stream_reader, parse_options.skip_rows).await? as u64;
+    } else {
+        // Discard the data up to just before the start byte, and then up to the next line terminator, so that the
+        // read starts at the first line at or after the start byte.
+        byte_offset += tokio::io::copy(
+            &mut (&mut stream_reader).take((start_byte - 1 - seeked_bytes) as u64),
+            &mut tokio::io::sink(),
+        )
+        .await?;
+        byte_offset += stream_reader
+            .read_until(parse_options.line_terminator.unwrap_or(b'\n'), &mut vec![])
+            .await? as u64;
+    }
     let reader = AsyncReaderBuilder::new()
-        .has_headers(has_header)
-        .delimiter(delimiter)
+        // Only the start of the file has a header.
+        .has_headers(parse_options.has_header && start_byte == 0)
+        .delimiter(parse_options.delimiter)
+        .escape(parse_options.escape)
+        .comment(parse_options.comment)
+        .terminator(parse_options.terminator())
+        .double_quote(parse_options.double_quote)
+        .flexible(parse_options.flexible)
         .buffer_capacity(buffer_size)
         .create_reader(stream_reader.compat());
     let mut fields = schema.fields;
     // Rename fields, if necessary.
-    if let Some(column_names) = column_names {
+    if let Some(column_names) = &convert_options.column_names {
         fields = fields
             .into_iter()
             .zip(column_names.iter())
             .map(|(field, name)| {
-                Field::new(*name, field.data_type, field.is_nullable).with_metadata(field.metadata)
+                Field::new(name, field.data_type, field.is_nullable).with_metadata(field.metadata)
             })
             .collect();
     }
-    // Read CSV into Arrow2 column chunks.
-    let column_chunks = read_into_column_chunks(
+    // Disambiguate (or reject) repeated names, whether they come from the header, a provided schema or the renames.
+    let mut names = fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    resolve_duplicate_column_names(&mut names, convert_options.dedup_column_names)?;
+    for (field, name) in fields.iter_mut().zip(names) {
+        field.name = name;
+    }
+    // Force the dtypes of overridden columns, keeping the inferred dtypes of all other columns.
+    if let Some(dtype_overrides) = &convert_options.dtype_overrides {
+        fields = apply_dtype_overrides(fields, dtype_overrides)?;
+    }
+    // Null values in non-nullable columns are an error when their chunks are parsed.
+    if let Some(non_nullable_columns) = &convert_options.non_nullable_columns {
+        fields = apply_non_nullable_columns(fields, non_nullable_columns)?;
+    }
+    // Resolve which columns to parse, and the (possibly renamed) fields of the output columns.
+    let (projection_indices, projected_fields) = match &convert_options.include_columns_as {
+        Some(include_columns_as) => {
+            if convert_options.include_columns.is_some()
+                || convert_options.include_column_indices.is_some()
+            {
+                return Err(DaftError::ValueError(
+                    "Only one of include_columns, include_column_indices and include_columns_as can be provided \
+                     for a CSV read"
+                        .to_string(),
+                ));
+            }
+            include_columns_as_to_projection(
+                &fields,
+                include_columns_as,
+                convert_options.case_insensitive_columns,
+            )?
+        }
+        None => {
+            let projection_indices = fields_to_projection_indices(
+                &fields,
+                &convert_options.include_columns,
+                &convert_options.include_column_indices,
+                convert_options.case_insensitive_columns,
+            )?;
+            let projected_fields = projection_indices
+                .iter()
+                .map(|idx| fields[*idx].clone())
+                .collect::<Vec<_>>();
+            (projection_indices, projected_fields)
+        }
+    };
+    // Open a stream of chunk parsing tasks.
+    let numeric_normalizer = NumericNormalizer::try_new(
+        convert_options.thousands_separator,
+        convert_options.parentheses_as_negative,
+    );
+    let chunk_stream = parse_into_column_array_chunk_stream(
         reader,
-        fields.clone().into(),
-        fields_to_projection_indices(&fields, &include_columns),
-        num_rows,
+        fields.into(),
+        projection_indices,
+        parse_options,
+        BooleanTokens::new(convert_options.true_values, convert_options.false_values),
+        convert_options.null_values,
+        numeric_normalizer,
+        TemporalFormats::try_new(
+            convert_options.date_format,
+            convert_options.timestamp_format,
+        ),
+        convert_options.limit,
         chunk_size,
-        max_chunks_in_flight,
+        max_bytes,
+        byte_offset,
+        progress,
+        record_buffer_pool,
+        column_stats,
+        thread_pool,
         estimated_mean_row_size,
         estimated_std_row_size,
-    )
-    .await?;
-    // Truncate fields to only contain projected columns.
-    if let Some(include_columns) = include_columns {
-        let include_columns: HashSet<&str> = include_columns.into_iter().collect();
-        fields.retain(|f| include_columns.contains(f.name.as_str()))
+        estimated_p85_row_size,
+    );
+    Ok((chunk_stream, projected_fields))
+}
+
+/// Concatenates the parsed chunks of a CSV file into a Daft Table with the given fields.
+fn chunks_into_table(
+    chunks: Vec<ColumnArrayChunk>,
+    projected_fields: Vec<Field>,
+) -> DaftResult<Table> {
+    // Transpose chunk x column into column x chunk.
+    let mut column_chunks = vec![Vec::with_capacity(chunks.len()); projected_fields.len()];
+    for chunk in chunks.into_iter() {
+        for (idx, col) in chunk.into_iter().enumerate() {
+            column_chunks[idx].push(col);
+        }
     }
     // Concatenate column chunks and convert into Daft Series.
     // Note that this concatenation is done in parallel on the rayon threadpool.
     let columns_series = column_chunks
         .into_par_iter()
-        .zip(&fields)
+        .zip(&projected_fields)
         .map(|(mut arrays, field)| {
             let array = if arrays.len() > 1 {
                 // Concatenate all array chunks.
@@ -293,24 +990,136 @@ where
         })
         .collect::<DaftResult<Vec<Series>>>()?;
     // Build Daft Table.
-    let schema: arrow2::datatypes::Schema = fields.into();
+    let schema: arrow2::datatypes::Schema = projected_fields.into();
     let daft_schema = Schema::try_from(&schema)?;
     Table::new(daft_schema, columns_series)
 }
 
+/// Merges the chunk streams of multiple CSV files into a single stream, so that at most `max_chunks_in_flight` chunks
+/// are being read or parsed at any given time across all files, and collects the parsed chunks of each file in order.
+async fn collect_column_array_chunks(
+    streams: Vec<ColumnArrayChunkStream>,
+    max_chunks_in_flight: usize,
+) -> DaftResult<Vec<Vec<ColumnArrayChunk>>> {
+    let num_streams = streams.len();
+    let merged =
+        futures::stream::select_all(streams.into_iter().enumerate().map(|(stream_idx, stream)| {
+            stream
+                .map_ok(move |chunk| chunk.map_ok(move |chunk| (stream_idx, chunk)))
+                .boxed()
+        }));
+    let mut chunks = (0..num_streams).map(|_| vec![]).collect::<Vec<_>>();
+    // The merged stream preserves the order of each file's chunks, and try_buffered preserves the merged stream order.
+    merged
+        .try_buffered(max_chunks_in_flight)
+        .try_for_each(|(stream_idx, chunk)| {
+            chunks[stream_idx].push(chunk);
+            futures::future::ready(Ok(()))
+        })
+        .await?;
+    Ok(chunks)
+}
+
+/// Discards the first `num_lines` raw lines of `reader`, e.g. banner rows preceding the CSV header, returning the
+/// number of bytes discarded.
+pub(crate) async fn skip_lines<R>(reader: &mut R, num_lines: usize) -> std::io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut line = vec![];
+    let mut num_bytes = 0;
+    for _ in 0..num_lines {
+        line.clear();
+        match reader.read_until(b'\n', &mut line).await? {
+            0 => break,
+            n => num_bytes += n,
+        }
+    }
+    Ok(num_bytes)
+}
+
+/// Size of the buffer to allocate for each record, so that ~85% of the records don't require reallocation during
+/// reading.
+///
+/// The 85th percentile of the record sizes observed during schema inference is used when available. Otherwise, the
+/// buffer is sized 1 standard deviation above the mean record size, which only holds ~85% of the records if their
+/// sizes are normally distributed; a few very long records inflate it for all others.
+fn record_buffer_size(
+    estimated_mean_row_size: f64,
+    estimated_std_row_size: f64,
+    estimated_p85_row_size: Option<usize>,
+) -> usize {
+    match estimated_p85_row_size {
+        Some(p85_row_size) => p85_row_size,
+        None => (estimated_mean_row_size + estimated_std_row_size).ceil() as usize,
+    }
+}
+
+/// Free-list of chunk buffers of [`ByteRecord`]s, so that reads recycle the buffers of parsed chunks rather than
+/// allocating a fresh buffer for every chunk.
+#[derive(Clone, Default)]
+struct RecordBufferPool(Arc<Mutex<Vec<Vec<ByteRecord>>>>);
+
+impl RecordBufferPool {
+    /// Maximum number of idle buffers kept in the pool; any more are dropped.
+    const MAX_IDLE_BUFFERS: usize = 64;
+
+    /// Returns a new pool if the read options enable buffer reuse.
+    fn for_read(read_options: Option<&CsvReadOptions>) -> Option<Self> {
+        read_options
+            .map_or(false, |opts| opts.reuse_record_buffers)
+            .then(Self::default)
+    }
+
+    /// Takes a buffer of `num_rows` records out of the pool, topping it up with records of `record_buffer_size` bytes
+    /// if it has fewer records.
+    fn take(
+        &self,
+        num_rows: usize,
+        record_buffer_size: usize,
+        num_fields: usize,
+    ) -> Vec<ByteRecord> {
+        let mut buffer = self.0.lock().unwrap().pop().unwrap_or_default();
+        buffer.resize(
+            num_rows,
+            ByteRecord::with_capacity(record_buffer_size, num_fields),
+        );
+        buffer
+    }
+
+    /// Returns a buffer to the pool, once its records have been parsed.
+    fn put(&self, buffer: Vec<ByteRecord>) {
+        let mut buffers = self.0.lock().unwrap();
+        if buffers.len() < Self::MAX_IDLE_BUFFERS {
+            buffers.push(buffer);
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
-async fn read_into_column_chunks<R>(
+fn parse_into_column_array_chunk_stream<R>(
     mut reader: AsyncReader<Compat<R>>,
     fields: Arc<Vec<arrow2::datatypes::Field>>,
     projection_indices: Arc<Vec<usize>>,
+    parse_options: CsvParseOptions,
+    boolean_tokens: BooleanTokens,
+    null_values: Option<Vec<String>>,
+    numeric_normalizer: Option<NumericNormalizer>,
+    temporal_formats: Option<TemporalFormats>,
     num_rows: Option<usize>,
     chunk_size: usize,
-    max_chunks_in_flight: usize,
+    max_bytes: Option<usize>,
+    byte_offset: u64,
+    progress: Option<ProgressCallback>,
+    record_buffer_pool: Option<RecordBufferPool>,
+    column_stats: Option<ColumnStatsCollector>,
+    thread_pool: Option<ParseThreadPool>,
     estimated_mean_row_size: Option<f64>,
     estimated_std_row_size: Option<f64>,
-) -> DaftResult<Vec<Vec<Box<dyn arrow2::array::Array>>>>
+    estimated_p85_row_size: Option<usize>,
+) -> RangedColumnArrayChunkStream
 where
-    R: AsyncRead + Unpin + Send,
+    R: AsyncRead + Unpin + Send + 'static,
 {
     let num_fields = fields.len();
     let num_rows = num_rows.unwrap_or(usize::MAX);
@@ -324,26 +1133,46 @@ where
         let mut total_rows_read = 0;
         let mut mean = 0f64;
         let mut m2 = 0f64;
-        while rows_read > 0 && total_rows_read < num_rows {
-            // Allocate a record buffer of size 1 standard above the observed mean record size.
-            // If the record sizes are normally distributed, this should result in ~85% of the records not requiring
-            // reallocation during reading.
-            let record_buffer_size = (estimated_mean_row_size + estimated_std_row_size).ceil() as usize;
+        let mut reached_max_bytes = false;
+        while rows_read > 0 && total_rows_read < num_rows && !reached_max_bytes {
+            let record_buffer_size = record_buffer_size(
+                estimated_mean_row_size,
+                estimated_std_row_size,
+                estimated_p85_row_size,
+            );
             // Get chunk size in # of rows, using the estimated mean row size in bytes.
             let chunk_size_rows = {
-                let estimated_rows_per_desired_chunk = chunk_size / (estimated_mean_row_size.ceil() as usize);
+                // Files without records have a mean row size of 0, so count rows as at least 1 byte.
+                let estimated_rows_per_desired_chunk = chunk_size / (estimated_mean_row_size.ceil() as usize).max(1);
                 // Process at least 8 rows in a chunk, even if the rows are pretty large.
                 // Cap chunk size at the remaining number of rows we need to read before we reach the num_rows limit.
                 estimated_rows_per_desired_chunk.max(8).min(num_rows - total_rows_read)
             };
-            let mut chunk_buffer = vec![
-                ByteRecord::with_capacity(record_buffer_size, num_fields);
-                chunk_size_rows
-            ];
+            let mut chunk_buffer = match &record_buffer_pool {
+                Some(pool) => pool.take(chunk_size_rows, record_buffer_size, num_fields),
+                None => vec![ByteRecord::with_capacity(record_buffer_size, num_fields); chunk_size_rows],
+            };
 
             let byte_pos_before = reader.position().byte();
-            rows_read = read_rows(&mut reader, 0, chunk_buffer.as_mut_slice()).await.context(ArrowSnafu {})?;
+            let records_read = read_rows(&mut reader, 0, chunk_buffer.as_mut_slice()).await.map_err(Error::from_record_read)?;
             let bytes_read = reader.position().byte() - byte_pos_before;
+            if let Some(progress) = &progress {
+                progress.report(reader.position().byte() as usize);
+            }
+            rows_read = records_read;
+            // Past the byte budget, only keep the records that end within it, which discards a record that was only
+            // partly within it, and stop reading.
+            if let Some(max_bytes) = max_bytes && reader.position().byte() > max_bytes as u64 {
+                let record_end = |i: usize| {
+                    if i + 1 < rows_read {
+                        chunk_buffer[i + 1].position().map_or(u64::MAX, |p| p.byte())
+                    } else {
+                        reader.position().byte()
+                    }
+                };
+                rows_read = (0..rows_read).take_while(|i| record_end(*i) <= max_bytes as u64).count();
+                reached_max_bytes = true;
+            }
 
             // Update stats.
             total_rows_read += rows_read;
@@ -352,83 +1181,285 @@ where
             let delta2 = (bytes_read as f64) - mean;
             m2 += delta * delta2;
             estimated_mean_row_size = mean;
-            estimated_std_row_size = (m2 / ((total_rows_read - 1) as f64)).sqrt();
-
+            estimated_std_row_size = (m2 / (total_rows_read.saturating_sub(1) as f64)).sqrt();
+
+            // A chunk whose trailing records were dropped ends where the first dropped record starts.
+            let byte_range = ByteRange {
+                start: byte_offset + byte_pos_before,
+                end: byte_offset + match chunk_buffer.get(rows_read) {
+                    Some(record) if rows_read < records_read => {
+                        record.position().map_or(reader.position().byte(), |p| p.byte())
+                    }
+                    _ => reader.position().byte(),
+                },
+            };
             chunk_buffer.truncate(rows_read);
-            yield chunk_buffer
+            yield (chunk_buffer, byte_range)
         }
     };
     // Parsing stream: we spawn background tokio + rayon tasks so we can pipeline chunk parsing with chunk reading, and
-    // we further parse each chunk column in parallel on the rayon threadpool.
-    let parse_stream = read_stream.map_ok(|record| {
-        let fields = fields.clone();
-        let projection_indices = projection_indices.clone();
-        tokio::spawn(async move {
-            let (send, recv) = tokio::sync::oneshot::channel();
-            rayon::spawn(move || {
-                let result = (move || {
-                    let chunk = projection_indices
-                        .par_iter()
-                        .map(|idx| {
-                            deserialize_column(
-                                record.as_slice(),
-                                *idx,
-                                fields[*idx].data_type().clone(),
-                                0,
-                            )
-                        })
-                        .collect::<arrow2::error::Result<Vec<Box<dyn arrow2::array::Array>>>>()?;
-                    DaftResult::Ok(chunk)
-                })();
-                let _ = send.send(result);
+    // we further parse each chunk column in parallel on the rayon threadpool, which is the global one unless a
+    // thread pool is provided.
+    let parse_options = Arc::new(parse_options);
+    let boolean_tokens = Arc::new(boolean_tokens);
+    let null_values = null_values.map(Arc::new);
+    let numeric_normalizer = numeric_normalizer.map(Arc::new);
+    let temporal_formats = temporal_formats.map(Arc::new);
+    read_stream
+        .map_ok(move |(record, byte_range)| {
+            let record_buffer_pool = record_buffer_pool.clone();
+            let column_stats = column_stats.clone();
+            let fields = fields.clone();
+            let projection_indices = projection_indices.clone();
+            let parse_options = parse_options.clone();
+            let boolean_tokens = boolean_tokens.clone();
+            let null_values = null_values.clone();
+            let numeric_normalizer = numeric_normalizer.clone();
+            let temporal_formats = temporal_formats.clone();
+            let thread_pool = thread_pool.clone();
+            let handle = tokio::spawn(async move {
+                let (send, recv) = tokio::sync::oneshot::channel();
+                let parse = move || {
+                    let result = (|| {
+                        let chunk = projection_indices
+                            .par_iter()
+                            .map(|idx| {
+                                deserialize_column_with_options(
+                                    record.as_slice(),
+                                    *idx,
+                                    fields[*idx].data_type().clone(),
+                                    &parse_options,
+                                    &boolean_tokens,
+                                    null_values.as_deref().map(Vec::as_slice),
+                                    numeric_normalizer.as_deref(),
+                                    temporal_formats.as_deref(),
+                                    0,
+                                )
+                            })
+                            .collect::<arrow2::error::Result<ColumnArrayChunk>>()?;
+                        check_non_nullable_columns(&chunk, &fields, &projection_indices, &record)?;
+                        if let Some(column_stats) = &column_stats {
+                            column_stats.record(&chunk, &projection_indices, &record);
+                        }
+                        DaftResult::Ok(chunk)
+                    })();
+                    // The parsed records are no longer needed, so their buffer can be reused for a later chunk.
+                    if let Some(pool) = record_buffer_pool {
+                        pool.put(record);
+                    }
+                    let _ = send.send(result);
+                };
+                // Columns are parsed in parallel on the pool that the chunk's parsing is spawned on.
+                match thread_pool {
+                    Some(thread_pool) => thread_pool.spawn(parse),
+                    None => rayon::spawn(parse),
+                }
+                recv.await.context(super::OneShotRecvSnafu {})?
             });
-            recv.await.context(super::OneShotRecvSnafu {})?
+            async move {
+                let chunk = handle.await.context(super::JoinSnafu {})??;
+                DaftResult::Ok((chunk, byte_range))
+            }
+            .boxed()
         })
-        .context(super::JoinSnafu {})
-    });
-    // Collect all chunks in chunk x column form.
-    let chunks = parse_stream
-        // Limit the number of chunks we have in flight at any given time.
-        .try_buffered(max_chunks_in_flight)
-        .try_collect::<Vec<_>>()
-        .await?
-        .into_iter()
-        .collect::<DaftResult<Vec<_>>>()?;
-    // Transpose chunk x column into column x chunk.
-    let mut column_arrays = vec![Vec::with_capacity(chunks.len()); projection_indices.len()];
-    for chunk in chunks.into_iter() {
-        for (idx, col) in chunk.into_iter().enumerate() {
-            column_arrays[idx].push(col);
-        }
-    }
-    Ok(column_arrays)
+        .boxed()
 }
 
 fn fields_to_projection_indices(
-    fields: &Vec<arrow2::datatypes::Field>,
-    include_columns: &Option<Vec<&str>>,
-) -> Arc<Vec<usize>> {
-    let field_name_to_idx = fields
+    fields: &[arrow2::datatypes::Field],
+    include_columns: &Option<Vec<String>>,
+    include_column_indices: &Option<Vec<usize>>,
+    case_insensitive: bool,
+) -> DaftResult<Arc<Vec<usize>>> {
+    if let Some(indices) = include_column_indices {
+        if include_columns.is_some() {
+            return Err(DaftError::ValueError(
+                "Only one of include_columns and include_column_indices can be provided for a CSV read"
+                    .to_string(),
+            ));
+        }
+        // Indices are used as-is, so no name lookup is needed, e.g. for headerless CSVs without column names.
+        if let Some(idx) = indices.iter().find(|idx| **idx >= fields.len()) {
+            return Err(DaftError::ValueError(format!(
+                "Column index {} of include_column_indices is out of range for a CSV with {} columns",
+                idx,
+                fields.len()
+            )));
+        }
+        return Ok(indices.clone().into());
+    }
+    let Some(cols) = include_columns else {
+        return Ok(Arc::new((0..fields.len()).collect()));
+    };
+    let lookup = FieldLookup::new(fields, case_insensitive);
+    let indices = cols
         .iter()
-        .enumerate()
-        .map(|(idx, f)| (f.name.as_ref(), idx))
-        .collect::<HashMap<&str, usize>>();
-    include_columns
-        .as_ref()
-        .map_or_else(
-            || (0..fields.len()).collect(),
-            |cols| {
-                cols.iter()
-                    .map(|c| field_name_to_idx[c])
+        .map(|c| lookup.index_of(c, "include_columns"))
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(indices.into())
+}
+
+/// Lookup of CSV fields by name, which matches names regardless of case if `case_insensitive` is set.
+struct FieldLookup<'a> {
+    fields: &'a [arrow2::datatypes::Field],
+    case_insensitive: bool,
+    // Indices of the fields with each (lowercased, if case-insensitive) name, in field order.
+    name_to_indices: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> FieldLookup<'a> {
+    fn new(fields: &'a [arrow2::datatypes::Field], case_insensitive: bool) -> Self {
+        let mut name_to_indices = HashMap::<String, Vec<usize>>::new();
+        for (idx, field) in fields.iter().enumerate() {
+            let key = if case_insensitive {
+                field.name.to_lowercase()
+            } else {
+                field.name.clone()
+            };
+            name_to_indices.entry(key).or_default().push(idx);
+        }
+        Self {
+            fields,
+            case_insensitive,
+            name_to_indices,
+        }
+    }
+
+    /// Returns the index of the field named `name`, which was requested by the `option` convert option.
+    fn index_of(&self, name: &str, option: &str) -> DaftResult<usize> {
+        let indices = if self.case_insensitive {
+            self.name_to_indices.get(&name.to_lowercase())
+        } else {
+            self.name_to_indices.get(name)
+        };
+        match indices.map(Vec::as_slice) {
+            Some([idx]) => Ok(*idx),
+            // Columns differing only by case are ambiguous when case is ignored.
+            Some(indices) if self.case_insensitive && indices.len() > 1 => {
+                Err(DaftError::ValueError(format!(
+                    "Column \"{}\" of {} matches several CSV columns that only differ by case: {:?}",
+                    name,
+                    option,
+                    indices
+                        .iter()
+                        .map(|idx| self.fields[*idx].name.as_str())
+                        .collect::<Vec<_>>()
+                )))
+            }
+            // Otherwise, the last of several columns with the same name wins.
+            Some([.., idx]) => Ok(*idx),
+            _ => Err(DaftError::FieldNotFound(format!(
+                "Column \"{}\" of {} not found in CSV columns: {:?}",
+                name,
+                option,
+                self.fields
+                    .iter()
+                    .map(|f| f.name.as_str())
                     .collect::<Vec<_>>()
-            },
-        )
-        .into()
+            ))),
+        }
+    }
+}
+
+fn apply_dtype_overrides(
+    mut fields: Vec<arrow2::datatypes::Field>,
+    dtype_overrides: &[(String, daft_core::DataType)],
+) -> DaftResult<Vec<arrow2::datatypes::Field>> {
+    for (name, dtype) in dtype_overrides {
+        let idx = fields.iter().position(|f| &f.name == name).ok_or_else(|| {
+            DaftError::FieldNotFound(format!(
+                "Column \"{}\" of dtype_overrides not found in CSV columns: {:?}",
+                name,
+                fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>()
+            ))
+        })?;
+        fields[idx].data_type = dtype.to_arrow()?;
+    }
+    Ok(fields)
+}
+
+fn apply_non_nullable_columns(
+    mut fields: Vec<arrow2::datatypes::Field>,
+    non_nullable_columns: &[String],
+) -> DaftResult<Vec<arrow2::datatypes::Field>> {
+    for name in non_nullable_columns {
+        let idx = fields.iter().position(|f| &f.name == name).ok_or_else(|| {
+            DaftError::FieldNotFound(format!(
+                "Column \"{}\" of non_nullable_columns not found in CSV columns: {:?}",
+                name,
+                fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>()
+            ))
+        })?;
+        fields[idx].is_nullable = false;
+    }
+    Ok(fields)
+}
+
+/// Checks that the parsed columns of non-nullable fields have no nulls, returning an error that points at the record of
+/// the first null otherwise.
+fn check_non_nullable_columns(
+    chunk: &ColumnArrayChunk,
+    fields: &[arrow2::datatypes::Field],
+    projection_indices: &[usize],
+    records: &[ByteRecord],
+) -> DaftResult<()> {
+    for (array, idx) in chunk.iter().zip(projection_indices) {
+        let field = &fields[*idx];
+        if field.is_nullable || array.null_count() == 0 {
+            continue;
+        }
+        // Arrays without a validity bitmap that have nulls are all null, e.g. arrays of the null type.
+        let row = array
+            .validity()
+            .and_then(|validity| validity.iter().position(|valid| !valid))
+            .unwrap_or(0);
+        let line = records
+            .get(row)
+            .and_then(|record| record.position())
+            .map_or_else(String::new, |position| {
+                format!(" on line {}", position.line())
+            });
+        return Err(DaftError::ValueError(format!(
+            "Column \"{}\" is non-nullable, but has a null value{} of the CSV",
+            field.name, line
+        )));
+    }
+    Ok(())
+}
+
+fn include_columns_as_to_projection(
+    fields: &[arrow2::datatypes::Field],
+    include_columns_as: &[(String, String)],
+    case_insensitive: bool,
+) -> DaftResult<(Arc<Vec<usize>>, Vec<arrow2::datatypes::Field>)> {
+    let lookup = FieldLookup::new(fields, case_insensitive);
+    let mut output_names = HashSet::with_capacity(include_columns_as.len());
+    let mut projection_indices = Vec::with_capacity(include_columns_as.len());
+    let mut projected_fields = Vec::with_capacity(include_columns_as.len());
+    for (source_name, output_name) in include_columns_as {
+        let idx = lookup.index_of(source_name, "include_columns_as")?;
+        if !output_names.insert(output_name.as_str()) {
+            return Err(DaftError::ValueError(format!(
+                "Duplicate output column name \"{}\" in include_columns_as",
+                output_name
+            )));
+        }
+        let field = &fields[idx];
+        projection_indices.push(idx);
+        projected_fields.push(
+            Field::new(output_name, field.data_type.clone(), field.is_nullable)
+                .with_metadata(field.metadata.clone()),
+        );
+    }
+    Ok((projection_indices.into(), projected_fields))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
 
     use common_error::{DaftError, DaftResult};
 
@@ -437,16 +1468,27 @@ mod tests {
         ReaderBuilder,
     };
     use daft_core::{
-        datatypes::Field,
+        datatypes::{Field, TimeUnit},
         schema::Schema,
         utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed},
         DataType,
     };
-    use daft_io::{IOClient, IOConfig};
+    use daft_dsl::{col, lit};
+    use daft_io::{get_runtime, IOClient, IOConfig};
     use daft_table::Table;
+    use futures::{FutureExt, StreamExt, TryStreamExt};
     use rstest::rstest;
+    use tokio::io::AsyncReadExt;
 
-    use super::read_csv;
+    use super::{
+        collect_column_array_chunks, read_csv, read_csv_bulk, read_csv_from_compressed_reader,
+        read_csv_from_reader, read_csv_streaming, read_csv_with_stats, record_buffer_size,
+        ColumnArrayChunk, ColumnArrayChunkStream,
+    };
+    use crate::{
+        compression::CompressionCodec, metadata::read_csv_schema, CsvConvertOptions, CsvEncoding,
+        CsvParseOptions, CsvReadOptions, NullByteHandling,
+    };
 
     fn check_equal_local_arrow2(
         path: &str,
@@ -463,7 +1505,14 @@ mod tests {
             .unwrap();
         let (mut fields, _) = infer_schema(&mut reader, None, has_header, &infer).unwrap();
         if !has_header && let Some(column_names) = column_names {
-            fields = fields.into_iter().zip(column_names).map(|(field, name)| arrow2::datatypes::Field::new(name, field.data_type, true).with_metadata(field.metadata)).collect::<Vec<_>>();
+            fields = fields
+                .into_iter()
+                .zip(column_names)
+                .map(|(field, name)| {
+                    arrow2::datatypes::Field::new(name, field.data_type, true)
+                        .with_metadata(field.metadata)
+                })
+                .collect::<Vec<_>>();
         }
         let mut rows = vec![ByteRecord::default(); limit.unwrap_or(100)];
         let rows_read = read_rows(&mut reader, 0, &mut rows).unwrap();
@@ -505,8 +1554,12 @@ mod tests {
             Some("deflate"),
             // gzip
             Some("gz"),
+            // lz4
+            Some("lz4"),
             // lzma
             Some("lzma"),
+            // snappy
+            Some("sz"),
             // xz
             Some("xz"),
             // zlib
@@ -527,21 +1580,7 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let table = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            None,
-        )?;
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
         assert_eq!(table.len(), 20);
         assert_eq!(
             table.schema,
@@ -582,18 +1621,16 @@ mod tests {
         ];
         let table = read_csv(
             file.as_ref(),
-            Some(column_names.clone()),
-            None,
-            None,
-            false,
+            Some(
+                CsvConvertOptions::default()
+                    .with_column_names(Some(column_names.iter().map(|s| s.to_string()).collect())),
+            ),
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 20);
         assert_eq!(
@@ -634,18 +1671,13 @@ mod tests {
 
         let table = read_csv(
             file.as_ref(),
+            Some(CsvConvertOptions::default().with_limit(Some(5))),
+            Some(CsvParseOptions::default().with_delimiter(b'|')),
             None,
-            None,
-            Some(5),
-            true,
-            Some(b'|'),
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 5);
         assert_eq!(
@@ -675,18 +1707,13 @@ mod tests {
 
         let table = read_csv(
             file.as_ref(),
+            Some(CsvConvertOptions::default().with_limit(Some(5))),
             None,
             None,
-            Some(5),
-            true,
-            None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 5);
         assert_eq!(
@@ -706,7 +1733,7 @@ mod tests {
     }
 
     #[test]
-    fn test_csv_read_local_projection() -> DaftResult<()> {
+    fn test_csv_read_local_limit_smaller_than_min_chunk_rows() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
 
         let mut io_config = IOConfig::default();
@@ -714,19 +1741,60 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
+        // A chunk size smaller than a single row, so that chunks are sized by the 8 row floor rather than the
+        // estimated row size.
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(16));
+        let convert_options = CsvConvertOptions::default().with_limit(Some(3));
         let table = read_csv(
             file.as_ref(),
+            Some(convert_options.clone()),
             None,
-            Some(vec!["petal.length", "petal.width"]),
+            Some(read_options.clone()),
+            io_client.clone(),
             None,
             true,
             None,
+        )?;
+        assert_eq!(table.len(), 3);
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, Some(3));
+
+        let tables = read_csv_streaming(
+            file.as_ref(),
+            Some(convert_options),
+            None,
+            Some(read_options),
             io_client,
             None,
             true,
             None,
+        )?
+        .map(|chunk| Ok(chunk?.0))
+        .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(tables.iter().map(Table::len).sum::<usize>(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_projection() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["petal.length".into(), "petal.width".into()])),
+            ),
+            None,
             None,
+            io_client,
             None,
+            true,
             None,
         )?;
         assert_eq!(table.len(), 20);
@@ -751,6 +1819,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_read_local_include_columns_as() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default().with_include_columns_as(Some(vec![
+                    ("petal.width".into(), "pw".into()),
+                    ("petal.length".into(), "pl".into()),
+                ])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("pw", DataType::Float64),
+                Field::new("pl", DataType::Float64),
+            ])?
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_include_columns_as_duplicate_output() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default().with_include_columns_as(Some(vec![
+                    ("petal.width".into(), "p".into()),
+                    ("petal.length".into(), "p".into()),
+                ])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        );
+        assert!(matches!(err, Err(DaftError::ValueError(_))));
+
+        Ok(())
+    }
+
     #[test]
     fn test_csv_read_local_no_headers_and_projection() -> DaftResult<()> {
         let file = format!(
@@ -772,18 +1906,17 @@ mod tests {
         ];
         let table = read_csv(
             file.as_ref(),
-            Some(column_names.clone()),
-            Some(vec!["petal.length", "petal.width"]),
-            None,
-            false,
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["petal.length".into(), "petal.width".into()]))
+                    .with_column_names(Some(column_names.iter().map(|s| s.to_string()).collect())),
+            ),
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 20);
         assert_eq!(
@@ -808,8 +1941,11 @@ mod tests {
     }
 
     #[test]
-    fn test_csv_read_local_larger_than_buffer_size() -> DaftResult<()> {
-        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+    fn test_csv_read_local_no_headers_and_column_indices() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_no_headers.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
 
         let mut io_config = IOConfig::default();
         io_config.s3.anonymous = true;
@@ -818,143 +1954,2131 @@ mod tests {
 
         let table = read_csv(
             file.as_ref(),
+            Some(CsvConvertOptions::default().with_include_column_indices(Some(vec![2, 3]))),
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
+            io_client.clone(),
             None,
             true,
             None,
-            Some(128),
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 20);
         assert_eq!(
             table.schema,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
+                Field::new("column_3", DataType::Float64),
+                Field::new("column_4", DataType::Float64),
             ])?
             .into(),
         );
-        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+        check_equal_local_arrow2(
+            file.as_ref(),
+            &table,
+            false,
+            None,
+            None,
+            Some(vec![2, 3]),
+            None,
+        );
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_include_column_indices(Some(vec![2, 5]))),
+            Some(CsvParseOptions::default().with_has_header(false)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        );
+        assert!(matches!(
+            err,
+            Err(DaftError::ValueError(msg)) if msg.contains("Column index 5")
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_read_local_larger_than_chunk_size() -> DaftResult<()> {
+    fn test_csv_read_local_include_columns_not_found() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
 
-        let mut io_config = IOConfig::default();
-        io_config.s3.anonymous = true;
-
-        let io_client = Arc::new(IOClient::new(io_config.into())?);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
 
-        let table = read_csv(
+        let err = read_csv(
             file.as_ref(),
+            Some(CsvConvertOptions::default().with_include_columns(Some(vec![
+                "petal.length".to_string(),
+                "Variety".to_string(),
+            ]))),
             None,
             None,
+            io_client,
             None,
             true,
             None,
-            io_client,
+        );
+        assert!(matches!(
+            err,
+            Err(DaftError::FieldNotFound(msg))
+                if msg.contains("\"Variety\"") && msg.contains("\"variety\"")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_case_insensitive_columns() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let convert_options = CsvConvertOptions::default().with_include_columns(Some(vec![
+            "VARIETY".to_string(),
+            "Sepal.Length".to_string(),
+        ]));
+        let table = read_csv(
+            file.as_ref(),
+            Some(convert_options.clone().with_case_insensitive_columns(true)),
             None,
-            true,
             None,
+            io_client.clone(),
             None,
-            Some(100),
+            true,
             None,
         )?;
         assert_eq!(table.len(), 20);
+        // Columns keep the names of the CSV header.
         assert_eq!(
             table.schema,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
                 Field::new("variety", DataType::Utf8),
+                Field::new("sepal.length", DataType::Float64),
             ])?
             .into(),
         );
-        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(convert_options),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        );
+        assert!(matches!(err, Err(DaftError::FieldNotFound(_))));
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_read_local_throttled_streaming() -> DaftResult<()> {
-        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
-
-        let mut io_config = IOConfig::default();
-        io_config.s3.anonymous = true;
+    fn test_csv_read_local_case_insensitive_columns_ambiguous() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/case_colliding_columns.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
 
-        let io_client = Arc::new(IOClient::new(io_config.into())?);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
 
-        let table = read_csv(
+        let err = read_csv(
             file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["NAME".to_string()]))
+                    .with_case_insensitive_columns(true),
+            ),
             None,
             None,
+            io_client.clone(),
             None,
             true,
             None,
+        );
+        assert!(matches!(
+            err,
+            Err(DaftError::ValueError(msg)) if msg.contains("[\"Name\", \"name\"]")
+        ));
+
+        // Columns that don't collide can still be matched regardless of case.
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["ID".to_string()]))
+                    .with_case_insensitive_columns(true),
+            ),
+            None,
+            None,
             io_client,
             None,
             true,
             None,
+        )?;
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.column_names(), vec!["id".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_duplicate_column_names() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/duplicate_header_columns.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
             None,
             None,
-            Some(5),
+            io_client.clone(),
+            None,
+            true,
+            None,
         )?;
-        assert_eq!(table.len(), 20);
         assert_eq!(
             table.schema,
             Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                Field::new("petal.length", DataType::Float64),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
+                Field::new("id", DataType::Int64),
+                Field::new("id_1", DataType::Int64),
+                Field::new("value", DataType::Utf8),
             ])?
             .into(),
         );
-        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_csv_read_local_nulls() -> DaftResult<()> {
-        let file = format!("{}/test/iris_tiny_nulls.csv", env!("CARGO_MANIFEST_DIR"),);
-
-        let mut io_config = IOConfig::default();
-        io_config.s3.anonymous = true;
-
-        let io_client = Arc::new(IOClient::new(io_config.into())?);
 
+        // Both columns are selectable, in any order.
         let table = read_csv(
             file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["id_1".to_string(), "id".to_string()])),
+            ),
             None,
             None,
+            io_client.clone(),
             None,
             true,
             None,
-            io_client,
+        )?;
+        assert_eq!(table.column_names(), vec!["id_1", "id"]);
+        assert_eq!(table.get_column("id")?.i64()?.get(1), Some(3));
+        assert_eq!(table.get_column("id_1")?.i64()?.get(1), Some(4));
+
+        // Repeated names provided by the caller are disambiguated the same way.
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_column_names(Some(vec![
+                "x".to_string(),
+                "x".to_string(),
+                "y".to_string(),
+            ]))),
+            None,
+            None,
+            io_client.clone(),
             None,
             true,
             None,
+        )?;
+        assert_eq!(table.column_names(), vec!["x", "x_1", "y"]);
+
+        let err = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_dedup_column_names(false)),
+            None,
             None,
+            io_client,
             None,
+            true,
             None,
-        )?;
-        assert_eq!(table.len(), 6);
+        );
+        assert!(matches!(
+            err,
+            Err(DaftError::ValueError(msg)) if msg.contains("duplicate column name \"id\"")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_max_bytes() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // The header and the first 3 records span 138 bytes, and the 4th record ends at byte 162.
+        for (max_bytes, expected_len) in [(150, 3), (138, 3), (137, 2), (10, 0), (10_000, 20)] {
+            let table = read_csv(
+                file.as_ref(),
+                None,
+                None,
+                Some(CsvReadOptions::default().with_max_bytes(Some(max_bytes))),
+                io_client.clone(),
+                None,
+                true,
+                None,
+            )?;
+            assert_eq!(table.len(), expected_len, "max_bytes = {max_bytes}");
+            if expected_len == 3 {
+                // The last record is whole.
+                assert_eq!(table.get_column("variety")?.utf8()?.get(2), Some("Setosa"));
+                assert_eq!(table.get_column("sepal.width")?.f64()?.get(2), Some(3.2));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_heavy_tailed_record_sizes() -> DaftResult<()> {
+        // 97 short records, and 3 records that are ~10,000x longer.
+        let mut contents = "id,text\n".to_string();
+        for i in 0..100 {
+            let text = if i % 33 == 32 {
+                "x".repeat(100_000)
+            } else {
+                "abc".to_string()
+            };
+            contents.push_str(&format!("{i},{text}\n"));
+        }
+        let path = std::env::temp_dir().join(format!(
+            "daft_csv_read_{}_heavy_tailed.csv",
+            std::process::id()
+        ));
+        std::fs::write(&path, contents)?;
+        let uri = path.to_str().unwrap();
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let result = read_csv_schema(uri, None, None, None, None, io_client.clone(), None)
+            .and_then(|(_, read_stats)| {
+                let table = read_csv(uri, None, None, None, io_client, None, true, None)?;
+                Ok((read_stats, table))
+            });
+        std::fs::remove_file(&path)?;
+        let (read_stats, table) = result?;
+
+        let histogram = read_stats.record_size_histogram.clone().unwrap();
+        assert_eq!(histogram.p85, 5);
+        assert_eq!(histogram.max, 100_002);
+        // The long records inflate the mean + 1 stddev buffer size to many times the size of nearly all records,
+        // whereas the p85 buffer fits ~85% of the records exactly.
+        let normal_buffer_size = record_buffer_size(
+            read_stats.mean_record_size_bytes,
+            read_stats.stddev_record_size_bytes,
+            None,
+        );
+        assert!(normal_buffer_size > 10_000, "{normal_buffer_size}");
+        let buffer_size = record_buffer_size(
+            read_stats.mean_record_size_bytes,
+            read_stats.stddev_record_size_bytes,
+            Some(histogram.p85),
+        );
+        assert_eq!(buffer_size, 5);
+
+        // The long records are still read in full.
+        assert_eq!(table.len(), 100);
+        let text = table.get_column("text")?;
+        assert_eq!(text.utf8()?.get(32).map(str::len), Some(100_000));
+        assert_eq!(text.utf8()?.get(33), Some("abc"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_larger_than_buffer_size() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(CsvReadOptions::default().with_buffer_size(Some(128))),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_quoted_fields_across_buffer_boundaries() -> DaftResult<()> {
+        // Quoted fields with embedded quotes, delimiters and line terminators, read with buffers and chunks small enough
+        // that their boundaries fall within most of those fields.
+        let file = format!(
+            "{}/test/pathological_quotes.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_buffer_size(Some(16))
+                    .with_chunk_size(Some(16)),
+            ),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 40);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("text", DataType::Utf8),
+                Field::new("note", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_streaming() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Small chunks, so that the file is split into several tables.
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(100));
+        let tables = read_csv_streaming(
+            file.as_ref(),
+            None,
+            None,
+            Some(read_options.clone()),
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?
+        .map(|chunk| Ok(chunk?.0))
+        .collect::<DaftResult<Vec<_>>>()?;
+        assert!(tables.len() > 1);
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(read_options),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(tables.iter().map(Table::len).sum::<usize>(), table.len());
+        for streamed in tables.iter() {
+            assert_eq!(streamed.schema, table.schema);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_streaming_byte_ranges() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let chunks = read_csv_streaming(
+            file.as_ref(),
+            None,
+            None,
+            Some(CsvReadOptions::default().with_chunk_size(Some(100))),
+            io_client,
+            None,
+            true,
+            None,
+        )?
+        .collect::<DaftResult<Vec<_>>>()?;
+        assert!(chunks.len() > 1);
+        assert_eq!(
+            chunks.iter().map(|(table, _)| table.len()).sum::<usize>(),
+            20
+        );
+        // The ranges cover the whole file, without gaps or overlaps.
+        let byte_ranges = chunks
+            .iter()
+            .map(|(_, byte_range)| *byte_range)
+            .collect::<Vec<_>>();
+        assert_eq!(byte_ranges.first().unwrap().start, 0);
+        assert!(byte_ranges.iter().all(|range| range.start < range.end));
+        assert!(byte_ranges.windows(2).all(|w| w[0].end == w[1].start));
+        assert_eq!(
+            byte_ranges.last().unwrap().end,
+            std::fs::metadata(&file)?.len()
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_read_local_start_byte(
+        // The uncompressed file is seeked, and the gzipped file is read up to the start byte.
+        #[values(None, Some("gz"))] compression: Option<&str>,
+    ) -> DaftResult<()> {
+        let reference_file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+        let file = match compression {
+            Some(ext) => format!("{reference_file}.{ext}"),
+            None => reference_file.clone(),
+        };
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let reference = read_csv(
+            reference_file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        // Offset of the start of each row, after the header line.
+        let contents = std::fs::read(&reference_file)?;
+        let row_starts = contents
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(i, _)| i + 1)
+            .collect::<Vec<_>>();
+
+        // Starting at the start of a row reads from that row, and starting mid-row reads from the next row.
+        for (start_byte, first_row) in [(row_starts[4], 4), (row_starts[4] + 3, 5), (0, 0)] {
+            let table = read_csv(
+                file.as_ref(),
+                None,
+                None,
+                Some(CsvReadOptions::default().with_start_byte(Some(start_byte))),
+                io_client.clone(),
+                None,
+                true,
+                None,
+            )?;
+            assert_eq!(table.schema, reference.schema, "start_byte = {start_byte}");
+            let expected = reference.slice(first_row, reference.len())?;
+            assert_eq!(table.len(), expected.len(), "start_byte = {start_byte}");
+            for name in reference.column_names() {
+                assert_eq!(
+                    table.get_column(&name)?.to_arrow(),
+                    expected.get_column(&name)?.to_arrow()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_progress() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let progress = Arc::new(Mutex::new(vec![]));
+        let callback_progress = progress.clone();
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_chunk_size(Some(100))
+                    .with_progress(Some(Arc::new(move |bytes_read| {
+                        callback_progress.lock().unwrap().push(bytes_read)
+                    }))),
+            ),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        let progress = progress.lock().unwrap();
+        assert!(progress.len() > 1);
+        assert!(progress.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(
+            *progress.last().unwrap() as u64,
+            std::fs::metadata(&file)?.len()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_temporal_formats() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/custom_temporal_formats.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Without format hints, the columns aren't recognized as temporal.
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.get_column("day")?.data_type(), &DataType::Utf8);
+        assert_eq!(table.get_column("at")?.data_type(), &DataType::Utf8);
+
+        // With format hints, values that don't match the formats are read as null rather than demoting the columns.
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_date_format(Some("%d/%m/%Y".into()))
+                    .with_timestamp_format(Some("%Y/%m/%d %H:%M".into())),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("day", DataType::Date),
+                Field::new("at", DataType::Timestamp(TimeUnit::Seconds, None)),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("day")?.to_arrow(),
+            arrow2::array::Int32Array::from([Some(19388), Some(19389), None, Some(19403)])
+                .to(arrow2::datatypes::DataType::Date32)
+                .boxed()
+        );
+        assert_eq!(
+            table.get_column("at")?.to_arrow(),
+            arrow2::array::Int64Array::from([
+                Some(1675173600),
+                Some(1675243800),
+                Some(1675363500),
+                None
+            ])
+            .to(arrow2::datatypes::DataType::Timestamp(
+                arrow2::datatypes::TimeUnit::Second,
+                None
+            ))
+            .boxed()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_predicate() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Small chunks, so that the predicate is evaluated on several chunks.
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(100));
+        let predicate = vec![col("sepal.length").gt(&lit(5.0))];
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_predicate(Some(predicate.clone()))),
+            None,
+            Some(read_options.clone()),
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 9);
+        let expected = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(read_options),
+            io_client,
+            None,
+            true,
+            None,
+        )?
+        .filter(&predicate)?;
+        assert_eq!(table.schema, expected.schema);
+        for name in table.column_names() {
+            assert_eq!(
+                table.get_column(&name)?.to_arrow(),
+                expected.get_column(&name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_predicate_with_limit() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // The limit applies to the rows that satisfy the predicate, for both chunk filters and post-filters.
+        for predicate in [
+            col("sepal.length").gt(&lit(5.0)),
+            col("sepal.length")
+                .gt(&lit(5.0))
+                .and(&col("sepal.width").gt(&lit(0.0))),
+        ] {
+            let table = read_csv(
+                file.as_ref(),
+                Some(
+                    CsvConvertOptions::default()
+                        .with_limit(Some(3))
+                        .with_predicate(Some(vec![predicate])),
+                ),
+                None,
+                Some(CsvReadOptions::default().with_chunk_size(Some(100))),
+                io_client.clone(),
+                None,
+                true,
+                None,
+            )?;
+            assert_eq!(
+                table.get_column("sepal.length")?.to_arrow(),
+                arrow2::array::Float64Array::from_slice([5.1, 5.4, 5.4]).boxed()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_larger_than_chunk_size() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(CsvReadOptions::default().with_chunk_size(Some(100))),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_throttled_streaming() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            Some(5),
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_max_chunks_in_flight_read_option() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let chunks_read = Arc::new(AtomicUsize::new(0));
+        let callback_chunks_read = chunks_read.clone();
+        let mut tables = read_csv_streaming(
+            file.as_ref(),
+            None,
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_chunk_size(Some(100))
+                    .with_max_chunks_in_flight(Some(1))
+                    .with_progress(Some(Arc::new(move |_| {
+                        callback_chunks_read.fetch_add(1, Ordering::SeqCst);
+                    }))),
+            ),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        // With a single chunk in flight, no chunk is read ahead of the one that's being parsed.
+        assert_eq!(tables.next().unwrap()?.0.len(), 8);
+        assert_eq!(chunks_read.load(Ordering::SeqCst), 1);
+        assert_eq!(tables.next().unwrap()?.0.len(), 8);
+        assert_eq!(chunks_read.load(Ordering::SeqCst), 2);
+
+        let num_rows = tables
+            .map(|table| Ok(table?.0.len()))
+            .sum::<DaftResult<usize>>()?;
+        assert_eq!(num_rows, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_empty_file() -> DaftResult<()> {
+        let file = format!("{}/test/empty.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.schema, Schema::empty().into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_header_only() -> DaftResult<()> {
+        let file = format!("{}/test/header_only.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 0);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Utf8),
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Utf8),
+            ])?
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_schema_with_row_size_hints() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = Schema::new(vec![
+            Field::new("sepal.length", DataType::Float64),
+            Field::new("sepal.width", DataType::Float64),
+            Field::new("petal.length", DataType::Float64),
+            Field::new("petal.width", DataType::Float64),
+            Field::new("variety", DataType::Utf8),
+        ])?;
+        let mut tables = read_csv_streaming(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_schema(Some(schema.into()))),
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_chunk_size(Some(1000))
+                    .with_mean_row_size_hint(Some(100))
+                    .with_stddev_row_size_hint(Some(10)),
+            ),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        // The first chunk is sized off the hints, as 1000 / 100 rows, rather than off the default row size.
+        assert_eq!(tables.next().unwrap()?.0.len(), 10);
+        let num_rows = tables
+            .map(|table| Ok(table?.0.len()))
+            .sum::<DaftResult<usize>>()?;
+        assert_eq!(num_rows, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_nulls() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny_nulls.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 6);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_nullable_int_schema() -> DaftResult<()> {
+        let file = format!("{}/test/int_empty_cells.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("count", DataType::Int64),
+        ])?;
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_schema(Some(schema.clone().into()))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.schema, schema.into());
+        // Empty cells of nullable integer columns are read as null.
+        assert_eq!(
+            table.get_column("count")?.to_arrow(),
+            Box::new(arrow2::array::Int64Array::from([Some(10), None, Some(30)]))
+                as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_non_nullable_int_schema() -> DaftResult<()> {
+        let file = format!("{}/test/int_empty_cells.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let convert_options = CsvConvertOptions::default().with_schema(Some(
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("count", DataType::Int64),
+            ])?
+            .into(),
+        ));
+        // Non-nullable columns without nulls are read as usual.
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                convert_options
+                    .clone()
+                    .with_non_nullable_columns(Some(vec!["id".to_string()])),
+            ),
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        // An empty cell of a non-nullable column is an error rather than read as null.
+        let err = read_csv(
+            file.as_ref(),
+            Some(convert_options.with_non_nullable_columns(Some(vec!["count".to_string()]))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(&err, DaftError::ValueError(message) if message.contains("\"count\" is non-nullable")),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_with_stats() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny_nulls.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let (table, column_stats) =
+            read_csv_with_stats(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(
+            column_stats
+                .columns
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>(),
+            table.column_names()
+        );
+        // Variety names are longer than the numbers of the numeric columns.
+        assert!(
+            column_stats.get("variety").unwrap().num_bytes
+                > column_stats.get("sepal.length").unwrap().num_bytes
+        );
+        for (name, stats) in column_stats.columns.iter() {
+            assert_eq!(
+                stats.null_count,
+                table.get_column(name)?.to_arrow().null_count()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_all_null_column() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_all_null_column.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 6);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                // All null column parsed as null dtype.
+                Field::new("petal.length", DataType::Null),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let null_column = table.get_column("petal.length")?;
+        assert_eq!(null_column.data_type(), &DataType::Null);
+        assert_eq!(null_column.len(), 6);
+        assert_eq!(
+            null_column.to_arrow(),
+            Box::new(arrow2::array::NullArray::new(
+                arrow2::datatypes::DataType::Null,
+                6
+            )) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_all_null_column_with_schema() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_all_null_column.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+        let schema = Schema::new(vec![
+            Field::new("sepal.length", DataType::Float64),
+            Field::new("sepal.width", DataType::Float64),
+            Field::new("petal.length", DataType::Null),
+            Field::new("petal.width", DataType::Float64),
+            Field::new("variety", DataType::Utf8),
+        ])?;
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_schema(Some(schema.into()))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 6);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                // All null column parsed as null dtype.
+                Field::new("petal.length", DataType::Null),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let null_column = table.get_column("petal.length")?;
+        assert_eq!(null_column.data_type(), &DataType::Null);
+        assert_eq!(null_column.len(), 6);
+        assert_eq!(
+            null_column.to_arrow(),
+            Box::new(arrow2::array::NullArray::new(
+                arrow2::datatypes::DataType::Null,
+                6
+            )) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_parentheses_as_negative() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/accounting_negatives.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+        let schema = Schema::new(vec![
+            Field::new("account", DataType::Utf8),
+            Field::new("amount", DataType::Float64),
+        ])?;
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_schema(Some(schema.into()))
+                    .with_thousands_separator(Some(b','))
+                    .with_parentheses_as_negative(true),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.get_column("amount")?.to_arrow(),
+            Box::new(arrow2::array::PrimitiveArray::<f64>::from(vec![
+                Some(-1234.56),
+                Some(2000.5),
+                Some(-3.0),
+                None,
+            ])) as Box<dyn arrow2::array::Array>
+        );
+        assert_eq!(
+            table.get_column("account")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "a", "b", "c", "d"
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_boolean_tokens() -> DaftResult<()> {
+        let file = format!("{}/test/yes_no_flags.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_true_values(Some(vec!["Y".into()]))
+                    .with_false_values(Some(vec!["N".into()])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.get_column("flag")?.to_arrow(),
+            Box::new(arrow2::array::BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_boolean_tokens_outside_sets() -> DaftResult<()> {
+        let file = format!("{}/test/yes_no_flags.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // A column forced to booleans reads the values that aren't tokens of either set as null.
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_dtype_overrides(Some(vec![("flag".to_string(), DataType::Boolean)]))
+                    .with_true_values(Some(vec!["yes".into(), "Y".into()]))
+                    .with_false_values(Some(vec!["no".into()])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(
+            table.get_column("flag")?.to_arrow(),
+            Box::new(arrow2::array::BooleanArray::from(vec![
+                Some(true),
+                None,
+                None,
+                Some(true),
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_dtype_overrides() -> DaftResult<()> {
+        let file = format!("{}/test/zip_codes.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_dtype_overrides(Some(vec![("zip".to_string(), DataType::Utf8)])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        // Only the overridden column is read as strings; its siblings keep their inferred dtypes.
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("zip", DataType::Utf8),
+                Field::new("count", DataType::Int64),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("zip")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "00123", "94107", "02134"
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_null_bytes() -> DaftResult<()> {
+        let file = format!("{}/test/null_bytes.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_null_byte_handling(NullByteHandling::Strip)),
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.get_column("name")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "abc", "plain"
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        // Null bytes fail the read by default.
+        let err = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("contains a null byte"), "{}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_utf16le() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny_utf16le.csv", env!("CARGO_MANIFEST_DIR"),);
+        let reference_file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_encoding(Some(CsvEncoding::Utf16Le))),
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        let reference = read_csv(
+            reference_file.as_ref(),
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(table.schema, reference.schema);
+        for name in reference.column_names() {
+            assert_eq!(
+                table.get_column(&name)?.to_arrow(),
+                reference.get_column(&name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_utf8_bom() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny_utf8_bom.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+
+        // The first column can be projected by its name.
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["sepal.length".to_string()])),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![Field::new("sepal.length", DataType::Float64)])?.into(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_trim() -> DaftResult<()> {
+        let file = format!("{}/test/padded_fields.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_trim(true)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 2);
+        // Padded numbers are inferred as numbers once trimmed.
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("value", DataType::Float64),
+                Field::new("label", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("value")?.to_arrow(),
+            Box::new(arrow2::array::Float64Array::from_slice([1.5, 2.25]))
+                as Box<dyn arrow2::array::Array>
+        );
+        // Whitespace within quotes is kept.
+        assert_eq!(
+            table.get_column("label")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice(["foo", " x "]))
+                as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_double_quote_toggle() -> DaftResult<()> {
+        let file = format!("{}/test/doubled_quotes.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // With double quoting, `"a""b"` is read as `a"b`; without it, the second quote ends the quoted part of the
+        // cell, and the rest of the cell, `"b"`, is read literally.
+        for (double_quote, expected) in [(true, "a\"b"), (false, "a\"b\"")] {
+            let table = read_csv(
+                file.as_ref(),
+                None,
+                Some(CsvParseOptions::default().with_double_quote(double_quote)),
+                None,
+                io_client.clone(),
+                None,
+                true,
+                None,
+            )?;
+            assert_eq!(table.len(), 2);
+            assert_eq!(
+                table.schema,
+                Schema::new(vec![
+                    Field::new("id", DataType::Int64),
+                    Field::new("text", DataType::Utf8),
+                ])?
+                .into(),
+            );
+            assert_eq!(
+                table.get_column("text")?.to_arrow(),
+                Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                    expected, "plain"
+                ])) as Box<dyn arrow2::array::Array>
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_escape_char() -> DaftResult<()> {
+        let file = format!("{}/test/escaped_quotes.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_escape(Some(b'\\'))),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("quote", DataType::Utf8),
+            ])?
+            .into(),
+        );
+
+        // Compare against arrow2's reader using the same escape character.
+        let mut reader = ReaderBuilder::new()
+            .escape(Some(b'\\'))
+            .from_path(&file)
+            .unwrap();
+        let (fields, _) = infer_schema(&mut reader, None, true, &infer).unwrap();
+        let mut rows = vec![ByteRecord::default(); 100];
+        let rows_read = read_rows(&mut reader, 0, &mut rows).unwrap();
+        let chunk =
+            deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column).unwrap();
+        let expected = cast_array_from_daft_if_needed(cast_array_for_daft_if_needed(
+            chunk.into_arrays().pop().unwrap(),
+        ));
+        assert_eq!(table.get_column("quote")?.to_arrow(), expected);
+        assert_eq!(
+            table.get_column("quote")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "he said \"hi\"",
+                "plain",
+                "a \"quoted\", with comma",
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_comment_lines_skipped() -> DaftResult<()> {
+        let file = format!("{}/test/commented_lines.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_comment(Some(b'#'))),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("label", DataType::Utf8),
+                Field::new("value", DataType::Float64),
+            ])?
+            .into(),
+        );
+        // A comment character in the middle of a line is kept as data.
+        assert_eq!(
+            table.get_column("label")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "a#1", "b", "c#"
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_cr_line_terminator() -> DaftResult<()> {
+        let file = format!("{}/test/cr_terminated.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_line_terminator(Some(b'\r'))),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("label", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        // Terminators within quoted cells, including the configured one, are kept as data.
+        assert_eq!(
+            table.get_column("label")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                "a\nb", "plain", "c\rd"
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_skip_rows() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_banner_rows.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+        let clean_file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // The banner rows are skipped before the header is consumed.
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_skip_rows(3)),
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        let clean_table = read_csv(
+            clean_file.as_ref(),
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(table.schema, clean_table.schema);
+        for i in 0..table.num_columns() {
+            assert_eq!(
+                table.get_column_by_index(i)?.to_arrow(),
+                clean_table.get_column_by_index(i)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_null_values() -> DaftResult<()> {
+        let file = format!("{}/test/null_tokens.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_null_values(Some(vec![
+                "NA".into(),
+                "N/A".into(),
+                "null".into(),
+            ]))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.get_column("score")?.to_arrow(),
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(1.5),
+                None,
+                None,
+                Some(4.0),
+            ])) as Box<dyn arrow2::array::Array>
+        );
+        // Null tokens only null out whole fields, not substrings of longer values.
+        assert_eq!(
+            table.get_column("label")?.to_arrow(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                None,
+                Some("NAB"),
+                Some("x"),
+                Some("NA x"),
+            ])) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_infer_decimals() -> DaftResult<()> {
+        let file = format!("{}/test/decimal_prices.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_infer_decimals(true)),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("item", DataType::Utf8),
+                Field::new("price", DataType::Decimal128(5, 2)),
+                // Inconsistent numbers of fractional digits fall back to floats.
+                Field::new("weight", DataType::Float64),
+            ])?
+            .into(),
+        );
+        // Decimals are parsed exactly, as integers scaled by 10^scale.
+        assert_eq!(
+            table.get_column("price")?.to_arrow(),
+            Box::new(
+                arrow2::array::PrimitiveArray::<i128>::from_slice([1250, 305, -710, 10000])
+                    .to(arrow2::datatypes::DataType::Decimal(5, 2))
+            ) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_decimal_scale_hints() -> DaftResult<()> {
+        let file = format!("{}/test/decimal_prices.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            Some(CsvConvertOptions::default().with_decimal_scales(Some(vec![
+                ("price".to_string(), 3),
+                ("weight".to_string(), 2),
+            ]))),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("item", DataType::Utf8),
+                Field::new("price", DataType::Decimal128(6, 3)),
+                Field::new("weight", DataType::Decimal128(3, 2)),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("price")?.to_arrow(),
+            Box::new(
+                arrow2::array::PrimitiveArray::<i128>::from_slice([12500, 3050, -7100, 100000])
+                    .to(arrow2::datatypes::DataType::Decimal(6, 3))
+            ) as Box<dyn arrow2::array::Array>
+        );
+        // 0.125 has more fractional digits than the hinted scale, so it's read as null.
+        assert_eq!(
+            table.get_column("weight")?.to_arrow(),
+            Box::new(
+                arrow2::array::PrimitiveArray::<i128>::from([Some(50), Some(125), None, Some(200)])
+                    .to(arrow2::datatypes::DataType::Decimal(3, 2))
+            ) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_all_strings_no_inference() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_no_headers.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let column_names = vec![
+            "sepal.length",
+            "sepal.width",
+            "petal.length",
+            "petal.width",
+            "variety",
+        ];
+        let table = read_csv(
+            file.as_ref(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_column_names(Some(column_names.iter().map(|s| s.to_string()).collect()))
+                    .with_all_strings(true),
+            ),
+            Some(CsvParseOptions::default().with_has_header(false)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(
+                column_names
+                    .iter()
+                    .map(|name| Field::new(*name, DataType::Utf8))
+                    .collect()
+            )?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("sepal.length")?.to_arrow().as_ref(),
+            &arrow2::array::Utf8Array::<i64>::from_slice([
+                "5.1", "4.9", "4.7", "4.6", "5", "5.4", "4.6", "5", "4.4", "4.9", "5.4", "4.8",
+                "4.8", "4.3", "5.8", "5.7", "5.4", "5.1", "5.7", "5.1"
+            ]) as &dyn arrow2::array::Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_dates() -> DaftResult<()> {
+        let file = format!("{}/test/dates.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.get_column("pure_date")?.to_arrow(),
+            Box::new(
+                arrow2::array::Int32Array::from_slice([18262, 18793, 19357])
+                    .to(arrow2::datatypes::DataType::Date32)
+            ) as Box<dyn arrow2::array::Array>
+        );
+        // Pure dates in a timestamp column are read as midnight of that date.
+        assert_eq!(
+            table.get_column("mixed")?.to_arrow(),
+            Box::new(
+                arrow2::array::Int64Array::from_slice([1577836800, 1623760200, 1672531199]).to(
+                    arrow2::datatypes::DataType::Timestamp(
+                        arrow2::datatypes::TimeUnit::Second,
+                        None
+                    )
+                )
+            ) as Box<dyn arrow2::array::Array>
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_bulk_local() -> DaftResult<()> {
+        let files = ["", ".gz", ".zst"]
+            .iter()
+            .map(|suffix| {
+                format!(
+                    "{}/test/iris_tiny.csv{}",
+                    env!("CARGO_MANIFEST_DIR"),
+                    suffix
+                )
+            })
+            .collect::<Vec<_>>();
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let tables = read_csv_bulk(
+            uris.as_slice(),
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+            2,
+        )?;
+        assert_eq!(tables.len(), 3);
+        for table in tables.iter() {
+            assert_eq!(table.len(), 20);
+            assert_eq!(
+                table.schema,
+                Schema::new(vec![
+                    Field::new("sepal.length", DataType::Float64),
+                    Field::new("sepal.width", DataType::Float64),
+                    Field::new("petal.length", DataType::Float64),
+                    Field::new("petal.width", DataType::Float64),
+                    Field::new("variety", DataType::Utf8),
+                ])?
+                .into(),
+            );
+            // Each table, compressed or not, should match the uncompressed file's data.
+            check_equal_local_arrow2(files[0].as_ref(), table, true, None, None, None, None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_bulk_local_reuse_record_buffers() -> DaftResult<()> {
+        // Files of different widths, so that recycled buffers are reused for records with other numbers of fields.
+        let files = ["iris_tiny.csv", "decimal_prices.csv", "iris_tiny.csv.gz"]
+            .iter()
+            .map(|file| format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), file))
+            .collect::<Vec<_>>();
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Small chunks, so that each file is read in several chunks and buffers are recycled within each read too.
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(100));
+        let read = |read_options: CsvReadOptions| {
+            read_csv_bulk(
+                uris.as_slice(),
+                None,
+                None,
+                Some(read_options),
+                io_client.clone(),
+                None,
+                true,
+                Some(2),
+                2,
+            )
+        };
+        let expected = read(read_options.clone())?;
+        let tables = read(read_options.clone().with_reuse_record_buffers(true))?;
+        assert_eq!(tables.len(), expected.len());
+        for (table, expected) in tables.iter().zip(expected.iter()) {
+            assert_eq!(table.schema, expected.schema);
+            for name in expected.column_names() {
+                assert_eq!(
+                    table.get_column(&name)?.to_arrow(),
+                    expected.get_column(&name)?.to_arrow()
+                );
+            }
+        }
+        // Single-file reads recycle buffers across their own chunks.
+        let table = read_csv(
+            uris[0],
+            None,
+            None,
+            Some(read_options.with_reuse_record_buffers(true)),
+            io_client,
+            None,
+            true,
+            Some(2),
+        )?;
+        assert_eq!(table.len(), 20);
+        check_equal_local_arrow2(uris[0], &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_thread_pool() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let thread_pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap(),
+        );
+        // Small chunks, so that several chunks are parsed on the pool.
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_chunk_size(Some(100))
+                    .with_thread_pool(Some(thread_pool)),
+            ),
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        check_equal_local_arrow2(file.as_ref(), &table, true, None, None, None, None);
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_read_from_reader(
+        #[values(
+            // uncompressed
+            None,
+            // gzip
+            Some("gz"),
+            // zstd
+            Some("zst"),
+        )]
+        compression: Option<&str>,
+    ) -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+        let compressed_file = format!(
+            "{}{}",
+            file,
+            compression.map_or("".to_string(), |ext| format!(".{}", ext))
+        );
+
+        // The compression of the in-memory bytes is detected from their leading bytes.
+        let bytes = std::fs::read(&compressed_file)?;
+        let table = read_csv_from_reader(std::io::Cursor::new(bytes), None, None, None)?;
+        assert_eq!(table.len(), 20);
         assert_eq!(
             table.schema,
             Schema::new(vec![
@@ -972,9 +4096,135 @@ mod tests {
     }
 
     #[test]
-    fn test_csv_read_local_all_null_column() -> DaftResult<()> {
+    fn test_csv_read_bulk_shared_max_chunks_in_flight() -> DaftResult<()> {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_in_flight = Arc::new(AtomicUsize::new(0));
+        let num_chunks_per_stream = [10, 1, 7];
+        // Each stream reports a chunk as in flight when it is yielded, until the chunk's parsing task completes.
+        let streams = num_chunks_per_stream
+            .iter()
+            .enumerate()
+            .map(|(stream_idx, num_chunks)| {
+                let in_flight = in_flight.clone();
+                let peak_in_flight = peak_in_flight.clone();
+                futures::stream::iter(0..*num_chunks)
+                    .map(move |chunk_idx| {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak_in_flight.fetch_max(current, Ordering::SeqCst);
+                        let in_flight = in_flight.clone();
+                        DaftResult::Ok(
+                            async move {
+                                tokio::task::yield_now().await;
+                                in_flight.fetch_sub(1, Ordering::SeqCst);
+                                let chunk: ColumnArrayChunk =
+                                    vec![Box::new(arrow2::array::Int64Array::from_vec(vec![
+                                        stream_idx as i64,
+                                        chunk_idx as i64,
+                                    ]))];
+                                Ok(chunk)
+                            }
+                            .boxed(),
+                        )
+                    })
+                    .boxed()
+            })
+            .collect::<Vec<ColumnArrayChunkStream>>();
+
+        let chunks = get_runtime(true)?.block_on(collect_column_array_chunks(streams, 4))?;
+        assert!(peak_in_flight.load(Ordering::SeqCst) <= 4);
+        assert_eq!(chunks.len(), num_chunks_per_stream.len());
+        // Each stream's chunks should be returned in order.
+        for (stream_idx, (stream_chunks, num_chunks)) in
+            chunks.iter().zip(num_chunks_per_stream).enumerate()
+        {
+            assert_eq!(stream_chunks.len(), num_chunks);
+            for (chunk_idx, chunk) in stream_chunks.iter().enumerate() {
+                assert_eq!(
+                    chunk[0].as_ref(),
+                    &arrow2::array::Int64Array::from_vec(vec![stream_idx as i64, chunk_idx as i64])
+                        as &dyn arrow2::array::Array
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_gzip_stream_incrementally() -> DaftResult<()> {
+        let num_rows = 500_000;
+        let csv = std::iter::once("id,name\n".to_string())
+            .chain((0..num_rows).map(|i| format!("{i},name_{i}\n")))
+            .collect::<String>();
+        let runtime_handle = get_runtime(true)?;
+        let _rt_guard = runtime_handle.enter();
+        runtime_handle.block_on(async move {
+            let mut compressed = vec![];
+            CompressionCodec::Gzip
+                .to_encoder(std::io::Cursor::new(csv.into_bytes()))
+                .read_to_end(&mut compressed)
+                .await?;
+            // A mocked object store stream, which yields the compressed object in small chunks and counts the bytes
+            // pulled from it.
+            let bytes_pulled = Arc::new(AtomicUsize::new(0));
+            let stream = {
+                let bytes_pulled = bytes_pulled.clone();
+                let chunks = compressed
+                    .chunks(8 * 1024)
+                    .map(bytes::Bytes::copy_from_slice)
+                    .collect::<Vec<_>>();
+                futures::stream::iter(chunks).map(move |chunk| {
+                    bytes_pulled.fetch_add(chunk.len(), Ordering::SeqCst);
+                    std::io::Result::Ok(chunk)
+                })
+            };
+            let schema = Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+            ])?
+            .to_arrow()?;
+            let (mut chunk_stream, _) = read_csv_from_compressed_reader(
+                tokio_util::io::StreamReader::new(stream),
+                Some(CompressionCodec::Gzip),
+                CsvConvertOptions::default(),
+                CsvParseOptions::default(),
+                schema,
+                64 * 1024,
+                64 * 1024,
+                None,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+            // Parsing the first chunk only pulls a small prefix of the compressed object, rather than all of it.
+            let (first_chunk, _) = chunk_stream.try_next().await?.unwrap().await?;
+            let mut rows_read = first_chunk[0].len();
+            let bytes_pulled_for_first_chunk = bytes_pulled.load(Ordering::SeqCst);
+            assert!(
+                bytes_pulled_for_first_chunk < compressed.len() / 4,
+                "pulled {bytes_pulled_for_first_chunk} of {} compressed bytes for the first chunk",
+                compressed.len()
+            );
+            while let Some(chunk) = chunk_stream.try_next().await? {
+                rows_read += chunk.await?.0[0].len();
+            }
+            assert_eq!(rows_read, num_rows);
+            assert_eq!(bytes_pulled.load(Ordering::SeqCst), compressed.len());
+            DaftResult::Ok(())
+        })
+    }
+
+    #[test]
+    fn test_csv_read_local_compression_from_magic_bytes() -> DaftResult<()> {
+        // A gzipped CSV without a compression extension.
         let file = format!(
-            "{}/test/iris_tiny_all_null_column.csv",
+            "{}/test/iris_tiny_gzip_no_extension",
             env!("CARGO_MANIFEST_DIR"),
         );
 
@@ -983,52 +4233,37 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let table = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            None,
-        )?;
-        assert_eq!(table.len(), 6);
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 20);
         assert_eq!(
             table.schema,
             Schema::new(vec![
                 Field::new("sepal.length", DataType::Float64),
                 Field::new("sepal.width", DataType::Float64),
-                // All null column parsed as null dtype.
-                Field::new("petal.length", DataType::Null),
+                Field::new("petal.length", DataType::Float64),
                 Field::new("petal.width", DataType::Float64),
                 Field::new("variety", DataType::Utf8),
             ])?
             .into(),
         );
-        let null_column = table.get_column("petal.length")?;
-        assert_eq!(null_column.data_type(), &DataType::Null);
-        assert_eq!(null_column.len(), 6);
-        assert_eq!(
-            null_column.to_arrow(),
-            Box::new(arrow2::array::NullArray::new(
-                arrow2::datatypes::DataType::Null,
-                6
-            )) as Box<dyn arrow2::array::Array>
+        check_equal_local_arrow2(
+            format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR")).as_ref(),
+            &table,
+            true,
+            None,
+            None,
+            None,
+            None,
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_csv_read_local_all_null_column_with_schema() -> DaftResult<()> {
+    fn test_csv_read_local_multi_member_gzip() -> DaftResult<()> {
+        // Two concatenated gzip members, the first with the header and the first 10 rows, the second with the rest.
         let file = format!(
-            "{}/test/iris_tiny_all_null_column.csv",
+            "{}/test/iris_tiny_multi_member.csv.gz",
             env!("CARGO_MANIFEST_DIR"),
         );
 
@@ -1036,51 +4271,17 @@ mod tests {
         io_config.s3.anonymous = true;
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
-        let schema = Schema::new(vec![
-            Field::new("sepal.length", DataType::Float64),
-            Field::new("sepal.width", DataType::Float64),
-            Field::new("petal.length", DataType::Null),
-            Field::new("petal.width", DataType::Float64),
-            Field::new("variety", DataType::Utf8),
-        ])?;
 
-        let table = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(table.len(), 20);
+        check_equal_local_arrow2(
+            format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR")).as_ref(),
+            &table,
             true,
             None,
-            io_client,
-            None,
-            true,
-            Some(schema.into()),
             None,
             None,
             None,
-        )?;
-        assert_eq!(table.len(), 6);
-        assert_eq!(
-            table.schema,
-            Schema::new(vec![
-                Field::new("sepal.length", DataType::Float64),
-                Field::new("sepal.width", DataType::Float64),
-                // All null column parsed as null dtype.
-                Field::new("petal.length", DataType::Null),
-                Field::new("petal.width", DataType::Float64),
-                Field::new("variety", DataType::Utf8),
-            ])?
-            .into(),
-        );
-        let null_column = table.get_column("petal.length")?;
-        assert_eq!(null_column.data_type(), &DataType::Null);
-        assert_eq!(null_column.len(), 6);
-        assert_eq!(
-            null_column.to_arrow(),
-            Box::new(arrow2::array::NullArray::new(
-                arrow2::datatypes::DataType::Null,
-                6
-            )) as Box<dyn arrow2::array::Array>
         );
 
         Ok(())
@@ -1098,21 +4299,7 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let table = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            None,
-        )?;
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
         assert_eq!(table.len(), 3);
         assert_eq!(
             table.schema,
@@ -1149,17 +4336,12 @@ mod tests {
         ])?;
         let table = read_csv(
             file.as_ref(),
+            Some(CsvConvertOptions::default().with_schema(Some(schema.into()))),
             None,
             None,
-            None,
-            true,
-            None,
             io_client,
             None,
             true,
-            Some(schema.into()),
-            None,
-            None,
             None,
         )?;
         let num_rows = table.len();
@@ -1185,24 +4367,20 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let err = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            None,
-        );
+        let err = read_csv(file.as_ref(), None, None, None, io_client, None, true, None);
         assert!(err.is_err());
         let err = err.unwrap_err();
-        assert!(matches!(err, DaftError::ArrowError(_)), "{}", err);
+        assert!(
+            matches!(
+                &err,
+                DaftError::External(source) if matches!(
+                    source.downcast_ref::<crate::Error>(),
+                    Some(crate::Error::FieldCountMismatch { expected: 5, found: 4, .. })
+                )
+            ),
+            "{}",
+            err
+        );
         assert!(
             err.to_string()
                 .contains("found record with 4 fields, but the previous record has 5 fields"),
@@ -1228,21 +4406,26 @@ mod tests {
         let err = read_csv(
             file.as_ref(),
             None,
-            None,
-            None,
-            false,
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         );
         assert!(err.is_err());
         let err = err.unwrap_err();
-        assert!(matches!(err, DaftError::ArrowError(_)), "{}", err);
+        assert!(
+            matches!(
+                &err,
+                DaftError::External(source) if matches!(
+                    source.downcast_ref::<crate::Error>(),
+                    Some(crate::Error::FieldCountMismatch { expected: 4, found: 5, .. })
+                )
+            ),
+            "{}",
+            err
+        );
         assert!(
             err.to_string()
                 .contains("found record with 5 fields, but the previous record has 4 fields"),
@@ -1253,6 +4436,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_read_local_flexible_variable_num_cols() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_invalid_no_header_variable_num_cols.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        // Without a header, the widest record sets the number of columns, and shorter records are padded with nulls.
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(
+                CsvParseOptions::default()
+                    .with_has_header(false)
+                    .with_flexible(true),
+            ),
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("column_1", DataType::Float64),
+                Field::new("column_2", DataType::Float64),
+                Field::new("column_3", DataType::Float64),
+                Field::new("column_4", DataType::Float64),
+                Field::new("column_5", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table.get_column("column_5")?.to_arrow(),
+            arrow2::array::Utf8Array::<i64>::from([None, Some("Seratosa"), None]).boxed()
+        );
+
+        // With a header, the header sets the number of columns, and the extra trailing fields of longer records are
+        // dropped.
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_flexible(true)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("5.1", DataType::Float64),
+                Field::new("3.5", DataType::Float64),
+                Field::new("1.4", DataType::Float64),
+                Field::new(".2", DataType::Float64),
+            ])?
+            .into(),
+        );
+
+        Ok(())
+    }
+
     #[rstest]
     fn test_csv_read_s3_compression(
         #[values(
@@ -1287,21 +4541,7 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let table = read_csv(
-            file.as_ref(),
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            None,
-        )?;
+        let table = read_csv(file.as_ref(), None, None, None, io_client, None, true, None)?;
         assert_eq!(table.len(), 100);
         assert_eq!(
             table.schema,
@@ -1327,18 +4567,16 @@ mod tests {
         let column_names = vec!["a", "b"];
         let table = read_csv(
             file,
-            Some(column_names.clone()),
-            None,
-            None,
-            false,
+            Some(
+                CsvConvertOptions::default()
+                    .with_column_names(Some(column_names.iter().map(|s| s.to_string()).collect())),
+            ),
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 100);
         assert_eq!(
@@ -1365,18 +4603,17 @@ mod tests {
         let column_names = vec!["a", "b"];
         let table = read_csv(
             file,
-            Some(column_names.clone()),
-            Some(vec!["b"]),
-            None,
-            false,
+            Some(
+                CsvConvertOptions::default()
+                    .with_include_columns(Some(vec!["b".into()]))
+                    .with_column_names(Some(column_names.iter().map(|s| s.to_string()).collect())),
+            ),
+            Some(CsvParseOptions::default().with_has_header(false)),
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 100);
         assert_eq!(
@@ -1398,18 +4635,13 @@ mod tests {
 
         let table = read_csv(
             file,
+            Some(CsvConvertOptions::default().with_limit(Some(10))),
             None,
             None,
-            Some(10),
-            true,
-            None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 10);
         assert_eq!(
@@ -1435,18 +4667,13 @@ mod tests {
 
         let table = read_csv(
             file,
+            Some(CsvConvertOptions::default().with_include_columns(Some(vec!["b".into()]))),
             None,
-            Some(vec!["b"]),
-            None,
-            true,
             None,
             io_client,
             None,
             true,
             None,
-            None,
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 100);
         assert_eq!(
@@ -1470,16 +4697,11 @@ mod tests {
             file,
             None,
             None,
-            None,
-            true,
-            None,
+            Some(CsvReadOptions::default().with_buffer_size(Some(100))),
             io_client,
             None,
             true,
             None,
-            Some(100),
-            None,
-            None,
         )?;
         assert_eq!(table.len(), 5000);
 
@@ -1499,16 +4721,11 @@ mod tests {
             file,
             None,
             None,
-            None,
-            true,
-            None,
+            Some(CsvReadOptions::default().with_chunk_size(Some(100))),
             io_client,
             None,
             true,
             None,
-            None,
-            Some(100),
-            None,
         )?;
         assert_eq!(table.len(), 5000);
 
@@ -1524,21 +4741,7 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let table = read_csv(
-            file,
-            None,
-            None,
-            None,
-            true,
-            None,
-            io_client,
-            None,
-            true,
-            None,
-            None,
-            None,
-            Some(5),
-        )?;
+        let table = read_csv(file, None, None, None, io_client, None, true, Some(5))?;
         assert_eq!(table.len(), 5000);
 
         Ok(())
@@ -0,0 +1,193 @@
+use std::io;
+
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+/// Size of the header of a chunk: a 1-byte chunk type, followed by the 3-byte little-endian length of its data.
+const CHUNK_HEADER_SIZE: usize = 4;
+/// Maximum number of uncompressed bytes in a chunk.
+const MAX_CHUNK_SIZE: usize = 65536;
+
+const COMPRESSED_CHUNK: u8 = 0x00;
+const UNCOMPRESSED_CHUNK: u8 = 0x01;
+const STREAM_IDENTIFIER_CHUNK: u8 = 0xff;
+const STREAM_IDENTIFIER: &[u8] = b"sNaPpY";
+
+/// Decompresses a stream in the Snappy framing format, e.g. `.sz` files, as its bytes are read.
+pub(crate) fn decode_framed<R>(reader: R) -> impl Stream<Item = io::Result<Bytes>>
+where
+    R: AsyncRead + Send + 'static,
+{
+    async_stream::try_stream! {
+        let mut decoder = FrameDecoder::new();
+        let mut chunks = ReaderStream::with_capacity(Box::pin(reader), MAX_CHUNK_SIZE);
+        while let Some(chunk) = chunks.try_next().await? {
+            yield Bytes::from(decoder.decode(&chunk)?);
+        }
+        decoder.finish()?;
+    }
+}
+
+/// Compresses a stream in the Snappy framing format, as its bytes are read.
+pub(crate) fn encode_framed<R>(reader: R) -> impl Stream<Item = io::Result<Bytes>>
+where
+    R: AsyncRead + Send + 'static,
+{
+    async_stream::try_stream! {
+        let mut encoder = snap::raw::Encoder::new();
+        yield Bytes::from(chunk(STREAM_IDENTIFIER_CHUNK, &[], STREAM_IDENTIFIER));
+        // Each chunk of the reader fits in a single frame chunk.
+        let mut chunks = ReaderStream::with_capacity(Box::pin(reader), MAX_CHUNK_SIZE);
+        while let Some(data) = chunks.try_next().await? {
+            let checksum = masked_crc32c(&data).to_le_bytes();
+            let compressed = encoder.compress_vec(&data).map_err(invalid_data)?;
+            // Data that doesn't compress is stored as-is.
+            yield Bytes::from(if compressed.len() < data.len() {
+                chunk(COMPRESSED_CHUNK, &checksum, &compressed)
+            } else {
+                chunk(UNCOMPRESSED_CHUNK, &checksum, &data)
+            });
+        }
+    }
+}
+
+fn chunk(chunk_type: u8, checksum: &[u8], data: &[u8]) -> Vec<u8> {
+    let len = (checksum.len() + data.len()) as u32;
+    let mut chunk = Vec::with_capacity(CHUNK_HEADER_SIZE + len as usize);
+    chunk.push(chunk_type);
+    chunk.extend_from_slice(&len.to_le_bytes()[..3]);
+    chunk.extend_from_slice(checksum);
+    chunk.extend_from_slice(data);
+    chunk
+}
+
+/// Decoder of the Snappy framing format, which splits a stream into independently compressed chunks.
+///
+/// Bytes are decoded as they arrive, so chunks that span several reads are buffered until they're complete.
+struct FrameDecoder {
+    decoder: snap::raw::Decoder,
+    // Bytes of the chunks that haven't been received in full yet.
+    pending: Vec<u8>,
+    seen_stream_identifier: bool,
+}
+
+impl FrameDecoder {
+    fn new() -> Self {
+        Self {
+            decoder: snap::raw::Decoder::new(),
+            pending: vec![],
+            seen_stream_identifier: false,
+        }
+    }
+
+    /// Returns the decompressed data of the chunks completed by `bytes`.
+    fn decode(&mut self, bytes: &[u8]) -> io::Result<Vec<u8>> {
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.extend_from_slice(bytes);
+        let mut decoded = vec![];
+        let mut offset = 0;
+        while let Some(header) = pending.get(offset..offset + CHUNK_HEADER_SIZE) {
+            let len = u32::from_le_bytes([header[1], header[2], header[3], 0]) as usize;
+            let end = offset + CHUNK_HEADER_SIZE + len;
+            let Some(data) = pending.get(offset + CHUNK_HEADER_SIZE..end) else {
+                break;
+            };
+            self.decode_chunk(header[0], data, &mut decoded)?;
+            offset = end;
+        }
+        pending.drain(..offset);
+        self.pending = pending;
+        Ok(decoded)
+    }
+
+    fn decode_chunk(
+        &mut self,
+        chunk_type: u8,
+        data: &[u8],
+        decoded: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        match chunk_type {
+            STREAM_IDENTIFIER_CHUNK => {
+                if data != STREAM_IDENTIFIER {
+                    return Err(invalid_data("invalid Snappy stream identifier"));
+                }
+                self.seen_stream_identifier = true;
+            }
+            _ if !self.seen_stream_identifier => {
+                return Err(invalid_data(
+                    "Snappy stream doesn't start with a stream identifier",
+                ));
+            }
+            COMPRESSED_CHUNK | UNCOMPRESSED_CHUNK => {
+                if data.len() < 4 {
+                    return Err(invalid_data("Snappy chunk is too short for its checksum"));
+                }
+                let (checksum, data) = data.split_at(4);
+                let start = decoded.len();
+                if chunk_type == COMPRESSED_CHUNK {
+                    decoded.extend(self.decoder.decompress_vec(data).map_err(invalid_data)?);
+                } else {
+                    decoded.extend_from_slice(data);
+                }
+                if masked_crc32c(&decoded[start..]).to_le_bytes() != checksum {
+                    return Err(invalid_data("Snappy chunk checksum mismatch"));
+                }
+            }
+            0x02..=0x7f => {
+                return Err(invalid_data(format!(
+                    "unsupported unskippable Snappy chunk type {chunk_type:#04x}"
+                )));
+            }
+            // Padding and reserved skippable chunks.
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Checks that the stream didn't end within a chunk.
+    fn finish(&self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Snappy stream ended within a chunk",
+            ))
+        }
+    }
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Lookup table of the CRC-32C (Castagnoli) checksum, for each byte value.
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82f63b78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32C checksum of `bytes`, masked as in the Snappy framing format.
+fn masked_crc32c(bytes: &[u8]) -> u32 {
+    let crc = !bytes.iter().fold(!0u32, |crc, byte| {
+        CRC32C_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    });
+    crc.rotate_right(15).wrapping_add(0xa282ead8)
+}
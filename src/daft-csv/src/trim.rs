@@ -0,0 +1,108 @@
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use tokio::io::AsyncRead;
+use tokio_util::io::{ReaderStream, StreamReader};
+
+use crate::CsvParseOptions;
+
+const QUOTE: u8 = b'"';
+
+/// Wraps `reader` to trim the leading and trailing ASCII whitespace of every field if `trim` is set in
+/// `parse_options`, or returns `reader` as-is otherwise.
+pub(crate) fn maybe_trim_fields<'a, R>(
+    reader: R,
+    parse_options: &CsvParseOptions,
+) -> Pin<Box<dyn AsyncRead + Send + 'a>>
+where
+    R: AsyncRead + Unpin + Send + 'a,
+{
+    if !parse_options.trim {
+        return Box::pin(reader);
+    }
+    let mut trimmer = FieldTrimmer::new(parse_options);
+    Box::pin(StreamReader::new(
+        ReaderStream::new(reader).map_ok(move |chunk| Bytes::from(trimmer.trim(&chunk))),
+    ))
+}
+
+/// Trims whitespace around the fields of a stream of CSV bytes, chunk by chunk.
+///
+/// Whitespace is only trimmed outside of quotes, so the contents of quoted fields are kept as-is, e.g. `" x "` is
+/// still read as ` x `. Trimming happens on the raw bytes, before they're parsed into records, so that it applies
+/// equally to schema inference and to reads.
+struct FieldTrimmer {
+    delimiter: u8,
+    escape: Option<u8>,
+    line_terminator: Option<u8>,
+    // Whether we're within the quotes of a quoted field.
+    in_quotes: bool,
+    // Whether the previous byte was an escape character within quotes.
+    escaped: bool,
+    // Whether the current field started with a quote.
+    quoted_field: bool,
+    // Whether no non-whitespace byte of the current field has been seen yet.
+    at_field_start: bool,
+    // Whitespace seen after the last non-whitespace byte of the current field, which is only kept if more
+    // non-whitespace bytes follow within the field. This is carried over across chunks.
+    pending_whitespace: Vec<u8>,
+}
+
+impl FieldTrimmer {
+    fn new(parse_options: &CsvParseOptions) -> Self {
+        Self {
+            delimiter: parse_options.delimiter,
+            escape: parse_options.escape,
+            line_terminator: parse_options.line_terminator,
+            in_quotes: false,
+            escaped: false,
+            quoted_field: false,
+            at_field_start: true,
+            pending_whitespace: vec![],
+        }
+    }
+
+    fn is_terminator(&self, byte: u8) -> bool {
+        match self.line_terminator {
+            Some(terminator) => byte == terminator,
+            None => byte == b'\n' || byte == b'\r',
+        }
+    }
+
+    fn trim(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let mut trimmed = Vec::with_capacity(bytes.len() + self.pending_whitespace.len());
+        for &byte in bytes {
+            if self.in_quotes {
+                trimmed.push(byte);
+                if self.escaped {
+                    self.escaped = false;
+                } else if Some(byte) == self.escape {
+                    self.escaped = true;
+                } else if byte == QUOTE {
+                    self.in_quotes = false;
+                }
+            } else if byte == self.delimiter || self.is_terminator(byte) {
+                self.pending_whitespace.clear();
+                trimmed.push(byte);
+                self.quoted_field = false;
+                self.at_field_start = true;
+            } else if byte.is_ascii_whitespace() {
+                if !self.at_field_start {
+                    self.pending_whitespace.push(byte);
+                }
+            } else {
+                // A quote opens a quoted field at the start of a field, or reopens the quotes of a quoted field for a
+                // doubled quote; elsewhere, it's literal.
+                if byte == QUOTE && (self.at_field_start || self.quoted_field) {
+                    self.in_quotes = true;
+                    self.quoted_field = true;
+                }
+                trimmed.append(&mut self.pending_whitespace);
+                trimmed.push(byte);
+                self.at_field_start = false;
+            }
+        }
+        trimmed
+    }
+}
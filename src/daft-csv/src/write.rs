@@ -0,0 +1,320 @@
+use std::{io::Write, sync::Arc};
+
+use arrow2::{
+    array::{Array, Utf8Array},
+    chunk::Chunk,
+    io::csv::write::{write_chunk, write_header, SerializeOptions},
+};
+use bytes::Bytes;
+use common_error::DaftResult;
+use daft_core::{array::ops::as_arrow::AsArrow, DataType, Series};
+use daft_io::{get_runtime, IOClient};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use tokio_util::io::{ReaderStream, StreamReader};
+
+use crate::{compression::CompressionCodec, CsvParseOptions, CsvWriteOptions};
+
+// Number of rows serialized at a time when writing a table as CSV.
+const WRITE_CHUNK_ROWS: usize = 64 * 1024;
+
+/// Writes a table as CSV to the file at `uri`, compressing it according to the compression extension of `uri`, if
+/// any, e.g. `.csv.gz`.
+///
+/// The table is serialized (and compressed) `WRITE_CHUNK_ROWS` rows at a time as the file is written, so only a chunk
+/// of the file is buffered in memory at a time. The file is written through `io_client`, which never exposes a
+/// partially written file to readers: local files are written to a temporary file next to them and renamed into
+/// place, and S3 objects are only exposed once their upload completes.
+pub fn write_csv(
+    table: &Table,
+    uri: &str,
+    parse_options: Option<CsvParseOptions>,
+    write_options: Option<CsvWriteOptions>,
+    io_client: Arc<IOClient>,
+) -> DaftResult<()> {
+    let compression_codec = CompressionCodec::try_from_uri(uri)?;
+    let parse_options = parse_options.unwrap_or_default();
+    let table = table.clone();
+    // An empty table is still written as a single (empty) chunk, for its header.
+    let num_chunks = table.len().div_ceil(WRITE_CHUNK_ROWS).max(1);
+    let chunks = futures::stream::iter(0..num_chunks).map(move |i| {
+        let start = i * WRITE_CHUNK_ROWS;
+        let end = (start + WRITE_CHUNK_ROWS).min(table.len());
+        // Only the first chunk starts with the header.
+        let parse_options = parse_options
+            .clone()
+            .with_has_header(parse_options.has_header && i == 0);
+        let mut buffer = vec![];
+        serialize_csv(
+            &table.slice(start, end)?,
+            &mut buffer,
+            Some(parse_options),
+            write_options.clone(),
+        )?;
+        DaftResult::Ok(Bytes::from(buffer))
+    });
+    let chunks = chunks
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
+        .boxed();
+    let data = match compression_codec {
+        Some(compression_codec) => {
+            ReaderStream::new(compression_codec.to_encoder(StreamReader::new(chunks))).boxed()
+        }
+        None => chunks,
+    };
+
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(io_client.single_url_put_stream(uri, data, None))?;
+    Ok(())
+}
+
+/// Serializes a table to `writer` as CSV, with the delimiter of `parse_options`, and a header row of the column names
+/// if `parse_options` has a header.
+///
+/// When `float_precision` is set, float columns are rounded to that many decimal places, which is lossy: the written
+/// values may not read back identically. Otherwise, floats are written with full round-trip precision.
+pub fn serialize_csv<W: Write>(
+    table: &Table,
+    writer: &mut W,
+    parse_options: Option<CsvParseOptions>,
+    write_options: Option<CsvWriteOptions>,
+) -> DaftResult<()> {
+    let parse_options = parse_options.unwrap_or_default();
+    let write_options = write_options.unwrap_or_default();
+    let column_names = table.column_names();
+    let arrays = column_names
+        .iter()
+        .map(|name| {
+            let series = table.get_column(name)?;
+            match write_options.float_precision {
+                Some(precision) if series.data_type().is_floating() => {
+                    format_floats(series, precision)
+                }
+                _ => Ok(series.to_arrow()),
+            }
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let serialize_options = SerializeOptions {
+        delimiter: parse_options.delimiter,
+        ..Default::default()
+    };
+    if parse_options.has_header {
+        write_header(writer, &column_names, &serialize_options)?;
+    }
+    write_chunk(writer, &Chunk::new(arrays), &serialize_options)?;
+    Ok(())
+}
+
+/// Formats a float series as strings with `precision` decimal places.
+fn format_floats(series: &Series, precision: usize) -> DaftResult<Box<dyn Array>> {
+    let series = series.cast(&DataType::Float64)?;
+    let formatted = series
+        .f64()?
+        .as_arrow()
+        .iter()
+        .map(|v| v.map(|v| format!("{v:.precision$}")))
+        .collect::<Utf8Array<i64>>();
+    Ok(formatted.boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Float64Array, Int64Array, Utf8Array},
+        IntoSeries,
+    };
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+
+    use super::{serialize_csv, write_csv, WRITE_CHUNK_ROWS};
+    use crate::{read::read_csv, CsvConvertOptions, CsvParseOptions, CsvWriteOptions};
+
+    fn float_table() -> DaftResult<Table> {
+        Table::from_columns(vec![
+            Int64Array::from(("id", vec![1, 2, 3])).into_series(),
+            Float64Array::from((
+                "value",
+                Box::new(arrow2::array::Float64Array::from(vec![
+                    Some(1.23456),
+                    None,
+                    Some(-2.0 / 3.0),
+                ])),
+            ))
+            .into_series(),
+        ])
+    }
+
+    #[test]
+    fn test_csv_write_float_precision() -> DaftResult<()> {
+        let table = float_table()?;
+        let mut buffer = vec![];
+        serialize_csv(
+            &table,
+            &mut buffer,
+            None,
+            Some(CsvWriteOptions::default().with_float_precision(Some(2))),
+        )?;
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "id,value\n1,1.23\n2,\n3,-0.67\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_write_full_float_precision_by_default() -> DaftResult<()> {
+        let table = float_table()?;
+        let mut buffer = vec![];
+        serialize_csv(&table, &mut buffer, None, None)?;
+        let written = String::from_utf8(buffer).unwrap();
+        assert!(written.starts_with("id,value\n1,1.23456\n2,\n3,-0.666666"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_write_delimiter_without_header() -> DaftResult<()> {
+        let table = float_table()?;
+        let mut buffer = vec![];
+        serialize_csv(
+            &table,
+            &mut buffer,
+            Some(
+                CsvParseOptions::default()
+                    .with_delimiter(b'|')
+                    .with_has_header(false),
+            ),
+            None,
+        )?;
+        assert!(String::from_utf8(buffer)
+            .unwrap()
+            .starts_with("1|1.23456\n2|\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_write_read_roundtrip() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("id", vec![1, 2, 3])).into_series(),
+            Float64Array::from(("value", vec![0.5, -1.25, 3.0])).into_series(),
+            Utf8Array::from(("label", ["a", "b,c", "d\"e"].as_slice())).into_series(),
+        ])?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let dir = std::env::temp_dir();
+
+        for file_name in [
+            "roundtrip.csv",
+            "roundtrip.csv.gz",
+            "roundtrip.csv.sz",
+            "roundtrip.csv.zst",
+        ] {
+            let path = dir.join(format!(
+                "daft_csv_write_{}_{}",
+                std::process::id(),
+                file_name
+            ));
+            let uri = path.to_str().unwrap();
+            write_csv(&table, uri, None, None, io_client.clone())?;
+            let read = read_csv(uri, None, None, None, io_client.clone(), None, true, None);
+            std::fs::remove_file(&path)?;
+            let read = read?;
+
+            assert_eq!(read.schema, table.schema);
+            for name in table.column_names() {
+                assert_eq!(
+                    read.get_column(&name)?.to_arrow(),
+                    table.get_column(&name)?.to_arrow()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_write_multiple_chunks_roundtrip() -> DaftResult<()> {
+        // The table spans several chunks, which are serialized and compressed one at a time, with a single header.
+        let num_rows = 2 * WRITE_CHUNK_ROWS + 1;
+        let table = Table::from_columns(vec![Int64Array::from((
+            "id",
+            (0..num_rows as i64).collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let path = std::env::temp_dir().join(format!(
+            "daft_csv_write_{}_multiple_chunks.csv.gz",
+            std::process::id()
+        ));
+        let uri = path.to_str().unwrap();
+        write_csv(&table, uri, None, None, io_client.clone())?;
+        let read = read_csv(uri, None, None, None, io_client, None, true, None);
+        std::fs::remove_file(&path)?;
+        let read = read?;
+
+        assert_eq!(read.len(), num_rows);
+        assert_eq!(read.schema, table.schema);
+        assert_eq!(
+            read.get_column("id")?.to_arrow(),
+            table.get_column("id")?.to_arrow()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_write_headerless_shard_roundtrip() -> DaftResult<()> {
+        let table = float_table()?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let path = std::env::temp_dir().join(format!(
+            "daft_csv_write_{}_headerless_shard.csv",
+            std::process::id()
+        ));
+        let uri = path.to_str().unwrap();
+
+        // A shard appended to another file has no header of its own, and uses that file's delimiter.
+        let parse_options = CsvParseOptions::default()
+            .with_has_header(false)
+            .with_delimiter(b';');
+        write_csv(
+            &table,
+            uri,
+            Some(parse_options.clone()),
+            None,
+            io_client.clone(),
+        )?;
+        let read = read_csv(
+            uri,
+            Some(
+                CsvConvertOptions::default()
+                    .with_column_names(Some(table.column_names()))
+                    .with_schema(Some(table.schema.clone())),
+            ),
+            Some(parse_options),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        );
+        std::fs::remove_file(&path)?;
+        let read = read?;
+
+        assert_eq!(read.len(), 3);
+        assert_eq!(read.schema, table.schema);
+        for name in table.column_names() {
+            assert_eq!(
+                read.get_column(&name)?.to_arrow(),
+                table.get_column(&name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+}
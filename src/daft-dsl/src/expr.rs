@@ -399,6 +399,29 @@ impl Expr {
         }
     }
 
+    /// Whether this expression is a Python UDF with struct output, whose struct is unpacked into a column per field
+    /// when projected.
+    pub fn has_struct_output(&self) -> bool {
+        match self {
+            #[cfg(feature = "python")]
+            Expr::Function {
+                func: FunctionExpr::Python(udf),
+                ..
+            } => udf.struct_output(),
+            _ => false,
+        }
+    }
+
+    /// Fields of the columns that this expression is projected to: the fields of its struct if it has struct output,
+    /// or its own field otherwise.
+    pub fn to_projected_fields(&self, schema: &Schema) -> DaftResult<Vec<Field>> {
+        let field = self.to_field(schema)?;
+        match field.dtype {
+            DataType::Struct(fields) if self.has_struct_output() => Ok(fields),
+            _ => Ok(vec![field]),
+        }
+    }
+
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         use Expr::*;
         match self {
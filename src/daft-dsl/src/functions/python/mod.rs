@@ -1,7 +1,7 @@
 mod partial_udf;
 mod udf;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::datatypes::DataType;
 use serde::{Deserialize, Serialize};
 
@@ -12,14 +12,43 @@ pub struct PythonUDF {
     func: partial_udf::PartialUDF,
     num_expressions: usize,
     return_dtype: DataType,
+    // Maximum number of rows passed to the function per call, or all rows in a single call if `None`.
+    batch_size: Option<usize>,
+    // Whether the function returns multiple columns as a struct of the declared struct return dtype, which is unpacked
+    // into a column per field when projected.
+    struct_output: bool,
 }
 
-pub fn udf(func: pyo3::PyObject, expressions: &[Expr], return_dtype: DataType) -> DaftResult<Expr> {
+impl PythonUDF {
+    pub fn struct_output(&self) -> bool {
+        self.struct_output
+    }
+}
+
+pub fn udf(
+    func: pyo3::PyObject,
+    expressions: &[Expr],
+    return_dtype: DataType,
+    batch_size: Option<usize>,
+    struct_output: bool,
+) -> DaftResult<Expr> {
+    if batch_size == Some(0) {
+        return Err(DaftError::ValueError(
+            "UDF batch size must be positive".into(),
+        ));
+    }
+    if struct_output && !matches!(return_dtype, DataType::Struct(_)) {
+        return Err(DaftError::ValueError(format!(
+            "UDF with struct output must declare a struct return dtype, but got {return_dtype}"
+        )));
+    }
     Ok(Expr::Function {
         func: super::FunctionExpr::Python(PythonUDF {
             func: partial_udf::PartialUDF(func),
             num_expressions: expressions.len(),
             return_dtype,
+            batch_size,
+            struct_output,
         }),
         inputs: expressions.into(),
     })
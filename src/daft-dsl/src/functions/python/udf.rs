@@ -1,6 +1,14 @@
-use pyo3::{types::PyModule, PyAny, PyResult};
+use pyo3::{
+    types::{PyDict, PyModule},
+    PyAny, PyErr, PyResult, Python,
+};
 
-use daft_core::{datatypes::Field, schema::Schema, series::Series};
+use daft_core::{
+    array::StructArray,
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
 
 use crate::Expr;
 
@@ -32,8 +40,6 @@ impl FunctionEvaluator for PythonUDF {
     }
 
     fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
-        use pyo3::Python;
-
         if inputs.len() != self.num_expressions {
             return Err(DaftError::SchemaMismatch(format!(
                 "Number of inputs required by UDF {} does not match number of inputs provided: {}",
@@ -42,6 +48,120 @@ impl FunctionEvaluator for PythonUDF {
             )));
         }
 
+        let num_rows = inputs.first().map_or(0, Series::len);
+        match self.batch_size {
+            Some(batch_size) if num_rows > batch_size => {
+                // Call the function on consecutive batches of at most `batch_size` rows, and concatenate the results.
+                let results = (0..num_rows)
+                    .step_by(batch_size)
+                    .map(|start| {
+                        let end = (start + batch_size).min(num_rows);
+                        let batch = inputs
+                            .iter()
+                            .map(|s| s.slice(start, end))
+                            .collect::<DaftResult<Vec<_>>>()?;
+                        self.call(&batch)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Series::concat(&results.iter().collect::<Vec<_>>())
+            }
+            _ => self.call(inputs),
+        }
+    }
+}
+
+/// Checks that the field names of a struct returned by a UDF are exactly the names of the declared fields.
+fn validate_struct_field_names<'a>(
+    names: impl Iterator<Item = &'a str>,
+    declared_fields: &[Field],
+) -> DaftResult<()> {
+    let mut names = names.collect::<Vec<_>>();
+    let mut declared_names = declared_fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    declared_names.sort_unstable();
+    if names != declared_names {
+        return Err(DaftError::SchemaMismatch(format!(
+            "UDF returned a struct with fields {names:?}, but declared fields {declared_names:?}"
+        )));
+    }
+    Ok(())
+}
+
+impl PythonUDF {
+    /// Casts a Series returned by the Python function to the declared return dtype, which the planner has already
+    /// assumed for the UDF's output.
+    fn cast_to_return_dtype(&self, series: Series) -> DaftResult<Series> {
+        if series.data_type() == &self.return_dtype {
+            return Ok(series);
+        }
+        if let (true, DataType::Struct(fields), DataType::Struct(declared_fields)) =
+            (self.struct_output, series.data_type(), &self.return_dtype)
+        {
+            validate_struct_field_names(fields.iter().map(|f| f.name.as_str()), declared_fields)?;
+        }
+        series.cast(&self.return_dtype).map_err(|e| {
+            DaftError::TypeError(format!(
+                "UDF returned a Series of type {}, which cannot be cast to its declared return type {}: {e}",
+                series.data_type(),
+                self.return_dtype
+            ))
+        })
+    }
+
+    /// Builds a struct Series of the declared struct return dtype from a dict of field names to Series returned by
+    /// the Python function.
+    fn struct_from_dict(&self, dict: &PyDict, name: &str) -> DaftResult<Series> {
+        let DataType::Struct(declared_fields) = &self.return_dtype else {
+            unreachable!("UDF with struct output must have a struct return dtype")
+        };
+        let names = dict
+            .keys()
+            .iter()
+            .map(|key| key.extract::<String>())
+            .collect::<PyResult<Vec<_>>>()?;
+        validate_struct_field_names(names.iter().map(String::as_str), declared_fields)?;
+        let children = declared_fields
+            .iter()
+            .map(|field| {
+                let series = dict
+                    .get_item(field.name.as_str())
+                    .expect("Field names were validated")
+                    .extract::<PySeries>()?
+                    .series
+                    .rename(&field.name);
+                if series.data_type() == &field.dtype {
+                    return Ok(series);
+                }
+                series.cast(&field.dtype).map_err(|e| {
+                    DaftError::TypeError(format!(
+                        "UDF returned field {} of type {}, which cannot be cast to its declared type {}: {e}",
+                        field.name,
+                        series.data_type(),
+                        field.dtype
+                    ))
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(first) = children.first() {
+            if let Some(child) = children.iter().find(|child| child.len() != first.len()) {
+                return Err(DaftError::ValueError(format!(
+                    "UDF returned fields of different lengths: {} has {} rows, but {} has {} rows",
+                    first.name(),
+                    first.len(),
+                    child.name(),
+                    child.len()
+                )));
+            }
+        }
+        let field = Field::new(name, self.return_dtype.clone());
+        Ok(StructArray::new(field, children, None).into_series())
+    }
+
+    /// Calls the Python function once on all rows of `inputs`.
+    fn call(&self, inputs: &[Series]) -> DaftResult<Series> {
         Python::with_gil(|py| {
             // Convert input Rust &[Series] to wrapped Python Vec<&PyAny>
             let py_series_module = PyModule::import(py, pyo3::intern!(py, "daft.series"))?;
@@ -63,10 +183,14 @@ impl FunctionEvaluator for PythonUDF {
             let result = func.call1((pyseries,));
 
             match result {
+                Ok(pyany) if self.struct_output && pyany.is_instance_of::<PyDict>() => {
+                    let name = inputs.first().map_or("", Series::name);
+                    self.struct_from_dict(pyany.downcast::<PyDict>().map_err(PyErr::from)?, name)
+                }
                 Ok(pyany) => {
                     let pyseries = pyany.extract::<PySeries>();
                     match pyseries {
-                        Ok(pyseries) => Ok(pyseries.series),
+                        Ok(pyseries) => self.cast_to_return_dtype(pyseries.series),
                         Err(e) => Err(DaftError::ValueError(format!("Internal error occurred when coercing the results of running UDF to Series:\n\n{e}"))),
                     }
                 }
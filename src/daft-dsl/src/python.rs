@@ -69,12 +69,17 @@ pub fn lit(item: &PyAny) -> PyResult<PyExpr> {
 // * `func` - a Python function that takes as input an ordered list of Python Series to execute the user's UDF.
 // * `expressions` - an ordered list of Expressions, each representing computation that will be performed, producing a Series to pass into `func`
 // * `return_dtype` - returned column's DataType
+// * `batch_size` - maximum number of rows to pass to `func` per call, or all rows in a single call if not provided
+// * `struct_output` - whether `func` returns multiple columns as a struct of the `return_dtype` struct type, which is unpacked into a column per field
 #[pyfunction]
+#[pyo3(signature = (func, expressions, return_dtype, batch_size=None, struct_output=false))]
 pub fn udf(
     py: Python,
     func: &PyAny,
     expressions: Vec<PyExpr>,
     return_dtype: PyDataType,
+    batch_size: Option<usize>,
+    struct_output: bool,
 ) -> PyResult<PyExpr> {
     use crate::functions::python::udf;
 
@@ -83,7 +88,13 @@ pub fn udf(
     let func = func.to_object(py);
     let expressions_map: Vec<Expr> = expressions.into_iter().map(|pyexpr| pyexpr.expr).collect();
     Ok(PyExpr {
-        expr: udf(func, &expressions_map, return_dtype.dtype)?,
+        expr: udf(
+            func,
+            &expressions_map,
+            return_dtype.dtype,
+            batch_size,
+            struct_output,
+        )?,
     })
 }
 
@@ -236,6 +247,15 @@ impl PyExpr {
         Ok(self.expr.to_field(&schema.schema)?.into())
     }
 
+    pub fn to_projected_fields(&self, schema: &PySchema) -> PyResult<Vec<PyField>> {
+        Ok(self
+            .expr
+            .to_projected_fields(&schema.schema)?
+            .into_iter()
+            .map(|field| field.into())
+            .collect())
+    }
+
     pub fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{}", self.expr))
     }
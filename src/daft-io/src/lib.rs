@@ -21,6 +21,7 @@ pub use object_io::GetResult;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use stats::{IOStatsContext, IOStatsRef};
+pub use stream_utils::{join_all_bounded, DEFAULT_MAX_CONCURRENT_IO};
 use tokio::runtime::RuntimeFlavor;
 
 use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
@@ -87,6 +88,13 @@ pub enum Error {
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display(
+        "Writing is not yet implemented for store: {}, so can't write {}",
+        store,
+        path
+    ))]
+    NotImplementedWrite { store: SourceType, path: String },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
@@ -186,6 +194,30 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    /// Writes `data` as the object at `input`, replacing any existing object there. Readers observe either the
+    /// previous object or all of `data`, never a partially written object.
+    pub async fn single_url_put(
+        &self,
+        input: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let data = futures::stream::once(async { Ok(data) }).boxed();
+        self.single_url_put_stream(input, data, io_stats).await
+    }
+
+    /// Like `single_url_put`, but writes `data` as it's streamed in, rather than buffering all of it in memory first.
+    pub async fn single_url_put_stream(
+        &self,
+        input: &str,
+        data: futures::stream::BoxStream<'static, std::io::Result<bytes::Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(input)?;
+        let source = self.get_source(&scheme).await?;
+        source.put(path.as_ref(), data, io_stats).await
+    }
+
     async fn single_url_download(
         &self,
         index: usize,
@@ -15,7 +15,7 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use snafu::{ResultExt, Snafu};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use url::ParseError;
 
 /// NOTE: We hardcode this even for Windows
@@ -39,6 +39,12 @@ enum Error {
         path: String,
         source: std::io::Error,
     },
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteBytes {
+        path: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to seek in file {}: {}", path, source))]
     UnableToSeek {
         path: String,
@@ -142,6 +148,60 @@ impl ObjectSource for LocalSource {
         Ok(meta.len() as usize)
     }
 
+    /// Writes `data` to a uniquely named temporary file in the directory of `uri` first, and then renames it into
+    /// place, since a rename within a directory is atomic.
+    async fn put(
+        &self,
+        uri: &str,
+        mut data: BoxStream<'static, std::io::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        let path = std::path::Path::new(uri);
+        let Some(file_name) = path.file_name() else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(std::path::Path::new("."));
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        // The temporary file is removed if it's dropped before it's renamed into place, e.g. on an error.
+        let temp_file = tempfile::Builder::new()
+            .prefix(&format!(".{}.", file_name.to_string_lossy()))
+            .suffix(".tmp")
+            .tempfile_in(dir)
+            .context(UnableToWriteBytesSnafu { path: uri })?;
+        let mut file = tokio::fs::File::from_std(
+            temp_file
+                .reopen()
+                .context(UnableToWriteBytesSnafu { path: uri })?,
+        );
+        while let Some(chunk) = data
+            .try_next()
+            .await
+            .context(UnableToWriteBytesSnafu { path: uri })?
+        {
+            file.write_all(&chunk)
+                .await
+                .context(UnableToWriteBytesSnafu { path: uri })?;
+        }
+        file.flush()
+            .await
+            .context(UnableToWriteBytesSnafu { path: uri })?;
+        drop(file);
+        temp_file
+            .persist(path)
+            .map_err(|err| err.error)
+            .context(UnableToWriteBytesSnafu { path: uri })?;
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -315,6 +375,9 @@ pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 mod tests {
     use std::io::Write;
 
+    use futures::stream::BoxStream;
+    use futures::StreamExt;
+
     use crate::object_io::{FileMetadata, FileType, ObjectSource};
     use crate::Result;
     use crate::{HttpSource, LocalSource};
@@ -437,4 +500,59 @@ mod tests {
 
         Ok(())
     }
+
+    fn put_data(chunks: Vec<&'static str>) -> BoxStream<'static, std::io::Result<bytes::Bytes>> {
+        futures::stream::iter(
+            chunks
+                .into_iter()
+                .map(|chunk| Ok(bytes::Bytes::from_static(chunk.as_bytes()))),
+        )
+        .boxed()
+    }
+
+    #[tokio::test]
+    async fn test_local_put() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().to_string_lossy().replace('\\', "/");
+        let file_path = format!("file://{dir_path}/put.bin");
+        let client = LocalSource::get_client().await?;
+
+        client
+            .put(&file_path, put_data(vec!["first"]), None)
+            .await?;
+        client
+            .put(&file_path, put_data(vec!["sec", "ond"]), None)
+            .await?;
+        let read = client.get(&file_path, None, None).await?.bytes().await?;
+        assert_eq!(read.as_ref(), b"second");
+
+        // Concurrent writes to the same file each write to a temporary file of their own, so every write succeeds and
+        // the file ends up with the data of one of them.
+        let writes = (0..8).map(|_| {
+            let client = client.clone();
+            let file_path = file_path.clone();
+            let data = put_data(vec!["third"]);
+            tokio::spawn(async move { client.put(&file_path, data, None).await })
+        });
+        for write in futures::future::join_all(writes).await {
+            write.unwrap()?;
+        }
+        let read = client.get(&file_path, None, None).await?.bytes().await?;
+        assert_eq!(read.as_ref(), b"third");
+
+        // The temporary file is renamed into place, so only the written file is left in the directory.
+        let ls_result = client
+            .ls(&format!("file://{dir_path}"), true, None, None, None)
+            .await?;
+        assert_eq!(
+            ls_result
+                .files
+                .into_iter()
+                .map(|f| f.filepath)
+                .collect::<Vec<_>>(),
+            vec![file_path]
+        );
+
+        Ok(())
+    }
 }
@@ -111,6 +111,19 @@ pub(crate) trait ObjectSource: Sync + Send {
     }
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    async fn put(
+        &self,
+        uri: &str,
+        _data: BoxStream<'static, std::io::Result<Bytes>>,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let (store, _) = super::parse_url(uri)?;
+        Err(super::Error::NotImplementedWrite {
+            store,
+            path: uri.to_string(),
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -5,8 +5,13 @@ use aws_config::timeout::TimeoutConfig;
 use aws_smithy_async::rt::sleep::TokioSleep;
 use futures::stream::BoxStream;
 use reqwest::StatusCode;
+use s3::operation::complete_multipart_upload::CompleteMultipartUploadError;
+use s3::operation::create_multipart_upload::CreateMultipartUploadError;
 use s3::operation::head_object::HeadObjectError;
 use s3::operation::list_objects_v2::ListObjectsV2Error;
+use s3::operation::put_object::PutObjectError;
+use s3::operation::upload_part::UploadPartError;
+use s3::types::{CompletedMultipartUpload, CompletedPart};
 use tokio::sync::{OwnedSemaphorePermit, SemaphorePermit};
 
 use crate::object_io::{FileMetadata, FileType, LSResult};
@@ -29,7 +34,8 @@ use url::{ParseError, Position};
 use super::object_io::{GetResult, ObjectSource};
 use async_recursion::async_recursion;
 use aws_sdk_s3 as s3;
-use aws_sdk_s3::primitives::ByteStreamError;
+use aws_sdk_s3::primitives::{ByteStream, ByteStreamError};
+use bytes::{Bytes, BytesMut};
 
 use std::collections::HashMap;
 
@@ -40,6 +46,10 @@ use std::time::Duration;
 
 const S3_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+// Size of the parts of multipart uploads, which S3 requires to be at least 5 MiB, except for the last part.
+const MULTIPART_PART_SIZE: usize = 16 * 1024 * 1024;
+// Maximum number of parts of a multipart upload allowed by S3.
+const MAX_MULTIPART_PARTS: usize = 10_000;
 pub(crate) struct S3LikeSource {
     region_to_client_map: tokio::sync::RwLock<HashMap<Region, Arc<s3::Client>>>,
     connection_pool_sema: Arc<tokio::sync::Semaphore>,
@@ -68,6 +78,61 @@ enum Error {
         source: SdkError<ListObjectsV2Error, Response>,
     },
 
+    #[snafu(display("Unable to put {}: {}", path, s3::error::DisplayErrorContext(source)))]
+    UnableToPutFile {
+        path: String,
+        source: SdkError<PutObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to start a multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCreateMultipartUpload {
+        path: String,
+        source: SdkError<CreateMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to upload part {} to {}: {}",
+        part_number,
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToUploadPart {
+        path: String,
+        part_number: i32,
+        source: SdkError<UploadPartError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to complete the multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCompleteMultipartUpload {
+        path: String,
+        source: SdkError<CompleteMultipartUploadError, Response>,
+    },
+
+    #[snafu(display("Missing upload ID of the multipart upload to {}", path))]
+    MissingUploadId { path: String },
+
+    #[snafu(display(
+        "Unable to put {}: it has more than {} parts of {} bytes",
+        path,
+        MAX_MULTIPART_PARTS,
+        MULTIPART_PART_SIZE
+    ))]
+    TooManyParts { path: String },
+
+    #[snafu(display("Unable to read the data to put at {}: {}", path, source))]
+    UnableToReadPutData {
+        path: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to query the region for {}: {}", path, source))]
     UnableToQueryRegion {
         path: String,
@@ -207,6 +272,24 @@ fn parse_url(uri: &str) -> super::Result<(String, String, String)> {
     ))
 }
 
+/// Reads the next part of a multipart upload from `data`, of at least `MULTIPART_PART_SIZE` bytes, unless `data` ends
+/// first. The part is empty once `data` has ended.
+async fn next_part(
+    data: &mut BoxStream<'static, std::io::Result<Bytes>>,
+    uri: &str,
+) -> super::Result<Bytes> {
+    let mut part = BytesMut::new();
+    while part.len() < MULTIPART_PART_SIZE
+        && let Some(chunk) = data
+            .try_next()
+            .await
+            .with_context(|_| UnableToReadPutDataSnafu { path: uri })?
+    {
+        part.extend_from_slice(&chunk);
+    }
+    Ok(part.freeze())
+}
+
 fn handle_https_client_settings(
     builder: aws_sdk_s3::config::Builder,
     config: &S3Config,
@@ -582,6 +665,127 @@ impl S3LikeSource {
         }
     }
 
+    /// Puts `data` at `uri` with a multipart upload of parts of `MULTIPART_PART_SIZE` bytes, which S3 only exposes
+    /// once it's completed, so that a failed or interrupted upload never leaves a partial object at `uri`. Data that
+    /// fits in a single part is put with a single (equally atomic) PutObject request instead.
+    async fn _put_impl(
+        &self,
+        _permit: SemaphorePermit<'_>,
+        uri: &str,
+        mut data: BoxStream<'static, std::io::Result<Bytes>>,
+        region: &Region,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        log::debug!("S3 put at {uri} in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() || key.ends_with(S3_DELIMITER) {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        log::debug!("S3 put parsed uri: {uri} into Bucket: {bucket}, Key: {key}");
+
+        let client = self.get_s3_client(region).await?;
+        let mut part = next_part(&mut data, uri).await?;
+        if part.len() < MULTIPART_PART_SIZE {
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1)
+            }
+            client
+                .put_object()
+                .bucket(&bucket)
+                .key(&key)
+                .body(ByteStream::from(part))
+                .send()
+                .await
+                .with_context(|_| UnableToPutFileSnafu { path: uri })?;
+            return Ok(());
+        }
+
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        let upload = client
+            .create_multipart_upload()
+            .bucket(&bucket)
+            .key(&key)
+            .send()
+            .await
+            .with_context(|_| UnableToCreateMultipartUploadSnafu { path: uri })?;
+        let Some(upload_id) = upload.upload_id() else {
+            return Err(Error::MissingUploadId { path: uri.into() }.into());
+        };
+        let completed = async {
+            let mut completed_parts = vec![];
+            while !part.is_empty() {
+                if completed_parts.len() == MAX_MULTIPART_PARTS {
+                    return Err(Error::TooManyParts { path: uri.into() }.into());
+                }
+                let part_number = completed_parts.len() as i32 + 1;
+                if let Some(is) = io_stats.as_ref() {
+                    is.mark_put_requests(1)
+                }
+                let uploaded = client
+                    .upload_part()
+                    .bucket(&bucket)
+                    .key(&key)
+                    .upload_id(upload_id)
+                    .part_number(part_number)
+                    .body(ByteStream::from(part))
+                    .send()
+                    .await
+                    .with_context(|_| UnableToUploadPartSnafu {
+                        path: uri,
+                        part_number,
+                    })?;
+                completed_parts.push(
+                    CompletedPart::builder()
+                        .set_e_tag(uploaded.e_tag().map(String::from))
+                        .part_number(part_number)
+                        .build(),
+                );
+                part = next_part(&mut data, uri).await?;
+            }
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1)
+            }
+            client
+                .complete_multipart_upload()
+                .bucket(&bucket)
+                .key(&key)
+                .upload_id(upload_id)
+                .multipart_upload(
+                    CompletedMultipartUpload::builder()
+                        .set_parts(Some(completed_parts))
+                        .build(),
+                )
+                .send()
+                .await
+                .with_context(|_| UnableToCompleteMultipartUploadSnafu { path: uri })?;
+            super::Result::Ok(())
+        }
+        .await;
+        if completed.is_err() {
+            // Abort the upload, so that S3 drops the parts uploaded so far, rather than keep (and bill for) them.
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1)
+            }
+            let aborted = client
+                .abort_multipart_upload()
+                .bucket(&bucket)
+                .key(&key)
+                .upload_id(upload_id)
+                .send()
+                .await;
+            if let Err(err) = aborted {
+                log::warn!(
+                    "Unable to abort the multipart upload to {uri}: {}",
+                    DisplayErrorContext(err)
+                );
+            }
+        }
+        completed
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[async_recursion]
     async fn _list_impl(
@@ -776,6 +980,21 @@ impl ObjectSource for S3LikeSource {
         Ok(head_result)
     }
 
+    async fn put(
+        &self,
+        uri: &str,
+        data: BoxStream<'static, std::io::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._put_impl(permit, uri, data, &self.default_region, io_stats)
+            .await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -11,6 +11,7 @@ pub struct IOStatsContext {
     num_get_requests: atomic::AtomicUsize,
     num_head_requests: atomic::AtomicUsize,
     num_list_requests: atomic::AtomicUsize,
+    num_put_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
 }
 
@@ -20,11 +21,12 @@ impl Drop for IOStatsContext {
         let num_gets = self.load_get_requests();
         let mean_size = (bytes_read as f64) / (num_gets as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, Puts: {}, BytesRead: {}, AvgGetSize: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
             self.load_list_requests(),
+            self.load_put_requests(),
             bytes_read,
             mean_size as i64
         );
@@ -44,6 +46,7 @@ impl IOStatsContext {
             num_get_requests: atomic::AtomicUsize::new(0),
             num_head_requests: atomic::AtomicUsize::new(0),
             num_list_requests: atomic::AtomicUsize::new(0),
+            num_put_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
         })
     }
@@ -66,6 +69,12 @@ impl IOStatsContext {
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
+    #[inline]
+    pub(crate) fn mark_put_requests(&self, num_requests: usize) {
+        self.num_put_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn load_get_requests(&self) -> usize {
         self.num_get_requests.load(atomic::Ordering::Acquire)
@@ -82,7 +91,12 @@ impl IOStatsContext {
     }
 
     #[inline]
-    pub(crate) fn mark_bytes_read(&self, bytes_read: usize) {
+    pub fn load_put_requests(&self) -> usize {
+        self.num_put_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn mark_bytes_read(&self, bytes_read: usize) {
         self.bytes_read
             .fetch_add(bytes_read, atomic::Ordering::Relaxed);
     }
@@ -2,7 +2,7 @@ use bytes::Bytes;
 
 use crate::stats::{IOStatsByteStreamContextHandle, IOStatsRef};
 
-use futures::{stream::BoxStream, StreamExt};
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 
 pub(crate) fn io_stats_on_bytestream(
     mut s: impl futures::stream::Stream<Item = super::Result<Bytes>>
@@ -26,3 +26,59 @@ pub(crate) fn io_stats_on_bytestream(
         s.boxed()
     }
 }
+
+/// Default cap on the number of concurrent requests of bulk reads over many files, e.g. of their metadata.
+pub const DEFAULT_MAX_CONCURRENT_IO: usize = 64;
+
+/// Spawns a task per future of `futures` and returns their outputs in order, with at most `max_concurrent_io` of them
+/// running at once, so that reads of many files don't flood the IO client with requests.
+///
+/// Tasks are only spawned once there's room for them, so the futures don't start running until then.
+pub async fn join_all_bounded<F>(
+    futures: impl IntoIterator<Item = F>,
+    max_concurrent_io: usize,
+) -> Result<Vec<F::Output>, tokio::task::JoinError>
+where
+    F: std::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    futures::stream::iter(futures.into_iter().map(tokio::spawn))
+        .buffered(max_concurrent_io.max(1))
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use super::join_all_bounded;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_join_all_bounded_caps_concurrency() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let futures = (0..50).map(|i| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            }
+        });
+
+        let outputs = join_all_bounded(futures, 4).await.unwrap();
+
+        assert_eq!(outputs, (0..50).collect::<Vec<_>>());
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 4);
+    }
+}
@@ -3,30 +3,78 @@ use std::fmt::Display;
 use std::sync::Arc;
 use std::{ops::Deref, sync::Mutex};
 
-use arrow2::io::parquet::read::schema::infer_schema_with_options;
-use common_error::DaftResult;
-use daft_core::schema::{Schema, SchemaRef};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::Field,
+    schema::{Schema, SchemaRef},
+    DataType,
+};
+
+use daft_dsl::{optimization::get_required_columns, Expr};
 
-use daft_csv::read::read_csv;
-use daft_parquet::read::{
-    read_parquet_bulk, read_parquet_metadata_bulk, ParquetSchemaInferenceOptions,
+use daft_csv::{
+    metadata::{read_csv_schema, read_csv_schema_bulk},
+    read::{read_csv, read_csv_bulk, read_csv_from_reader},
+    CsvConvertOptions, CsvParseOptions, CsvReadOptions,
+};
+use daft_parquet::{
+    read::{
+        read_parquet_bulk, read_parquet_from_bytes, read_parquet_metadata_bulk,
+        ParquetSchemaInferenceOptions,
+    },
+    write::ParquetCompression,
 };
+use daft_scan::{FileType, ScanTask};
 use daft_table::Table;
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use snafu::ResultExt;
 
 use crate::DaftCoreComputeSnafu;
 
-use daft_io::{IOConfig, IOStatsRef};
+use daft_io::{join_all_bounded, IOClient, IOConfig, IOStatsRef, DEFAULT_MAX_CONCURRENT_IO};
 use daft_stats::TableMetadata;
-use daft_stats::TableStatistics;
+use daft_stats::{TableStatistics, TruthValue};
+
+/// Version tag prefixed to every serialized MicroPartition payload (schema, loading params, metadata and statistics),
+/// so that workers running a different version of Daft fail loudly on an incompatible layout instead of misreading it.
+/// Bump this whenever the layout of any of those payloads changes.
+pub(crate) const SERIALIZATION_FORMAT_VERSION: u8 = 11;
+
+pub(crate) fn serialize_versioned<T: Serialize>(value: &T) -> DaftResult<Vec<u8>> {
+    let mut bytes = vec![SERIALIZATION_FORMAT_VERSION];
+    bincode::serialize_into(&mut bytes, value).map_err(|e| DaftError::External(e.into()))?;
+    Ok(bytes)
+}
+
+pub(crate) fn deserialize_versioned<T: DeserializeOwned>(bytes: &[u8]) -> DaftResult<T> {
+    match bytes.split_first() {
+        Some((&SERIALIZATION_FORMAT_VERSION, payload)) => {
+            bincode::deserialize(payload).map_err(|e| DaftError::External(e.into()))
+        }
+        Some((version, _)) => Err(DaftError::ValueError(format!(
+            "Unsupported MicroPartition serialization format version {}, expected version {}; this usually means the payload was serialized by a different version of Daft",
+            version, SERIALIZATION_FORMAT_VERSION
+        ))),
+        None => Err(DaftError::ValueError(
+            "Cannot deserialize MicroPartition from an empty payload".to_string(),
+        )),
+    }
+}
 
 #[derive(Clone, Serialize, Deserialize)]
 enum FormatParams {
     Parquet {
         row_groups: Option<Vec<Vec<i64>>>,
         inference_options: ParquetSchemaInferenceOptions,
+        // Statistics of every row group of every file, if the files have statistics, to prune row groups by filters.
+        row_group_statistics: Option<Vec<Vec<TableStatistics>>>,
+    },
+    Csv {
+        // Options of the read, whose schema is the full schema of the files, before any projection.
+        convert_options: CsvConvertOptions,
+        parse_options: CsvParseOptions,
+        read_options: Option<CsvReadOptions>,
     },
 }
 
@@ -36,9 +84,115 @@ pub(crate) struct DeferredLoadingParams {
     urls: Vec<String>,
     io_config: Arc<IOConfig>,
     multithreaded_io: bool,
+    // Number of rows to skip at the start of each file, before the limit.
+    start_offset: Option<usize>,
     limit: Option<usize>,
     columns: Option<Vec<String>>,
+    // Predicates that the rows are filtered by once they're read, after the limit.
+    filters: Vec<Expr>,
+}
+
+impl DeferredLoadingParams {
+    /// Returns these params with their limit reduced to at most `limit` rows.
+    pub(crate) fn with_limit(&self, limit: usize) -> Self {
+        Self {
+            limit: Some(self.limit.map_or(limit, |l| l.min(limit))),
+            ..self.clone()
+        }
+    }
+
+    /// Returns these params with `predicate` added to their filters.
+    pub(crate) fn with_filters(&self, predicate: &[Expr]) -> Self {
+        let mut filters = self.filters.clone();
+        filters.extend_from_slice(predicate);
+        Self {
+            filters,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn has_filters(&self) -> bool {
+        !self.filters.is_empty()
+    }
+
+    /// Returns these params with the columns of a CSV read parsed directly as the types of the same-named fields of
+    /// `schema`, for the types that the CSV reader can parse, so that casting the read to `schema` is a no-op for
+    /// them. Other formats are cast to `schema` after they're read.
+    pub(crate) fn with_cast_to_schema(&self, schema: &Schema) -> DaftResult<Self> {
+        let FormatParams::Csv {
+            convert_options,
+            parse_options,
+            read_options,
+        } = &self.format_params
+        else {
+            return Ok(self.clone());
+        };
+        let Some(file_schema) = &convert_options.schema else {
+            return Ok(self.clone());
+        };
+        let fields = file_schema
+            .fields
+            .values()
+            .enumerate()
+            .map(|(i, field)| {
+                // The fields of the schema are renamed by the column names of the read, if any.
+                let name = convert_options
+                    .column_names
+                    .as_ref()
+                    .and_then(|names| names.get(i))
+                    .unwrap_or(&field.name);
+                match schema.fields.get(name) {
+                    Some(target) if is_csv_parseable(&target.dtype) => Field {
+                        dtype: target.dtype.clone(),
+                        ..field.clone()
+                    },
+                    _ => field.clone(),
+                }
+            })
+            .collect();
+        Ok(Self {
+            format_params: FormatParams::Csv {
+                convert_options: convert_options
+                    .clone()
+                    .with_schema(Some(Schema::new(fields)?.into())),
+                parse_options: parse_options.clone(),
+                read_options: read_options.clone(),
+            },
+            ..self.clone()
+        })
+    }
+
+    /// Returns these params with their projection replaced by `columns`, which must be a subset of the columns of the
+    /// MicroPartition that is read.
+    pub(crate) fn with_columns(&self, columns: &[&str]) -> Self {
+        Self {
+            columns: Some(columns.iter().map(|c| c.to_string()).collect()),
+            ..self.clone()
+        }
+    }
+}
+
+/// Whether the CSV reader parses values as `dtype` directly, rather than them being cast to it after they're read.
+fn is_csv_parseable(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Utf8
+            | DataType::Date
+            | DataType::Timestamp(_, None)
+    )
 }
+
 pub(crate) enum TableState {
     Unloaded(DeferredLoadingParams),
     Loaded(Arc<Vec<Table>>),
@@ -110,14 +264,196 @@ impl MicroPartition {
         )
     }
 
+    /// Reads the file of a scan task, dispatching to the reader of its file type, with the projection and limit that
+    /// were pushed down into the scan.
+    ///
+    /// Partition columns of the scan task aren't stored in the file, so they're excluded from the projection of the
+    /// reader, and added afterwards as columns holding the partition value of the file.
+    pub fn from_scan_task(
+        scan_task: &ScanTask,
+        io_config: Arc<IOConfig>,
+        multithreaded_io: bool,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let partition_fields = scan_task
+            .partition_fields()
+            .iter()
+            .filter(|partition_field| {
+                scan_task.columns().map_or(true, |columns| {
+                    columns.contains(&partition_field.field.name)
+                })
+            })
+            .collect::<Vec<_>>();
+        if partition_fields.is_empty() {
+            return Self::read_scan_task_file(
+                scan_task,
+                scan_task.columns().map(<[String]>::to_vec),
+                io_config,
+                multithreaded_io,
+                io_stats,
+            );
+        }
+
+        let file_columns = scan_task.columns().map(|columns| {
+            columns
+                .iter()
+                .filter(|column| {
+                    !scan_task
+                        .partition_fields()
+                        .iter()
+                        .any(|partition_field| &partition_field.field.name == *column)
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        // When only partition columns are selected, the file is still read in full to know its number of rows.
+        let only_partition_columns = matches!(&file_columns, Some(columns) if columns.is_empty());
+        let file_columns = if only_partition_columns {
+            None
+        } else {
+            file_columns
+        };
+        let mp = Self::read_scan_task_file(
+            scan_task,
+            file_columns,
+            io_config,
+            multithreaded_io,
+            io_stats,
+        )?;
+
+        let tables = mp
+            .concat_or_get()?
+            .iter()
+            .map(|table| {
+                let mut columns = if only_partition_columns {
+                    vec![]
+                } else {
+                    table
+                        .column_names()
+                        .iter()
+                        .map(|name| table.get_column(name).cloned())
+                        .collect::<DaftResult<Vec<_>>>()?
+                };
+                for partition_field in &partition_fields {
+                    let field = &partition_field.field;
+                    columns.push(
+                        partition_field
+                            .value
+                            .to_series()
+                            .broadcast(table.len())?
+                            .rename(&field.name)
+                            .cast(&field.dtype)?,
+                    );
+                }
+                Table::from_columns(columns)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let schema = match tables.first() {
+            Some(table) => table.schema.clone(),
+            None => {
+                let mut fields = if only_partition_columns {
+                    vec![]
+                } else {
+                    mp.schema.fields.values().cloned().collect::<Vec<_>>()
+                };
+                fields.extend(partition_fields.iter().map(|p| p.field.clone()));
+                Schema::new(fields)?.into()
+            }
+        };
+        Ok(Self::new(
+            schema,
+            TableState::Loaded(Arc::new(tables)),
+            mp.metadata.clone(),
+            None,
+        ))
+    }
+
+    fn read_scan_task_file(
+        scan_task: &ScanTask,
+        columns: Option<Vec<String>>,
+        io_config: Arc<IOConfig>,
+        multithreaded_io: bool,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let uri = scan_task.source().path();
+        match scan_task.source().file_type() {
+            FileType::Csv => match scan_task.source().metadata() {
+                // The number of rows of the file is known, so its read can be deferred until it's needed.
+                Some(metadata) => read_deferred_csv_into_micropartition(
+                    uri,
+                    columns,
+                    scan_task.limit(),
+                    metadata.length,
+                    scan_task.source().statistics(),
+                    io_config,
+                    multithreaded_io,
+                    io_stats,
+                ),
+                None => read_csv_into_micropartition(
+                    &[uri],
+                    Some(
+                        CsvConvertOptions::default()
+                            .with_include_columns(columns)
+                            .with_limit(scan_task.limit()),
+                    ),
+                    None,
+                    None,
+                    io_config,
+                    multithreaded_io,
+                    io_stats,
+                ),
+            },
+            FileType::Parquet => {
+                let columns = columns
+                    .as_ref()
+                    .map(|columns| columns.iter().map(String::as_str).collect::<Vec<_>>());
+                read_parquet_into_micropartition(
+                    &[uri],
+                    columns.as_deref(),
+                    None,
+                    scan_task.limit(),
+                    None,
+                    io_config,
+                    io_stats,
+                    1,
+                    multithreaded_io,
+                    &ParquetSchemaInferenceOptions::default(),
+                )
+            }
+            file_type @ (FileType::Json | FileType::Avro | FileType::Orc) => {
+                Err(DaftError::ValueError(format!(
+                    "Reading {file_type:?} files is not yet implemented, but got {uri}"
+                )))
+            }
+        }
+    }
+
     pub fn column_names(&self) -> Vec<String> {
         self.schema.names()
     }
 
+    /// Returns the number of rows, without reading an unloaded MicroPartition.
+    ///
+    /// Once filters have been pushed into the deferred read of an unloaded MicroPartition, this is only an upper bound
+    /// of its number of rows, see [`Self::is_exact_length`].
     pub fn len(&self) -> usize {
-        self.metadata.length
+        match self.state.lock().unwrap().deref() {
+            TableState::Loaded(tables) => tables.iter().map(|t| t.len()).sum(),
+            TableState::Unloaded(..) => self.metadata.length,
+        }
+    }
+
+    /// Whether [`Self::len`] is the exact number of rows rather than an upper bound, which is the case unless filters
+    /// have been pushed into the deferred read of an unloaded MicroPartition.
+    pub fn is_exact_length(&self) -> bool {
+        match self.state.lock().unwrap().deref() {
+            TableState::Loaded(..) => true,
+            TableState::Unloaded(params) => !params.has_filters(),
+        }
     }
 
+    /// Returns the size in bytes of this MicroPartition: the exact size of its tables when it's loaded, and otherwise
+    /// an estimate from its statistics, so that it's never read just to be sized, e.g. while planning.
     pub fn size_bytes(&self) -> DaftResult<usize> {
         {
             let guard = self.state.lock().unwrap();
@@ -141,6 +477,29 @@ impl MicroPartition {
         }
     }
 
+    /// Whether [`Self::size_bytes`] is an estimate rather than the exact size, which is the case until the
+    /// MicroPartition is loaded.
+    pub fn size_bytes_is_estimate(&self) -> bool {
+        matches!(self.state.lock().unwrap().deref(), TableState::Unloaded(..))
+    }
+
+    /// Returns the (number of rows, size in bytes) of each internal table, e.g. to gauge fragmentation before deciding
+    /// whether to concatenate them.
+    ///
+    /// An unloaded MicroPartition isn't read; it's reported as a single planned chunk with its estimated size instead.
+    pub fn chunk_info(&self) -> DaftResult<Vec<(usize, usize)>> {
+        {
+            let guard = self.state.lock().unwrap();
+            if let TableState::Loaded(tables) = guard.deref() {
+                return tables
+                    .iter()
+                    .map(|t| Ok((t.len(), t.size_bytes()?)))
+                    .collect();
+            }
+        }
+        Ok(vec![(self.len(), self.size_bytes()?)])
+    }
+
     pub(crate) fn tables_or_read(
         &self,
         io_stats: Option<IOStatsRef>,
@@ -154,6 +513,7 @@ impl MicroPartition {
                 FormatParams::Parquet {
                     row_groups,
                     inference_options,
+                    row_group_statistics,
                 } => {
                     let io_client =
                         daft_io::get_io_client(params.multithreaded_io, params.io_config.clone())
@@ -162,32 +522,105 @@ impl MicroPartition {
                         .columns
                         .as_ref()
                         .map(|v| v.iter().map(|s| s.as_ref()).collect::<Vec<_>>());
-                    let urls = params.urls.iter().map(|s| s.as_str()).collect::<Vec<_>>();
-                    let all_tables = daft_parquet::read::read_parquet_bulk(
-                        urls.as_slice(),
-                        column_names.as_deref(),
-                        None,
-                        params.limit,
-                        row_groups.clone(),
-                        io_client.clone(),
-                        io_stats,
-                        8,
-                        runtime_handle,
-                        inference_options,
-                    )
-                    .context(DaftCoreComputeSnafu)?;
+                    // Row groups can only be pruned by the filters if there's no offset or limit, which apply before
+                    // them.
+                    let (urls, row_groups) = match row_group_statistics {
+                        Some(row_group_statistics)
+                            if params.has_filters()
+                                && params.start_offset.is_none()
+                                && params.limit.is_none() =>
+                        {
+                            let (urls, row_groups) = prune_parquet_row_groups(
+                                &params.urls,
+                                row_groups.as_deref(),
+                                row_group_statistics,
+                                &params.filters,
+                            );
+                            (urls, Some(row_groups))
+                        }
+                        _ => (params.urls.clone(), row_groups.clone()),
+                    };
+                    let urls = urls.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                    let all_tables = if urls.is_empty() {
+                        vec![]
+                    } else {
+                        daft_parquet::read::read_parquet_bulk(
+                            urls.as_slice(),
+                            column_names.as_deref(),
+                            params.start_offset,
+                            params.limit,
+                            row_groups,
+                            io_client.clone(),
+                            io_stats,
+                            8,
+                            runtime_handle,
+                            inference_options,
+                        )
+                        .context(DaftCoreComputeSnafu)?
+                    };
                     all_tables
                         .into_iter()
                         .map(|t| t.cast_to_schema(&self.schema))
                         .collect::<DaftResult<Vec<_>>>()
                         .context(DaftCoreComputeSnafu)?
                 }
+                FormatParams::Csv {
+                    convert_options,
+                    parse_options,
+                    read_options,
+                } => {
+                    let io_client =
+                        daft_io::get_io_client(params.multithreaded_io, params.io_config.clone())
+                            .unwrap();
+                    let urls = params.urls.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                    read_csv_bulk(
+                        urls.as_slice(),
+                        Some(
+                            convert_options
+                                .clone()
+                                .with_include_columns(params.columns.clone())
+                                .with_limit(params.limit),
+                        ),
+                        Some(parse_options.clone()),
+                        read_options.clone(),
+                        io_client,
+                        io_stats,
+                        params.multithreaded_io,
+                        None,
+                        8,
+                    )
+                    .context(DaftCoreComputeSnafu)?
+                }
             };
-            let casted_table_values = table_values
+            let mut casted_table_values = table_values
                 .iter()
                 .map(|tbl| tbl.cast_to_schema(self.schema.as_ref()))
                 .collect::<DaftResult<Vec<_>>>()
                 .context(DaftCoreComputeSnafu)?;
+            // The limit is applied to each file that is read, so the tables of multiple files may exceed it.
+            if let Some(limit) = params.limit {
+                let mut rows_needed = limit;
+                casted_table_values = casted_table_values
+                    .into_iter()
+                    .map(|tbl| {
+                        let rows = tbl.len().min(rows_needed);
+                        rows_needed -= rows;
+                        if rows == tbl.len() {
+                            Ok(tbl)
+                        } else {
+                            tbl.head(rows)
+                        }
+                    })
+                    .collect::<DaftResult<Vec<_>>>()
+                    .context(DaftCoreComputeSnafu)?;
+            }
+            if !params.filters.is_empty() {
+                casted_table_values = casted_table_values
+                    .iter()
+                    .map(|tbl| tbl.filter(&params.filters))
+                    .collect::<DaftResult<Vec<_>>>()
+                    .context(DaftCoreComputeSnafu)?;
+            }
             *guard = TableState::Loaded(Arc::new(casted_table_values));
         };
 
@@ -198,6 +631,35 @@ impl MicroPartition {
         }
     }
 
+    /// Writes this MicroPartition as a single Parquet file at `uri`, loading it first if it's unloaded. Returns the
+    /// URI of the written file.
+    pub fn write_parquet(
+        &self,
+        uri: &str,
+        compression: ParquetCompression,
+        row_group_size: Option<usize>,
+        io_config: Arc<IOConfig>,
+    ) -> DaftResult<String> {
+        let io_client = daft_io::get_io_client(true, io_config)?;
+        let tables = self.concat_or_get()?;
+        match tables.first() {
+            Some(table) => daft_parquet::write::write_parquet(
+                table,
+                uri,
+                compression,
+                row_group_size,
+                io_client,
+            ),
+            None => daft_parquet::write::write_parquet(
+                &Table::empty(Some(self.schema.clone()))?,
+                uri,
+                compression,
+                row_group_size,
+                io_client,
+            ),
+        }
+    }
+
     pub(crate) fn concat_or_get(&self) -> crate::Result<Arc<Vec<Table>>> {
         let tables = self.tables_or_read(None)?;
         if tables.len() <= 1 {
@@ -220,6 +682,47 @@ impl MicroPartition {
     }
 }
 
+/// Prunes the row groups of Parquet files whose statistics prove that none of their rows satisfy `filters`, out of
+/// the selected `row_groups` of each file, or all of its row groups if none are selected. Returns the files that have
+/// any row groups left, along with those row groups.
+fn prune_parquet_row_groups(
+    urls: &[String],
+    row_groups: Option<&[Vec<i64>]>,
+    row_group_statistics: &[Vec<TableStatistics>],
+    filters: &[Expr],
+) -> (Vec<String>, Vec<Vec<i64>>) {
+    let predicate = filters
+        .iter()
+        .cloned()
+        .reduce(|a, b| a.and(&b))
+        .expect("should have at least 1 filter");
+    let required_columns = get_required_columns(&predicate);
+    let may_match = |stats: &TableStatistics| {
+        // Statistics that don't cover the filtered columns, or that the predicate can't be evaluated on, can't prune.
+        !required_columns
+            .iter()
+            .all(|column| stats.columns.contains_key(column))
+            || stats
+                .eval_expression(&predicate)
+                .map_or(true, |result| result.to_truth_value() != TruthValue::False)
+    };
+    urls.iter()
+        .zip(row_group_statistics)
+        .enumerate()
+        .filter_map(|(i, (url, file_stats))| {
+            let candidates = match row_groups {
+                Some(row_groups) => row_groups[i].clone(),
+                None => (0..file_stats.len() as i64).collect(),
+            };
+            let remaining = candidates
+                .into_iter()
+                .filter(|idx| file_stats.get(*idx as usize).map_or(true, may_match))
+                .collect::<Vec<_>>();
+            (!remaining.is_empty()).then(|| (url.clone(), remaining))
+        })
+        .unzip()
+}
+
 fn prune_fields_from_schema(schema: Schema, columns: Option<&[&str]>) -> DaftResult<Schema> {
     if let Some(columns) = columns {
         let avail_names = schema
@@ -250,28 +753,129 @@ fn prune_fields_from_schema(schema: Schema, columns: Option<&[&str]>) -> DaftRes
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+// Number of files after the first whose inferred schema is checked against the reused schema in strict mode.
+const STRICT_SCHEMA_SAMPLE_SIZE: usize = 4;
+
 pub(crate) fn read_csv_into_micropartition(
     uris: &[&str],
-    column_names: Option<Vec<&str>>,
-    include_columns: Option<Vec<&str>>,
-    num_rows: Option<usize>,
-    has_header: bool,
-    delimiter: Option<u8>,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
     io_config: Arc<IOConfig>,
     multithreaded_io: bool,
     io_stats: Option<IOStatsRef>,
-    schema: Option<SchemaRef>,
-    buffer_size: Option<usize>,
-    chunk_size: Option<usize>,
 ) -> DaftResult<MicroPartition> {
     let io_client = daft_io::get_io_client(multithreaded_io, io_config.clone())?;
-    let mut remaining_rows = num_rows;
+    let mut remaining_rows = convert_options.as_ref().and_then(|opts| opts.limit);
+    let mut convert_options = convert_options;
+
+    // Headerless all-string reads build their schema from the column names, so no file needs to be inferred.
+    if let Some(opts) = &convert_options
+        && opts.schema.is_none()
+        && let Some(schema) = opts.all_strings_schema(&parse_options.clone().unwrap_or_default())?
+    {
+        convert_options = Some(opts.clone().with_schema(Some(schema.into())));
+    }
+
+    let CsvReadOptions {
+        reuse_first_schema,
+        strict_schema,
+        skip_empty_files,
+        ..
+    } = read_options.clone().unwrap_or_default();
+    let infers_schema = convert_options
+        .as_ref()
+        .map_or(true, |opts| opts.schema.is_none());
+
+    // Empty files have no header to infer a schema from, so handle them up front when inferring the schema of
+    // multiple files. With a known schema, an empty file is simply read as zero rows.
+    let non_empty_uris;
+    let uris = if infers_schema && uris.len() > 1 {
+        non_empty_uris = filter_empty_files(
+            uris,
+            skip_empty_files,
+            io_client.clone(),
+            io_stats.clone(),
+            multithreaded_io,
+        )?;
+        non_empty_uris.as_slice()
+    } else {
+        uris
+    };
+
+    // Infer the schema once from the first file and use it for all files, skipping per-file inference.
+    if reuse_first_schema && infers_schema && uris.len() > 1 {
+        let (schema, _) = read_csv_schema(
+            uris[0],
+            parse_options.clone(),
+            None,
+            None,
+            convert_options.clone(),
+            io_client.clone(),
+            io_stats.clone(),
+        )?;
+        if strict_schema {
+            validate_csv_schema_sample(
+                uris,
+                &schema,
+                parse_options.clone(),
+                convert_options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            )?;
+        }
+        convert_options = Some(
+            convert_options
+                .unwrap_or_default()
+                .with_schema(Some(schema.into())),
+        );
+    }
 
+    let compute_statistics = read_options
+        .as_ref()
+        .map_or(false, |opts| opts.compute_statistics);
     match uris {
-        [] => Ok(MicroPartition::empty(None)),
+        [] => {
+            // No files are left to read, e.g. every file was skipped for being empty, so the read is empty, but keeps
+            // the schema it was given, if any.
+            let schema = match convert_options.as_ref() {
+                Some(CsvConvertOptions {
+                    schema: Some(schema),
+                    include_columns,
+                    ..
+                }) => {
+                    let include_columns = include_columns
+                        .as_ref()
+                        .map(|cols| cols.iter().map(String::as_str).collect::<Vec<_>>());
+                    Some(
+                        prune_fields_from_schema(
+                            schema.as_ref().clone(),
+                            include_columns.as_deref(),
+                        )?
+                        .into(),
+                    )
+                }
+                _ => None,
+            };
+            Ok(MicroPartition::empty(schema))
+        }
+        uris if remaining_rows.is_none() => {
+            // Without a limit, every file is read in full, so read them in parallel.
+            let tables = read_csv_bulk(
+                uris,
+                convert_options,
+                parse_options,
+                read_options,
+                io_client,
+                io_stats,
+                multithreaded_io,
+                None,
+                8,
+            )?;
+            csv_tables_into_micropartition(tables, compute_statistics)
+        }
         uris => {
-            // Naively load CSVs from URIs
+            // Read files in order until we have read enough rows.
             let mut tables = vec![];
             for uri in uris {
                 // Terminate early if we have read enough rows already
@@ -280,44 +884,204 @@ pub(crate) fn read_csv_into_micropartition(
                 }
                 let table = read_csv(
                     uri,
-                    column_names.clone(),
-                    include_columns.clone(),
-                    remaining_rows,
-                    has_header,
-                    delimiter,
+                    convert_options
+                        .clone()
+                        .map(|opts| opts.with_limit(remaining_rows)),
+                    parse_options.clone(),
+                    read_options.clone(),
                     io_client.clone(),
                     io_stats.clone(),
                     multithreaded_io,
-                    schema.clone(),
-                    buffer_size,
-                    chunk_size,
                     None,
                 )?;
                 remaining_rows = remaining_rows.map(|rr| rr - table.len());
                 tables.push(table);
             }
+            csv_tables_into_micropartition(tables, compute_statistics)
+        }
+    }
+}
 
-            // Union all schemas and cast all tables to the same schema
-            let unioned_schema = tables
-                .iter()
-                .map(|tbl| tbl.schema.clone())
-                .try_reduce(|s1, s2| s1.union(s2.as_ref()).map(Arc::new))?
-                .unwrap();
-            let tables = tables
-                .into_iter()
-                .map(|tbl| tbl.cast_to_schema(&unioned_schema))
-                .collect::<DaftResult<Vec<_>>>()?;
-
-            // Construct MicroPartition from tables and unioned schema
-            let total_len = tables.iter().map(|t| t.len()).sum();
-            Ok(MicroPartition::new(
-                unioned_schema.clone(),
-                TableState::Loaded(Arc::new(tables)),
-                TableMetadata { length: total_len },
-                None,
-            ))
+/// Opens a deferred read of a CSV file of `num_rows` rows, which is known ahead of the read, e.g. from the metadata of
+/// a catalog. Only the schema of the file is inferred until the MicroPartition is loaded.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn read_deferred_csv_into_micropartition(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+    num_rows: usize,
+    statistics: Option<&TableStatistics>,
+    io_config: Arc<IOConfig>,
+    multithreaded_io: bool,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<MicroPartition> {
+    let io_client = daft_io::get_io_client(multithreaded_io, io_config.clone())?;
+    let (file_schema, _) = read_csv_schema(uri, None, None, None, None, io_client, io_stats)?;
+    let column_names = columns
+        .as_ref()
+        .map(|columns| columns.iter().map(String::as_str).collect::<Vec<_>>());
+    let schema = prune_fields_from_schema(file_schema.clone(), column_names.as_deref())?;
+    // Columns without statistics of their own get missing statistics.
+    let statistics = statistics
+        .cloned()
+        .unwrap_or_else(|| TableStatistics::new(Default::default()))
+        .cast_to_schema(&schema);
+
+    let params = DeferredLoadingParams {
+        format_params: FormatParams::Csv {
+            convert_options: CsvConvertOptions::default().with_schema(Some(file_schema.into())),
+            parse_options: CsvParseOptions::default(),
+            read_options: None,
+        },
+        urls: vec![uri.to_string()],
+        io_config,
+        multithreaded_io,
+        start_offset: None,
+        limit,
+        columns,
+        filters: vec![],
+    };
+    Ok(MicroPartition::new(
+        schema.into(),
+        TableState::Unloaded(params),
+        TableMetadata {
+            length: limit.map_or(num_rows, |limit| num_rows.min(limit)),
+        },
+        Some(statistics),
+    ))
+}
+
+/// Reads a MicroPartition from in-memory CSV `bytes`, e.g. for small inline data, without any IO.
+pub(crate) fn read_csv_from_bytes_into_micropartition(
+    bytes: &[u8],
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+) -> DaftResult<MicroPartition> {
+    let compute_statistics = read_options
+        .as_ref()
+        .map_or(false, |opts| opts.compute_statistics);
+    let table = read_csv_from_reader(
+        std::io::Cursor::new(bytes.to_vec()),
+        convert_options,
+        parse_options,
+        read_options,
+    )?;
+    csv_tables_into_micropartition(vec![table], compute_statistics)
+}
+
+fn csv_tables_into_micropartition(
+    tables: Vec<Table>,
+    compute_statistics: bool,
+) -> DaftResult<MicroPartition> {
+    // Union all schemas and cast all tables to the same schema
+    let unioned_schema = tables
+        .iter()
+        .map(|tbl| tbl.schema.clone())
+        .try_reduce(|s1, s2| s1.union(s2.as_ref()).map(Arc::new))?
+        .unwrap();
+    let tables = tables
+        .into_iter()
+        .map(|tbl| tbl.cast_to_schema(&unioned_schema))
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    // CSV files have no statistics of their own, so compute them from the data that was read, if requested.
+    let mut statistics: Option<TableStatistics> = None;
+    if compute_statistics {
+        for table in tables.iter() {
+            let table_stats = TableStatistics::from_table(table)?;
+            statistics = Some(match statistics {
+                Some(statistics) => statistics.union(&table_stats)?,
+                None => table_stats,
+            });
+        }
+    }
+
+    // Construct MicroPartition from tables and unioned schema
+    let total_len = tables.iter().map(|t| t.len()).sum();
+    Ok(MicroPartition::new(
+        unioned_schema.clone(),
+        TableState::Loaded(Arc::new(tables)),
+        TableMetadata { length: total_len },
+        statistics,
+    ))
+}
+
+/// Drops empty (zero-byte) files from `uris`, or errors on the first empty file if `skip_empty_files` is false.
+fn filter_empty_files<'a>(
+    uris: &[&'a str],
+    skip_empty_files: bool,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<Vec<&'a str>> {
+    let runtime_handle = daft_io::get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let sizes = runtime_handle.block_on(async {
+        let futures = uris.iter().map(|uri| {
+            let uri = uri.to_string();
+            let io_client = io_client.clone();
+            let io_stats = io_stats.clone();
+            async move { io_client.single_url_get_size(uri, io_stats).await }
+        });
+        // Limit the number of concurrent requests, while preserving the input URI order.
+        join_all_bounded(futures, DEFAULT_MAX_CONCURRENT_IO)
+            .await
+            .map_err(|e| DaftError::External(e.into()))
+    })?;
+    let mut non_empty_uris = Vec::with_capacity(uris.len());
+    for (uri, size) in uris.iter().zip(sizes) {
+        if size? > 0 {
+            non_empty_uris.push(*uri);
+        } else if !skip_empty_files {
+            return Err(DaftError::ValueError(format!(
+                "CSV file {} is empty; set skip_empty_files to skip empty files instead",
+                uri
+            )));
+        }
+    }
+    Ok(non_empty_uris)
+}
+
+fn validate_csv_schema_sample(
+    uris: &[&str],
+    first_schema: &Schema,
+    parse_options: Option<CsvParseOptions>,
+    convert_options: Option<CsvConvertOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    // Spread the sample evenly over the files after the first one.
+    let step = ((uris.len() - 1) / STRICT_SCHEMA_SAMPLE_SIZE).max(1);
+    let sample_uris = uris
+        .iter()
+        .skip(1)
+        .step_by(step)
+        .take(STRICT_SCHEMA_SAMPLE_SIZE)
+        .copied()
+        .collect::<Vec<_>>();
+    let runtime_handle = daft_io::get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    let schemas = runtime_handle.block_on(read_csv_schema_bulk(
+        sample_uris.as_slice(),
+        parse_options,
+        None,
+        None,
+        convert_options,
+        io_client,
+        io_stats,
+        None,
+        false,
+    ))?;
+    for (uri, (schema, _)) in sample_uris.iter().zip(schemas) {
+        if schema != *first_schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "CSV file {} has schema:\n{}\nwhich does not match the schema inferred from {}:\n{}",
+                uri, schema, uris[0], first_schema
+            )));
         }
     }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -333,10 +1097,6 @@ pub(crate) fn read_parquet_into_micropartition(
     multithreaded_io: bool,
     schema_infer_options: &ParquetSchemaInferenceOptions,
 ) -> DaftResult<MicroPartition> {
-    if let Some(so) = start_offset && so > 0 {
-        return Err(common_error::DaftError::ValueError("Micropartition Parquet Reader does not support non-zero start offsets".to_string()));
-    }
-
     let runtime_handle = daft_io::get_runtime(multithreaded_io)?;
     let io_client = daft_io::get_io_client(multithreaded_io, io_config.clone())?;
 
@@ -344,31 +1104,12 @@ pub(crate) fn read_parquet_into_micropartition(
     let meta_io_stats = io_stats.clone();
 
     let metadata = runtime_handle.block_on(async move {
-        read_parquet_metadata_bulk(uris, meta_io_client, meta_io_stats).await
+        read_parquet_metadata_bulk(uris, meta_io_client, meta_io_stats, None).await
     })?;
-    let any_stats_avail = metadata
-        .iter()
-        .flat_map(|m| m.row_groups.iter())
-        .flat_map(|rg| rg.columns().iter())
-        .any(|col| col.statistics().is_some());
-    let stats = if any_stats_avail {
-        let stat_per_table = metadata
-            .iter()
-            .flat_map(|fm| {
-                fm.row_groups
-                    .iter()
-                    .map(daft_parquet::row_group_metadata_to_table_stats)
-            })
-            .collect::<DaftResult<Vec<TableStatistics>>>()?;
-        stat_per_table.into_iter().try_reduce(|a, b| a.union(&b))?
-    } else {
-        None
-    };
-
     let schemas = metadata
         .iter()
         .map(|m| {
-            let schema = infer_schema_with_options(m, &Some((*schema_infer_options).into()))?;
+            let schema = schema_infer_options.infer_arrow_schema(m)?;
             let daft_schema = daft_core::schema::Schema::try_from(&schema)?;
             DaftResult::Ok(daft_schema)
         })
@@ -380,6 +1121,39 @@ pub(crate) fn read_parquet_into_micropartition(
 
     let daft_schema = prune_fields_from_schema(daft_schema, columns)?;
 
+    let any_stats_avail = metadata
+        .iter()
+        .flat_map(|m| m.row_groups.iter())
+        .flat_map(|rg| rg.columns().iter())
+        .any(|col| col.statistics().is_some());
+    let row_group_stats = if any_stats_avail {
+        Some(
+            metadata
+                .iter()
+                .map(|fm| {
+                    // Align the statistics of files with different columns, so that they can be unioned.
+                    fm.row_groups
+                        .iter()
+                        .map(|rg| {
+                            let stats = daft_parquet::row_group_metadata_to_table_stats(rg)?;
+                            Ok(stats.cast_to_schema(&daft_schema))
+                        })
+                        .collect::<DaftResult<Vec<TableStatistics>>>()
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+        )
+    } else {
+        None
+    };
+    let stats = match &row_group_stats {
+        Some(row_group_stats) => row_group_stats
+            .iter()
+            .flatten()
+            .cloned()
+            .try_reduce(|a, b| a.union(&b))?,
+        None => None,
+    };
+
     // Get total number of rows, accounting for selected `row_groups` and the indicated `num_rows`
     let total_rows_no_limit = match &row_groups {
         None => metadata.iter().map(|fm| fm.num_rows).sum(),
@@ -393,6 +1167,14 @@ pub(crate) fn read_parquet_into_micropartition(
             })
             .sum(),
     };
+    // The offset applies to each file, while the limit applies to the rows of all files.
+    let total_rows_no_limit = match (&row_groups, start_offset) {
+        (None, Some(start_offset)) => metadata
+            .iter()
+            .map(|fm| fm.num_rows.saturating_sub(start_offset))
+            .sum(),
+        _ => total_rows_no_limit,
+    };
     let total_rows = num_rows
         .map(|num_rows| num_rows.min(total_rows_no_limit))
         .unwrap_or(total_rows_no_limit);
@@ -404,23 +1186,18 @@ pub(crate) fn read_parquet_into_micropartition(
         let params = DeferredLoadingParams {
             format_params: FormatParams::Parquet {
                 row_groups,
-                inference_options: *schema_infer_options,
+                inference_options: schema_infer_options.clone(),
+                row_group_statistics: row_group_stats,
             },
             urls: owned_urls,
             io_config: io_config.clone(),
             multithreaded_io,
+            start_offset,
             limit: num_rows,
             columns: owned_columns,
+            filters: vec![],
         };
 
-        let exprs = daft_schema
-            .fields
-            .keys()
-            .map(|n| daft_dsl::col(n.as_str()))
-            .collect::<Vec<_>>();
-        // use schema to update stats
-        let stats = stats.eval_expression_list(exprs.as_slice(), &daft_schema)?;
-
         Ok(MicroPartition::new(
             Arc::new(daft_schema),
             TableState::Unloaded(params),
@@ -453,12 +1230,38 @@ pub(crate) fn read_parquet_into_micropartition(
     }
 }
 
+/// Reads a MicroPartition from in-memory Parquet `bytes`, e.g. for small inline data, without any IO.
+pub(crate) fn read_parquet_from_bytes_into_micropartition(
+    bytes: &[u8],
+    columns: Option<&[&str]>,
+    start_offset: Option<usize>,
+    num_rows: Option<usize>,
+    row_groups: Option<Vec<i64>>,
+    schema_infer_options: &ParquetSchemaInferenceOptions,
+) -> DaftResult<MicroPartition> {
+    let table = read_parquet_from_bytes(
+        bytes,
+        columns,
+        start_offset,
+        num_rows,
+        row_groups,
+        schema_infer_options.clone(),
+    )?;
+    let length = table.len();
+    Ok(MicroPartition::new(
+        table.schema.clone(),
+        TableState::Loaded(Arc::new(vec![table])),
+        TableMetadata { length },
+        None,
+    ))
+}
+
 impl Display for MicroPartition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let guard = self.state.lock().unwrap();
-
         writeln!(f, "MicroPartition with {} rows:", self.len())?;
 
+        let guard = self.state.lock().unwrap();
+
         match guard.deref() {
             TableState::Unloaded(..) => {
                 writeln!(f, "{}\n{}", self.schema, guard)?;
@@ -480,4 +1283,1199 @@ impl Display for MicroPartition {
     }
 }
 #[cfg(test)]
-mod test {}
+mod test {
+    use std::{ops::Deref, sync::Arc};
+
+    use arrow2::{
+        chunk::Chunk,
+        datatypes::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema},
+        io::parquet::write::{
+            CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+        },
+    };
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Field, Int64Array, Utf8Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
+    use daft_csv::{CsvConvertOptions, CsvReadOptions};
+    use daft_dsl::{col, lit, Expr};
+    use daft_io::{IOConfig, IOStatsContext};
+    use daft_parquet::{read::ParquetSchemaInferenceOptions, write::ParquetCompression};
+    use daft_scan::{AnonymousScanOperator, FileType, ScanOperator, ScanOperatorRef, ScanTask};
+    use daft_stats::{ColumnRangeStatistics, TableMetadata, TableStatistics};
+    use daft_table::Table;
+
+    use super::{
+        deserialize_versioned, prune_parquet_row_groups, read_csv_from_bytes_into_micropartition,
+        read_csv_into_micropartition, read_deferred_csv_into_micropartition,
+        read_parquet_from_bytes_into_micropartition, read_parquet_into_micropartition,
+        serialize_versioned, DeferredLoadingParams, FormatParams, MicroPartition, TableState,
+        SERIALIZATION_FORMAT_VERSION,
+    };
+
+    #[test]
+    fn test_versioned_serialization_roundtrip() -> DaftResult<()> {
+        let metadata = TableMetadata { length: 42 };
+        let bytes = serialize_versioned(&metadata)?;
+        assert_eq!(bytes[0], SERIALIZATION_FORMAT_VERSION);
+        let roundtripped = deserialize_versioned::<TableMetadata>(&bytes)?;
+        assert_eq!(roundtripped.length, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_versioned_serialization_unknown_version() -> DaftResult<()> {
+        let mut bytes = serialize_versioned(&TableMetadata { length: 42 })?;
+        bytes[0] = SERIALIZATION_FORMAT_VERSION + 1;
+        let err = deserialize_versioned::<TableMetadata>(&bytes);
+        assert!(
+            matches!(&err, Err(DaftError::ValueError(msg)) if msg.contains("Unsupported MicroPartition serialization format version")),
+        );
+        assert!(matches!(
+            deserialize_versioned::<TableMetadata>(&[]),
+            Err(DaftError::ValueError(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_info_reports_per_table_sizes() -> DaftResult<()> {
+        let tables = [3, 1, 5]
+            .iter()
+            .map(|len| {
+                Table::from_columns(vec![Int64Array::from((
+                    "a",
+                    (0..*len).collect::<Vec<i64>>(),
+                ))
+                .into_series()])
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let table_sizes = tables
+            .iter()
+            .map(|t| t.size_bytes())
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mp = MicroPartition::new(
+            tables[0].schema.clone(),
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length: 9 },
+            None,
+        );
+
+        let chunk_info = mp.chunk_info()?;
+        assert_eq!(
+            chunk_info.iter().map(|(rows, _)| *rows).collect::<Vec<_>>(),
+            vec![3, 1, 5]
+        );
+        assert_eq!(
+            chunk_info
+                .iter()
+                .map(|(_, bytes)| *bytes)
+                .collect::<Vec<_>>(),
+            table_sizes
+        );
+        assert_eq!(
+            chunk_info.iter().map(|(_, bytes)| *bytes).sum::<usize>(),
+            mp.size_bytes()?
+        );
+
+        Ok(())
+    }
+
+    /// An unloaded MicroPartition of 100 rows, whose file doesn't exist so that any read fails.
+    fn unloaded_micropartition() -> DaftResult<MicroPartition> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let bound = |v: i64| Int64Array::from(("a", vec![v])).into_series();
+        let stats = TableStatistics::new(
+            [(
+                "a".to_string(),
+                ColumnRangeStatistics::new(Some(bound(0)), Some(bound(99)))?,
+            )]
+            .into_iter()
+            .collect(),
+        );
+        let params = DeferredLoadingParams {
+            format_params: FormatParams::Parquet {
+                row_groups: None,
+                inference_options: ParquetSchemaInferenceOptions::default(),
+                row_group_statistics: None,
+            },
+            urls: vec!["/does/not/exist.parquet".to_string()],
+            io_config: IOConfig::default().into(),
+            multithreaded_io: false,
+            start_offset: None,
+            limit: None,
+            columns: None,
+            filters: vec![],
+        };
+        Ok(MicroPartition::new(
+            schema.into(),
+            TableState::Unloaded(params),
+            TableMetadata { length: 100 },
+            Some(stats),
+        ))
+    }
+
+    #[test]
+    fn test_unloaded_size_bytes_is_estimate() -> DaftResult<()> {
+        let mp = unloaded_micropartition()?;
+
+        assert!(mp.size_bytes_is_estimate());
+        let row_size = mp.statistics.as_ref().unwrap().estimate_row_size()?;
+        assert_eq!(mp.size_bytes()?, row_size * 100);
+        assert!(matches!(
+            mp.state.lock().unwrap().deref(),
+            TableState::Unloaded(..)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_head_pushes_limit() -> DaftResult<()> {
+        let mp = unloaded_micropartition()?;
+
+        // The tighter of the limits is kept.
+        for head in [mp.head(5)?, mp.head(10)?.head(5)?, mp.head(5)?.head(10)?] {
+            assert_eq!(head.len(), 5);
+            match head.state.lock().unwrap().deref() {
+                TableState::Unloaded(params) => assert_eq!(params.limit, Some(5)),
+                TableState::Loaded(..) => panic!("head should not load the MicroPartition"),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a Parquet file with statistics, with an `a` column of the provided values, and a `b` column of their
+    /// doubles, with a row group per vector of values.
+    fn write_parquet(path: &std::path::Path, row_group_values: Vec<Vec<i64>>) -> DaftResult<()> {
+        write_nullable_parquet(
+            path,
+            row_group_values
+                .into_iter()
+                .map(|values| values.into_iter().map(Some).collect())
+                .collect(),
+        )
+    }
+
+    /// Like [`write_parquet`], where `None` values are written as nulls.
+    fn write_nullable_parquet(
+        path: &std::path::Path,
+        row_group_values: Vec<Vec<Option<i64>>>,
+    ) -> DaftResult<()> {
+        let schema = ArrowSchema::from(vec![
+            ArrowField::new("a", ArrowDataType::Int64, true),
+            ArrowField::new("b", ArrowDataType::Int64, true),
+        ]);
+        let chunks = row_group_values.into_iter().map(|values| {
+            let doubles = values.iter().map(|v| v.map(|v| v * 2)).collect::<Vec<_>>();
+            Ok(Chunk::new(vec![
+                arrow2::array::Int64Array::from(values).boxed(),
+                arrow2::array::Int64Array::from(doubles).boxed(),
+            ]))
+        });
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            chunks,
+            &schema,
+            options,
+            vec![vec![Encoding::Plain], vec![Encoding::Plain]],
+        )?;
+        let mut writer = FileWriter::try_new(std::fs::File::create(path)?, schema, options)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_head_reads_limit() -> DaftResult<()> {
+        let paths = (0..2)
+            .map(|i| {
+                std::env::temp_dir().join(format!(
+                    "daft_mp_head_{}_{}.parquet",
+                    std::process::id(),
+                    i
+                ))
+            })
+            .collect::<Vec<_>>();
+        for path in &paths {
+            write_parquet(path, vec![(0..100).collect()])?;
+        }
+        let uris = paths
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect::<Vec<_>>();
+
+        let result = read_parquet_into_micropartition(
+            uris.as_slice(),
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            let head = mp.head(5)?;
+            assert!(head.size_bytes_is_estimate());
+            Ok(head.concat_or_get()?)
+        });
+        for path in &paths {
+            std::fs::remove_file(path)?;
+        }
+        let tables = result?;
+
+        // The limit applies to each file that is read, but only the first rows overall are kept.
+        assert_eq!(tables.len(), 1);
+        assert_eq!(
+            tables[0]
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[0, 1, 2, 3, 4]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_select_reads_projection() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_select_{}.parquet", std::process::id()));
+        write_parquet(&path, vec![(0..10).collect()])?;
+
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            let selected = mp.select(&["b"])?;
+            assert_eq!(selected.column_names(), vec!["b"]);
+            match selected.state.lock().unwrap().deref() {
+                TableState::Unloaded(params) => {
+                    assert_eq!(params.columns, Some(vec!["b".to_string()]))
+                }
+                TableState::Loaded(..) => panic!("select should not load the MicroPartition"),
+            }
+            Ok(selected.concat_or_get()?)
+        });
+        std::fs::remove_file(&path)?;
+        let tables = result?;
+
+        assert_eq!(tables[0].column_names(), vec!["b"]);
+        assert_eq!(
+            tables[0]
+                .get_column("b")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &(0..10).map(|v| v * 2).collect::<Vec<i64>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_cast_to_schema_is_lazy() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_cast_{}.parquet", std::process::id()));
+        write_parquet(&path, vec![(0..3).collect()])?;
+
+        let target = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8),
+            Field::new("b", DataType::Utf8),
+        ])?);
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            let casted = mp.cast_to_schema(target.clone())?;
+            assert!(matches!(
+                casted.state.lock().unwrap().deref(),
+                TableState::Unloaded(..)
+            ));
+            assert_eq!(casted.schema, target);
+            Ok(casted.concat_or_get()?)
+        });
+        std::fs::remove_file(&path)?;
+        let tables = result?;
+
+        // The cast is applied as the deferred read is loaded.
+        assert_eq!(tables[0].schema, target);
+        assert_eq!(
+            tables[0]
+                .get_column("b")?
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(str::to_string))
+                .collect::<Vec<_>>(),
+            vec![
+                Some("0".to_string()),
+                Some("2".to_string()),
+                Some("4".to_string())
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_csv_cast_to_schema_reads_target_types() -> DaftResult<()> {
+        let path = std::env::temp_dir().join(format!("daft_mp_cast_{}.csv", std::process::id()));
+        std::fs::write(&path, "a,b,c\n1,x,1.5\n2,y,2.5\n3,z,3.5\n")?;
+
+        let target = Arc::new(Schema::new(vec![
+            Field::new("c", DataType::Utf8),
+            Field::new("a", DataType::Utf8),
+        ])?);
+        let result = read_deferred_csv_into_micropartition(
+            path.to_str().unwrap(),
+            Some(vec!["a".to_string(), "c".to_string()]),
+            None,
+            3,
+            None,
+            IOConfig::default().into(),
+            true,
+            None,
+        )
+        .and_then(|mp| {
+            assert_eq!(mp.len(), 3);
+            let casted = mp.cast_to_schema(target.clone())?;
+            assert_eq!(casted.schema, target);
+            // The deferred read parses the cast columns as strings, rather than casting them after the read.
+            match casted.state.lock().unwrap().deref() {
+                TableState::Unloaded(DeferredLoadingParams {
+                    format_params:
+                        FormatParams::Csv {
+                            convert_options, ..
+                        },
+                    ..
+                }) => assert_eq!(
+                    convert_options.schema,
+                    Some(
+                        Schema::new(vec![
+                            Field::new("a", DataType::Utf8),
+                            Field::new("b", DataType::Utf8),
+                            Field::new("c", DataType::Utf8),
+                        ])?
+                        .into()
+                    )
+                ),
+                _ => panic!("casting should not load the MicroPartition"),
+            }
+            Ok(casted.concat_or_get()?)
+        });
+        std::fs::remove_file(&path)?;
+        let tables = result?;
+
+        assert_eq!(tables[0].schema, target);
+        let column = |name| -> DaftResult<Vec<Option<String>>> {
+            Ok(tables[0]
+                .get_column(name)?
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(str::to_string))
+                .collect())
+        };
+        assert_eq!(
+            column("a")?,
+            vec![
+                Some("1".to_string()),
+                Some("2".to_string()),
+                Some("3".to_string())
+            ]
+        );
+        assert_eq!(
+            column("c")?,
+            vec![
+                Some("1.5".to_string()),
+                Some("2.5".to_string()),
+                Some("3.5".to_string())
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_filter_length_is_inexact() -> DaftResult<()> {
+        let mp = unloaded_micropartition()?;
+        assert!(mp.is_exact_length());
+
+        let filtered = mp.filter(&[col("a").gt(&lit(50i64))])?;
+        assert!(!filtered.is_exact_length());
+        assert_eq!(filtered.len(), 100);
+        assert!(matches!(
+            filtered.state.lock().unwrap().deref(),
+            TableState::Unloaded(..)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_filter() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_filter_{}.parquet", std::process::id()));
+        write_parquet(&path, vec![(0..10).collect()])?;
+
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            let filtered = mp.filter(&[col("a").gt_eq(&lit(6i64))])?;
+            assert!(!filtered.is_exact_length());
+            filtered.concat_or_get()?;
+            Ok(filtered)
+        });
+        std::fs::remove_file(&path)?;
+        let filtered = result?;
+
+        assert!(filtered.is_exact_length());
+        assert_eq!(filtered.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_filter_prunes_row_groups() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_prune_{}.parquet", std::process::id()));
+        write_parquet(
+            &path,
+            vec![(0..10).collect(), (10..20).collect(), (20..30).collect()],
+        )?;
+
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            let filtered = mp.filter(&[col("a").gt_eq(&lit(25i64))])?;
+            let pruned = match filtered.state.lock().unwrap().deref() {
+                TableState::Unloaded(DeferredLoadingParams {
+                    format_params:
+                        FormatParams::Parquet {
+                            row_group_statistics: Some(row_group_statistics),
+                            ..
+                        },
+                    urls,
+                    filters,
+                    ..
+                }) => prune_parquet_row_groups(urls, None, row_group_statistics, filters),
+                _ => panic!("filter should keep the row group statistics of the unloaded read"),
+            };
+            let tables = filtered.concat_or_get()?;
+            Ok((pruned, tables))
+        });
+        std::fs::remove_file(&path)?;
+        let ((urls, row_groups), tables) = result?;
+
+        // Only the last row group may have values of at least 25.
+        assert_eq!(urls.len(), 1);
+        assert_eq!(row_groups, vec![vec![2]]);
+        assert_eq!(
+            tables[0]
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[25, 26, 27, 28, 29]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_filter_reads_fewer_bytes() -> DaftResult<()> {
+        let path = std::env::temp_dir().join(format!(
+            "daft_mp_prune_bytes_{}.parquet",
+            std::process::id()
+        ));
+        write_parquet(
+            &path,
+            vec![
+                (0..1000).collect(),
+                (1000..2000).collect(),
+                (2000..3000).collect(),
+                (3000..4000).collect(),
+            ],
+        )?;
+
+        let read = |filters: &[Expr]| {
+            let mp = read_parquet_into_micropartition(
+                &[path.to_str().unwrap()],
+                None,
+                None,
+                None,
+                None,
+                IOConfig::default().into(),
+                None,
+                1,
+                true,
+                &ParquetSchemaInferenceOptions::default(),
+            )?;
+            let mp = if filters.is_empty() {
+                mp
+            } else {
+                mp.filter(filters)?
+            };
+            let io_stats = IOStatsContext::new("test_unloaded_parquet_filter".to_string());
+            let tables = mp.tables_or_read(Some(io_stats.clone()))?;
+            DaftResult::Ok((tables, io_stats.load_bytes_read()))
+        };
+        let unfiltered = read(&[]);
+        let filtered = read(&[col("a").gt_eq(&lit(3990i64))]);
+        std::fs::remove_file(&path)?;
+        let (unfiltered_tables, unfiltered_bytes) = unfiltered?;
+        let (filtered_tables, filtered_bytes) = filtered?;
+
+        // Only the last row group is read for the selective filter.
+        assert_eq!(
+            unfiltered_tables.iter().map(|t| t.len()).sum::<usize>(),
+            4000
+        );
+        assert!(
+            filtered_bytes < unfiltered_bytes,
+            "read {filtered_bytes} bytes with the filter and {unfiltered_bytes} bytes without it"
+        );
+        assert_eq!(
+            filtered_tables[0]
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            (3990..4000).collect::<Vec<i64>>().as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_parquet_roundtrip() -> DaftResult<()> {
+        let path = std::env::temp_dir().join(format!(
+            "daft_mp_write_roundtrip_{}.parquet",
+            std::process::id()
+        ));
+        let tables = [(0..6), (6..10)]
+            .into_iter()
+            .map(|range| {
+                let strings = range.clone().map(|v| format!("v{v}")).collect::<Vec<_>>();
+                let strings = strings.iter().map(String::as_str).collect::<Vec<_>>();
+                Table::from_columns(vec![
+                    Int64Array::from(("a", range.collect::<Vec<i64>>())).into_series(),
+                    Utf8Array::from(("b", strings.as_slice())).into_series(),
+                ])
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mp = MicroPartition::new(
+            tables[0].schema.clone(),
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length: 10 },
+            None,
+        );
+
+        let result = mp
+            .write_parquet(
+                path.to_str().unwrap(),
+                ParquetCompression::Zstd,
+                Some(4),
+                IOConfig::default().into(),
+            )
+            .and_then(|written| {
+                assert_eq!(written, path.to_str().unwrap());
+                let metadata =
+                    arrow2::io::parquet::read::read_metadata(&mut std::fs::File::open(&path)?)?;
+                let read = read_parquet_into_micropartition(
+                    &[written.as_str()],
+                    None,
+                    None,
+                    None,
+                    None,
+                    IOConfig::default().into(),
+                    None,
+                    1,
+                    true,
+                    &ParquetSchemaInferenceOptions::default(),
+                )?;
+                Ok((metadata, read.concat_or_get()?))
+            });
+        std::fs::remove_file(&path)?;
+        let (metadata, read_tables) = result?;
+
+        // The rows of both tables are written in row groups of at most 4 rows.
+        assert_eq!(
+            metadata
+                .row_groups
+                .iter()
+                .map(|rg| rg.num_rows())
+                .collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert!(metadata.row_groups[0]
+            .columns()
+            .iter()
+            .all(|column| column.compression() == parquet2::compression::Compression::Zstd));
+        let read_table = &read_tables[0];
+        assert_eq!(read_table.schema, mp.schema);
+        assert_eq!(
+            read_table
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &(0..10).collect::<Vec<i64>>()
+        );
+        assert_eq!(
+            read_table
+                .get_column("b")?
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.unwrap().to_string())
+                .collect::<Vec<_>>(),
+            (0..10).map(|v| format!("v{v}")).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unloaded_parquet_start_offset_and_num_rows() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_offset_{}.parquet", std::process::id()));
+        write_parquet(&path, vec![(0..30).collect()])?;
+
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            Some(10),
+            Some(5),
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        )
+        .and_then(|mp| {
+            assert!(mp.size_bytes_is_estimate());
+            assert_eq!(mp.len(), 5);
+            Ok(mp.concat_or_get()?)
+        });
+        std::fs::remove_file(&path)?;
+        let tables = result?;
+
+        assert_eq!(
+            tables[0]
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[10, 11, 12, 13, 14]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_loaded_size_bytes_is_exact() -> DaftResult<()> {
+        let table =
+            Table::from_columns(vec![Int64Array::from(("a", vec![1, 2, 3])).into_series()])?;
+        let mp = MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table.clone()])),
+            TableMetadata { length: 3 },
+            None,
+        );
+
+        assert!(!mp.size_bytes_is_estimate());
+        assert_eq!(mp.size_bytes()?, table.size_bytes()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_read_null_counts() -> DaftResult<()> {
+        let path = std::env::temp_dir().join(format!(
+            "daft_mp_null_counts_{}.parquet",
+            std::process::id()
+        ));
+        write_nullable_parquet(
+            &path,
+            vec![
+                vec![Some(1), None, Some(1), None, Some(4)],
+                vec![None, Some(2)],
+                vec![Some(3), None, None],
+            ],
+        )?;
+
+        let result = read_parquet_into_micropartition(
+            &[path.to_str().unwrap()],
+            None,
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            None,
+            1,
+            true,
+            &ParquetSchemaInferenceOptions::default(),
+        );
+        std::fs::remove_file(&path)?;
+        let stats = result?
+            .statistics
+            .expect("Parquet files written with statistics");
+
+        // The null counts of the row groups add up.
+        assert_eq!(stats.num_rows, Some(10));
+        assert_eq!(stats.null_count("a"), Some(5));
+        assert_eq!(stats.null_count("b"), Some(5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_null_counts() -> DaftResult<()> {
+        let files = iris_uris(&["_nulls.csv"]);
+        let mp = read_csv_into_micropartition(
+            &[files[0].as_str()],
+            None,
+            None,
+            Some(CsvReadOptions::default().with_compute_statistics(true)),
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        let stats = mp
+            .statistics
+            .expect("statistics are computed for CSV reads that opt into them");
+
+        for name in [
+            "sepal.length",
+            "sepal.width",
+            "petal.length",
+            "petal.width",
+            "variety",
+        ] {
+            assert_eq!(stats.null_count(name), Some(1), "null count of {name}");
+        }
+        assert_eq!(stats.distinct_count("variety"), Some(1));
+        assert_eq!(stats.distinct_count("petal.width"), Some(2));
+
+        // Statistics aren't computed by default, as they take another pass over the data.
+        let mp = read_csv_into_micropartition(
+            &[files[0].as_str()],
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        assert!(mp.statistics.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_from_bytes() -> DaftResult<()> {
+        let mp = read_csv_from_bytes_into_micropartition(
+            b"a,b,c\n1,1.5,x\n2,,y\n3,3.5,\n",
+            None,
+            None,
+            Some(CsvReadOptions::default().with_compute_statistics(true)),
+        )?;
+        assert_eq!(mp.len(), 3);
+        assert_eq!(
+            mp.schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Float64),
+                Field::new("c", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let stats = mp
+            .statistics
+            .expect("statistics are computed for CSV reads that opt into them");
+        assert_eq!(stats.null_count("b"), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_read_from_bytes() -> DaftResult<()> {
+        let path =
+            std::env::temp_dir().join(format!("daft_mp_from_bytes_{}.parquet", std::process::id()));
+        write_nullable_parquet(&path, vec![vec![Some(1), None, Some(3)], vec![Some(4)]])?;
+        let bytes = std::fs::read(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let mp = read_parquet_from_bytes_into_micropartition(
+            &bytes,
+            Some(&["b"]),
+            None,
+            None,
+            None,
+            &ParquetSchemaInferenceOptions::default(),
+        )?;
+        assert_eq!(mp.len(), 4);
+        assert_eq!(
+            mp.schema,
+            Schema::new(vec![Field::new("b", DataType::Int64)])?.into(),
+        );
+        let table = mp.concat_or_get()?;
+        let b = table[0].get_column("b")?.i64()?;
+        assert_eq!(
+            b.as_arrow().iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(2), None, Some(6), Some(8)]
+        );
+
+        Ok(())
+    }
+
+    fn iris_uris(suffixes: &[&str]) -> Vec<String> {
+        suffixes
+            .iter()
+            .map(|suffix| {
+                format!(
+                    "{}/../daft-csv/test/iris_tiny{}",
+                    env!("CARGO_MANIFEST_DIR"),
+                    suffix
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_csv_read_reuse_first_schema() -> DaftResult<()> {
+        let files = iris_uris(&[".csv", ".csv.gz", ".csv.zst"]);
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+        let mp = read_csv_into_micropartition(
+            uris.as_slice(),
+            None,
+            None,
+            Some(CsvReadOptions::default()),
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        assert_eq!(mp.len(), 60);
+        assert_eq!(mp.schema.fields.len(), 5);
+
+        // The schema is inferred with a GET of the first file only, rather than a GET of every file, on top of the
+        // GET of every file for the read itself.
+        let files = ["", ".gz", ".zst"]
+            .iter()
+            .map(|ext| format!("s3://daft-public-data/test_fixtures/csv-dev/mvp.csv{}", ext))
+            .collect::<Vec<_>>();
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_config = Arc::new(io_config);
+        let read_s3 = |read_options: CsvReadOptions| {
+            let io_stats = IOStatsContext::new("test_csv_read_reuse_first_schema".to_string());
+            let mp = read_csv_into_micropartition(
+                uris.as_slice(),
+                None,
+                None,
+                Some(read_options),
+                io_config.clone(),
+                true,
+                Some(io_stats.clone()),
+            )?;
+            assert_eq!(mp.len(), 300);
+            DaftResult::Ok((io_stats.load_get_requests(), io_stats.load_bytes_read()))
+        };
+        let (reused_gets, reused_bytes) = read_s3(CsvReadOptions::default())?;
+        let (inferred_gets, inferred_bytes) =
+            read_s3(CsvReadOptions::default().with_reuse_first_schema(false))?;
+        assert_eq!(reused_gets, uris.len() + 1);
+        assert_eq!(inferred_gets, 2 * uris.len());
+        assert!(reused_bytes < inferred_bytes);
+
+        // The header of the second file differs, so only a read that reuses the schema of the first file names its
+        // columns after the first header.
+        let dir = std::env::temp_dir();
+        let first = dir.join(format!("daft_mp_reuse_first_{}.csv", std::process::id()));
+        let second = dir.join(format!("daft_mp_reuse_second_{}.csv", std::process::id()));
+        std::fs::write(&first, "a,b\n1,2\n")?;
+        std::fs::write(&second, "c,d\n3,4\n")?;
+        let uris = [first.to_str().unwrap(), second.to_str().unwrap()];
+        let read = |read_options: CsvReadOptions| {
+            read_csv_into_micropartition(
+                uris.as_slice(),
+                None,
+                None,
+                Some(read_options),
+                IOConfig::default().into(),
+                true,
+                None,
+            )
+        };
+        let reused = read(CsvReadOptions::default());
+        let inferred = read(CsvReadOptions::default().with_reuse_first_schema(false));
+        std::fs::remove_file(&first)?;
+        std::fs::remove_file(&second)?;
+
+        let reused = reused?;
+        assert_eq!(
+            reused.schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ])?
+            .into()
+        );
+        let mut a = vec![];
+        for table in reused.concat_or_get()?.iter() {
+            a.extend(
+                table
+                    .get_column("a")?
+                    .i64()?
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.copied()),
+            );
+        }
+        assert_eq!(a, vec![Some(1), Some(3)]);
+        assert_eq!(inferred?.schema.fields.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_reuse_first_schema_strict_mismatch() -> DaftResult<()> {
+        let files = iris_uris(&[".csv", "_bar_delimiter.csv"]);
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let err = read_csv_into_micropartition(
+            uris.as_slice(),
+            None,
+            None,
+            Some(
+                CsvReadOptions::default()
+                    .with_reuse_first_schema(true)
+                    .with_strict_schema(true),
+            ),
+            IOConfig::default().into(),
+            true,
+            None,
+        );
+        assert!(matches!(err, Err(DaftError::SchemaMismatch(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_skip_empty_files() -> DaftResult<()> {
+        let mut files = iris_uris(&[".csv", ".csv.gz"]);
+        files.insert(
+            1,
+            format!("{}/../daft-csv/test/empty.csv", env!("CARGO_MANIFEST_DIR")),
+        );
+        let uris = files.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let mp = read_csv_into_micropartition(
+            uris.as_slice(),
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        assert_eq!(mp.len(), 40);
+        assert_eq!(mp.schema.fields.len(), 5);
+
+        let err = read_csv_into_micropartition(
+            uris.as_slice(),
+            None,
+            None,
+            Some(CsvReadOptions::default().with_skip_empty_files(false)),
+            IOConfig::default().into(),
+            true,
+            None,
+        );
+        assert!(matches!(&err, Err(DaftError::ValueError(msg)) if msg.contains("empty.csv")),);
+
+        // When every file is skipped, the read is empty.
+        let empty = format!("{}/../daft-csv/test/empty.csv", env!("CARGO_MANIFEST_DIR"));
+        let mp = read_csv_into_micropartition(
+            &[empty.as_str(), empty.as_str()],
+            None,
+            None,
+            None,
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        assert_eq!(mp.len(), 0);
+
+        // With a known schema, empty files are read as zero rows, and the read keeps the projected schema.
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?;
+        let mp = read_csv_into_micropartition(
+            &[empty.as_str(), empty.as_str()],
+            Some(
+                CsvConvertOptions::default()
+                    .with_schema(Some(schema.into()))
+                    .with_include_columns(Some(vec!["b".to_string()])),
+            ),
+            None,
+            Some(CsvReadOptions::default().with_skip_empty_files(false)),
+            IOConfig::default().into(),
+            true,
+            None,
+        )?;
+        assert_eq!(mp.len(), 0);
+        assert_eq!(
+            mp.schema,
+            Schema::new(vec![Field::new("b", DataType::Utf8)])?.into()
+        );
+
+        Ok(())
+    }
+
+    fn scan_tasks(file_type: FileType, uris: Vec<String>) -> DaftResult<Vec<ScanTask>> {
+        let schema = Schema::new(vec![
+            Field::new("sepal.length", DataType::Float64),
+            Field::new("variety", DataType::Utf8),
+        ])?;
+        let operator: ScanOperatorRef = Box::new(AnonymousScanOperator::new(
+            schema.into(),
+            file_type,
+            uris,
+            false,
+        )?);
+        operator
+            .select(&["variety"])?
+            .limit(5)?
+            .to_scan_tasks()?
+            .collect()
+    }
+
+    #[test]
+    fn test_from_scan_task_csv() -> DaftResult<()> {
+        let scan_tasks = scan_tasks(FileType::Csv, iris_uris(&[".csv", ".csv.gz"]))?;
+        assert_eq!(scan_tasks.len(), 2);
+        for scan_task in &scan_tasks {
+            let mp =
+                MicroPartition::from_scan_task(scan_task, IOConfig::default().into(), true, None)?;
+            assert_eq!(mp.len(), 5);
+            assert_eq!(mp.column_names(), vec!["variety"]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_scan_task_hive_partition_columns() -> DaftResult<()> {
+        let dir = std::env::temp_dir().join(format!(
+            "daft_mp_hive_{}/year=2023/variety=setosa",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("part-0.csv");
+        std::fs::copy(&iris_uris(&[".csv"])[0], &path)?;
+
+        let schema = Schema::new(vec![Field::new("sepal.length", DataType::Float64)])?;
+        let operator: ScanOperatorRef = Box::new(AnonymousScanOperator::new(
+            schema.into(),
+            FileType::Csv,
+            vec![path.to_str().unwrap().to_string()],
+            true,
+        )?);
+        let scan_tasks = operator
+            .select(&["sepal.length", "year"])?
+            .limit(5)?
+            .to_scan_tasks()?
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mp =
+            MicroPartition::from_scan_task(&scan_tasks[0], IOConfig::default().into(), true, None);
+        std::fs::remove_dir_all(dir.parent().unwrap().parent().unwrap())?;
+        let mp = mp?;
+
+        assert_eq!(mp.len(), 5);
+        assert_eq!(mp.column_names(), vec!["sepal.length", "year"]);
+        let tables = mp.concat_or_get()?;
+        let year = tables[0].get_column("year")?;
+        assert_eq!(year.data_type(), &DataType::Int64);
+        assert_eq!(year.i64()?.as_arrow().values().as_slice(), &[2023; 5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_scan_task_json_not_implemented() -> DaftResult<()> {
+        let scan_tasks = scan_tasks(FileType::Json, vec!["part-0.jsonl".to_string()])?;
+        let err =
+            MicroPartition::from_scan_task(&scan_tasks[0], IOConfig::default().into(), true, None);
+        assert!(
+            matches!(err, Err(DaftError::ValueError(msg)) if msg.contains("not yet implemented"))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+use std::{f64::consts::PI, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{Field, Float64Array},
+    schema::Schema,
+    DataType, IntoSeries, Series,
+};
+use daft_table::Table;
+
+use crate::micropartition::{MicroPartition, TableState};
+
+use daft_stats::TableMetadata;
+
+impl MicroPartition {
+    /// Approximates the `num`-quantiles of every column, i.e. the values at the quantiles `1/num, ..., (num-1)/num`.
+    ///
+    /// Unlike [`MicroPartition::quantiles`], this doesn't require the MicroPartition to be sorted: each internal table
+    /// is summarized into a mergeable t-digest, and the quantiles are read off of the merged digest. `epsilon` sets the
+    /// compression of the digests, which keep on the order of `1 / epsilon` centroids each: smaller values trade memory
+    /// for accuracy, but it is not a bound on the rank error of the returned quantiles.
+    ///
+    /// All columns must be numeric, and are returned as Float64 columns. Columns without any non-null, non-NaN values
+    /// have null quantiles.
+    pub fn approx_quantiles(&self, num: usize, epsilon: f64) -> DaftResult<Self> {
+        if !(epsilon > 0.0 && epsilon < 1.0) {
+            return Err(DaftError::ValueError(format!(
+                "epsilon for approximate quantiles must be in (0, 1), but got {epsilon}"
+            )));
+        }
+        if let Some(field) = self.schema.fields.values().find(|f| !f.dtype.is_numeric()) {
+            return Err(DaftError::TypeError(format!(
+                "Approximate quantiles can only be computed for numeric columns, but {} has type {}",
+                field.name, field.dtype
+            )));
+        }
+        let compression = (1.0 / epsilon).ceil();
+        let tables = self.tables_or_read(None)?;
+
+        let columns = self
+            .schema
+            .fields
+            .keys()
+            .map(|name| {
+                let digest = tables
+                    .iter()
+                    .map(|t| TDigest::from_series(t.get_column(name)?, compression))
+                    .collect::<DaftResult<Vec<_>>>()?
+                    .into_iter()
+                    .fold(TDigest::new(compression), |acc, d| acc.merge(&d));
+                let quantiles = (1..num)
+                    .map(|i| (!digest.is_empty()).then(|| digest.quantile(i as f64 / num as f64)))
+                    .collect::<Vec<_>>();
+                Ok(Float64Array::from_iter(name, quantiles.into_iter()).into_series())
+            })
+            .collect::<DaftResult<Vec<Series>>>()?;
+
+        let schema = Arc::new(Schema::new(
+            columns
+                .iter()
+                .map(|s| Field::new(s.name(), DataType::Float64))
+                .collect(),
+        )?);
+        let table = Table::new(schema.clone(), columns)?;
+        let len = table.len();
+        Ok(Self::new(
+            schema,
+            TableState::Loaded(Arc::new(vec![table])),
+            TableMetadata { length: len },
+            None,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest (Dunning & Ertl), a mergeable sketch of a distribution that is most accurate at the tails.
+///
+/// `compression` bounds the number of centroids kept, at roughly `compression` centroids.
+#[derive(Clone, Debug)]
+struct TDigest {
+    centroids: Vec<Centroid>,
+    compression: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    fn new(compression: f64) -> Self {
+        Self {
+            centroids: vec![],
+            compression,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Summarizes the non-null, non-NaN values of a numeric series.
+    fn from_series(series: &Series, compression: f64) -> DaftResult<Self> {
+        let series = series.cast(&DataType::Float64)?;
+        let centroids = series
+            .f64()?
+            .as_arrow()
+            .iter()
+            .flatten()
+            .filter(|v| !v.is_nan())
+            .map(|v| Centroid {
+                mean: *v,
+                weight: 1.0,
+            })
+            .collect();
+        Ok(Self::new(compression).with_centroids(centroids))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.centroids.is_empty()
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let centroids = self
+            .centroids
+            .iter()
+            .chain(other.centroids.iter())
+            .copied()
+            .collect();
+        Self::new(self.compression.max(other.compression)).with_centroids(centroids)
+    }
+
+    /// Sets the digest to a compressed form of the provided centroids.
+    fn with_centroids(mut self, mut centroids: Vec<Centroid>) -> Self {
+        if centroids.is_empty() {
+            return self;
+        }
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        self.min = self.min.min(centroids.first().unwrap().mean);
+        self.max = self.max.max(centroids.last().unwrap().mean);
+
+        let total_weight = centroids.iter().map(|c| c.weight).sum::<f64>();
+        let mut compressed = Vec::with_capacity(self.compression as usize);
+        let mut current = centroids[0];
+        let mut weight_so_far = 0.0;
+        let mut k_lower = self.k_scale(0.0);
+        for centroid in centroids.into_iter().skip(1) {
+            let q = (weight_so_far + current.weight + centroid.weight) / total_weight;
+            if self.k_scale(q) - k_lower <= 1.0 {
+                // Absorb the centroid while the merged centroid stays within the size bound of the scale function.
+                let weight = current.weight + centroid.weight;
+                current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                k_lower = self.k_scale(weight_so_far / total_weight);
+                compressed.push(current);
+                current = centroid;
+            }
+        }
+        compressed.push(current);
+        self.centroids = compressed;
+        self
+    }
+
+    /// The k1 scale function, which keeps centroids small near the tails and large near the median.
+    fn k_scale(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).clamp(-1.0, 1.0).asin()
+    }
+
+    /// Estimates the value at quantile `q`, interpolating between the centers of adjacent centroids.
+    fn quantile(&self, q: f64) -> f64 {
+        if let [single] = self.centroids.as_slice() {
+            return single.mean;
+        }
+        let total_weight = self.centroids.iter().map(|c| c.weight).sum::<f64>();
+        let target = q.clamp(0.0, 1.0) * total_weight;
+
+        // (cumulative weight, value) of the previous interpolation point, starting at the minimum.
+        let mut prev = (0.0, self.min);
+        let mut weight_so_far = 0.0;
+        for centroid in self.centroids.iter() {
+            let center = weight_so_far + centroid.weight / 2.0;
+            if target <= center {
+                return interpolate(prev, (center, centroid.mean), target);
+            }
+            prev = (center, centroid.mean);
+            weight_so_far += centroid.weight;
+        }
+        interpolate(prev, (total_weight, self.max), target)
+    }
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int64Array},
+        IntoSeries,
+    };
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    #[test]
+    fn test_approx_quantiles_within_tolerance() -> DaftResult<()> {
+        // 1..=10000 split across two internal tables, with the second half first so the input isn't sorted.
+        let t1 = Table::from_columns(vec![Int64Array::from((
+            "a",
+            (5001..=10000).collect::<Vec<i64>>(),
+        ))
+        .into_series()])?;
+        let t2 = Table::from_columns(vec![Int64Array::from((
+            "a",
+            (1..=5000).collect::<Vec<i64>>(),
+        ))
+        .into_series()])?;
+        let mp = MicroPartition::new(
+            t1.schema.clone(),
+            TableState::Loaded(Arc::new(vec![t1, t2])),
+            TableMetadata { length: 10000 },
+            None,
+        );
+
+        let epsilon = 0.01;
+        let quantiles = mp.approx_quantiles(10, epsilon)?;
+        assert_eq!(quantiles.len(), 9);
+        let tables = quantiles.concat_or_get()?;
+        let column = tables.first().unwrap().get_column("a")?;
+        for (i, value) in column.f64()?.as_arrow().values().iter().enumerate() {
+            let exact = (i + 1) as f64 * 1000.0;
+            assert!(
+                (value - exact).abs() <= epsilon * 10000.0,
+                "approximate quantile {} is {} but exact is {}",
+                i + 1,
+                value,
+                exact
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_approx_quantiles_all_null() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from_iter("a", vec![None, None, None].into_iter()).into_series(),
+            Float64Array::from_iter("b", vec![Some(f64::NAN), None, Some(f64::NAN)].into_iter())
+                .into_series(),
+            Int64Array::from(("c", vec![1, 2, 3])).into_series(),
+        ])?;
+        let mp = MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table])),
+            TableMetadata { length: 3 },
+            None,
+        );
+
+        let quantiles = mp.approx_quantiles(4, 0.01)?;
+        assert_eq!(quantiles.len(), 3);
+        let tables = quantiles.concat_or_get()?;
+        let table = tables.first().unwrap();
+        for name in ["a", "b"] {
+            let column = table.get_column(name)?;
+            assert_eq!(column.len(), 3);
+            assert_eq!(column.f64()?.as_arrow().null_count(), 3);
+        }
+        assert_eq!(table.get_column("c")?.f64()?.as_arrow().null_count(), 0);
+
+        Ok(())
+    }
+}
@@ -1,43 +1,142 @@
 use std::{ops::Deref, sync::Arc};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::schema::SchemaRef;
 
 use crate::micropartition::{MicroPartition, TableState};
 
-use daft_stats::TableStatistics;
+use daft_stats::{TableMetadata, TableStatistics};
 
 impl MicroPartition {
+    /// Casts this MicroPartition to `schema`, selecting and reordering its columns to match the field order of
+    /// `schema` by name, and casting each column to the type of its target field.
+    ///
+    /// Errors if a field of `schema` is missing from this MicroPartition.
     pub fn cast_to_schema(&self, schema: SchemaRef) -> DaftResult<Self> {
-        let pruned_statistics = self.statistics.clone().map(|stats| TableStatistics {
-            columns: stats
-                .columns
-                .into_iter()
-                .filter(|(key, _)| schema.names().contains(key))
-                .collect(),
+        if let Some(missing) = schema
+            .names()
+            .into_iter()
+            .find(|name| !self.schema.fields.contains_key(name))
+        {
+            return Err(DaftError::FieldNotFound(format!(
+                "Column {missing} of the target schema not found in MicroPartition with columns: {:?}",
+                self.schema.names()
+            )));
+        }
+
+        let pruned_statistics = self.statistics.as_ref().map(|stats| {
+            TableStatistics::new(
+                schema
+                    .names()
+                    .into_iter()
+                    .filter_map(|name| {
+                        let column_stats = stats.columns.get(&name)?.clone();
+                        Some((name, column_stats))
+                    })
+                    .collect(),
+            )
+            .with_counts_from(stats)
         });
 
-        let guard = self.state.lock().unwrap();
-        match guard.deref() {
-            // Replace schema if Unloaded, which should be applied when data is lazily loaded
-            TableState::Unloaded(params) => Ok(MicroPartition::new(
-                schema.clone(),
-                TableState::Unloaded(params.clone()),
-                self.metadata.clone(),
-                pruned_statistics,
-            )),
-            // If Tables are already loaded, we map `Table::cast_to_schema` on each Table
-            TableState::Loaded(tables) => Ok(MicroPartition::new(
-                schema.clone(),
-                TableState::Loaded(Arc::new(
-                    tables
-                        .iter()
-                        .map(|tbl| tbl.cast_to_schema(schema.as_ref()))
-                        .collect::<DaftResult<Vec<_>>>()?,
-                )),
-                self.metadata.clone(),
-                pruned_statistics,
-            )),
+        {
+            let guard = self.state.lock().unwrap();
+            // Replace schema if Unloaded, which should be applied when data is lazily loaded, and CSV reads parse their
+            // columns as the target types directly. Filters pushed into the read apply to the data after it's cast to
+            // the current schema though, so then the data is loaded first.
+            if let TableState::Unloaded(params) = guard.deref()
+                && !params.has_filters()
+            {
+                return Ok(MicroPartition::new(
+                    schema.clone(),
+                    TableState::Unloaded(params.with_cast_to_schema(&schema)?),
+                    self.metadata.clone(),
+                    pruned_statistics,
+                ));
+            }
         }
+        // If Tables are loaded, we map `Table::cast_to_schema` on each Table
+        let tables = self.tables_or_read(None)?;
+        Ok(MicroPartition::new(
+            schema.clone(),
+            TableState::Loaded(Arc::new(
+                tables
+                    .iter()
+                    .map(|tbl| tbl.cast_to_schema(schema.as_ref()))
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )),
+            TableMetadata { length: self.len() },
+            pruned_statistics,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Field, Int32Array, Utf8Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    fn reordered_partition() -> DaftResult<MicroPartition> {
+        let table = Table::from_columns(vec![
+            Utf8Array::from(("name", ["a", "b"].as_slice())).into_series(),
+            Int32Array::from(("id", vec![1, 2])).into_series(),
+            Int32Array::from(("extra", vec![3, 4])).into_series(),
+        ])?;
+        Ok(MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table])),
+            TableMetadata { length: 2 },
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_cast_to_schema_reorders_columns() -> DaftResult<()> {
+        let mp = reordered_partition()?;
+        let target = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+        ])?);
+
+        let casted = mp.cast_to_schema(target.clone())?;
+        assert_eq!(casted.schema, target);
+        let tables = casted.concat_or_get()?;
+        let table = tables.first().unwrap();
+        assert_eq!(table.schema, target);
+        assert_eq!(
+            table
+                .get_column("id")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[1, 2]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_to_schema_missing_column_errors() -> DaftResult<()> {
+        let mp = reordered_partition()?;
+        let target = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("missing", DataType::Utf8),
+        ])?);
+
+        let err = mp.cast_to_schema(target);
+        assert!(matches!(err, Err(DaftError::FieldNotFound(_))));
+
+        Ok(())
     }
 }
@@ -32,17 +32,19 @@ impl MicroPartition {
             let tables = m.tables_or_read(None)?;
             all_tables.extend_from_slice(tables.as_slice());
         }
-        let mut all_stats = None;
-
-        for stats in mps.iter().flat_map(|m| &m.statistics) {
-            if all_stats.is_none() {
-                all_stats = Some(stats.clone());
-            }
-
-            if let Some(curr_stats) = &all_stats {
-                all_stats = Some(curr_stats.union(stats)?);
+        // The statistics of the result only cover all of its rows if every MicroPartition has statistics.
+        let all_stats = match mps
+            .iter()
+            .map(|m| m.statistics.as_ref())
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(stats) => {
+                let mut stats = stats.into_iter();
+                let first = stats.next().unwrap().clone();
+                Some(stats.try_fold(first, |acc, s| acc.union(s))?)
             }
-        }
+            None => None,
+        };
         let new_len = all_tables.iter().map(|t| t.len()).sum();
 
         Ok(MicroPartition {
@@ -53,3 +55,79 @@ impl MicroPartition {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Int64Array, Utf8Array},
+        IntoSeries,
+    };
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    fn loaded(table: Table) -> MicroPartition {
+        MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table.clone()])),
+            TableMetadata {
+                length: table.len(),
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn test_concat_loaded() -> DaftResult<()> {
+        let mp1 = loaded(Table::from_columns(vec![Int64Array::from((
+            "a",
+            vec![1, 2, 3],
+        ))
+        .into_series()])?);
+        let mp2 = loaded(Table::from_columns(vec![Int64Array::from((
+            "a",
+            vec![4, 5],
+        ))
+        .into_series()])?);
+
+        let concatted = MicroPartition::concat(&[&mp1, &mp2])?;
+        assert_eq!(concatted.len(), 5);
+        assert_eq!(concatted.schema, mp1.schema);
+        let tables = concatted.concat_or_get()?;
+        assert_eq!(
+            tables[0]
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[1, 2, 3, 4, 5]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_schema_mismatch() -> DaftResult<()> {
+        let mp1 = loaded(Table::from_columns(vec![Int64Array::from((
+            "a",
+            vec![1, 2, 3],
+        ))
+        .into_series()])?);
+        let mp2 = loaded(Table::from_columns(vec![Utf8Array::from((
+            "a",
+            ["x", "y"].as_slice(),
+        ))
+        .into_series()])?);
+
+        let result = MicroPartition::concat(&[&mp1, &mp2]);
+        assert!(matches!(result, Err(DaftError::SchemaMismatch(_))));
+
+        Ok(())
+    }
+}
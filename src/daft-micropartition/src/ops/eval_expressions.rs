@@ -17,8 +17,11 @@ use daft_stats::TableMetadata;
 fn infer_schema(exprs: &[Expr], schema: &Schema) -> DaftResult<Schema> {
     let fields = exprs
         .iter()
-        .map(|e| e.to_field(schema).context(DaftCoreComputeSnafu))
-        .collect::<crate::Result<Vec<_>>>()?;
+        .map(|e| e.to_projected_fields(schema).context(DaftCoreComputeSnafu))
+        .collect::<crate::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
     let mut seen: HashSet<String> = HashSet::new();
     for field in fields.iter() {
@@ -42,9 +45,11 @@ impl MicroPartition {
             .map(|t| t.eval_expression_list(exprs))
             .collect::<DaftResult<Vec<_>>>()?;
 
+        // Statistics are evaluated per expression, so they can't be mapped to the columns unpacked from struct outputs.
         let eval_stats = self
             .statistics
             .as_ref()
+            .filter(|_| !exprs.iter().any(Expr::has_struct_output))
             .map(|s| s.eval_expression_list(exprs, &expected_schema))
             .transpose()?;
 
@@ -72,7 +77,7 @@ impl MicroPartition {
                     new_stats.insert(name.to_string(), ColumnRangeStatistics::Missing);
                 }
             }
-            Some(TableStatistics { columns: new_stats })
+            Some(TableStatistics::new(new_stats))
         } else {
             None
         };
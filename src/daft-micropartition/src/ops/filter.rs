@@ -1,3 +1,5 @@
+use std::ops::Deref;
+
 use common_error::DaftResult;
 use daft_dsl::Expr;
 use snafu::ResultExt;
@@ -16,12 +18,12 @@ impl MicroPartition {
         if predicate.is_empty() {
             return Ok(Self::empty(Some(self.schema.clone())));
         }
+        let folded_expr = predicate
+            .iter()
+            .cloned()
+            .reduce(|a, b| a.and(&b))
+            .expect("should have at least 1 expr");
         if let Some(statistics) = &self.statistics {
-            let folded_expr = predicate
-                .iter()
-                .cloned()
-                .reduce(|a, b| a.and(&b))
-                .expect("should have at least 1 expr");
             let eval_result = statistics.eval_expression(&folded_expr)?;
             let tv = eval_result.to_truth_value();
 
@@ -29,6 +31,25 @@ impl MicroPartition {
                 return Ok(Self::empty(Some(self.schema.clone())));
             }
         }
+        let statistics = self
+            .statistics
+            .as_ref()
+            .map(|statistics| statistics.apply_filter(&folded_expr))
+            .transpose()?;
+
+        // An unloaded MicroPartition stays unloaded, with the predicate pushed into its deferred read, so its length
+        // becomes an upper bound until it's loaded.
+        {
+            let guard = self.state.lock().unwrap();
+            if let TableState::Unloaded(params) = guard.deref() {
+                return Ok(Self::new(
+                    self.schema.clone(),
+                    TableState::Unloaded(params.with_filters(predicate)),
+                    self.metadata.clone(),
+                    statistics,
+                ));
+            }
+        }
         // TODO figure out defered IOStats
         let tables = self
             .tables_or_read(None)?
@@ -43,7 +64,7 @@ impl MicroPartition {
             self.schema.clone(),
             TableState::Loaded(tables.into()),
             TableMetadata { length: new_len },
-            self.statistics.clone(), // update these values based off the filter we just ran
+            statistics,
         ))
     }
 }
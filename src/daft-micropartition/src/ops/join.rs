@@ -54,4 +54,103 @@ impl MicroPartition {
             _ => unreachable!(),
         }
     }
+
+    /// Joins this MicroPartition with `right` on an arbitrary boolean `predicate`, such as a range condition.
+    ///
+    /// See [`Table::join_with_predicate`](daft_table::Table::join_with_predicate) for how the predicate refers
+    /// to columns and how `max_output_rows` guards against explosive output.
+    pub fn join_with_predicate(
+        &self,
+        right: &Self,
+        predicate: &Expr,
+        max_output_rows: Option<usize>,
+    ) -> DaftResult<Self> {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, &[], &[])?;
+
+        let lt = self.concat_or_get()?;
+        let rt = right.concat_or_get()?;
+
+        match (lt.as_slice(), rt.as_slice()) {
+            ([], _) | (_, []) => Ok(Self::empty(Some(join_schema.into()))),
+            ([lt], [rt]) => {
+                let joined_table = lt.join_with_predicate(rt, predicate, max_output_rows)?;
+                let joined_len = joined_table.len();
+                Ok(MicroPartition::new(
+                    join_schema.into(),
+                    TableState::Loaded(vec![joined_table].into()),
+                    TableMetadata { length: joined_len },
+                    None,
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+    use daft_dsl::col;
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    fn loaded(table: Table) -> MicroPartition {
+        let len = table.len();
+        MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table])),
+            TableMetadata { length: len },
+            None,
+        )
+    }
+
+    #[test]
+    fn test_join_with_predicate_interval() -> DaftResult<()> {
+        let events = loaded(Table::from_columns(vec![
+            Int64Array::from(("event", vec![0, 1, 2, 3])).into_series(),
+            Int64Array::from(("ts", vec![5, 12, 15, 40])).into_series(),
+        ])?);
+        let windows = loaded(Table::from_columns(vec![
+            Int64Array::from(("window", vec![0, 1, 2])).into_series(),
+            Int64Array::from(("start", vec![0, 10, 14])).into_series(),
+            Int64Array::from(("end", vec![9, 19, 20])).into_series(),
+        ])?);
+
+        // Match each event to every window whose [start, end] range contains its timestamp.
+        let predicate = col("ts")
+            .gt_eq(&col("start"))
+            .and(&col("ts").lt_eq(&col("end")));
+        let joined = events.join_with_predicate(&windows, &predicate, None)?;
+        assert_eq!(joined.len(), 4);
+        let tables = joined.concat_or_get()?;
+        let table = tables.first().unwrap();
+        let pairs = table
+            .get_column("event")?
+            .i64()?
+            .as_arrow()
+            .values()
+            .iter()
+            .zip(
+                table
+                    .get_column("window")?
+                    .i64()?
+                    .as_arrow()
+                    .values()
+                    .iter(),
+            )
+            .map(|(e, w)| (*e, *w))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(0, 0), (1, 1), (2, 1), (2, 2)]);
+
+        // Exceeding the output cap fails instead of producing the full result.
+        let err = events.join_with_predicate(&windows, &predicate, Some(3));
+        assert!(matches!(err, Err(DaftError::ComputeError(_))));
+
+        Ok(())
+    }
 }
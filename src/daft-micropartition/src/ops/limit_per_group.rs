@@ -0,0 +1,134 @@
+use std::{collections::BinaryHeap, sync::Arc};
+
+use common_error::DaftResult;
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, IntoGroups},
+    datatypes::UInt64Array,
+    IntoSeries,
+};
+use daft_dsl::Expr;
+use daft_table::Table;
+
+use crate::micropartition::{MicroPartition, TableState};
+
+use daft_stats::{TableMetadata, TableStatistics};
+
+impl MicroPartition {
+    /// Keeps at most `n` rows for each group defined by `group_by`, choosing the first `n` rows of each group
+    /// when ordered by `sort_keys`.
+    ///
+    /// The rows of each group are returned contiguously and in sort order, with groups ordered by the position of
+    /// their first row in this MicroPartition.
+    pub fn limit_per_group(
+        &self,
+        group_by: &[Expr],
+        n: usize,
+        sort_keys: &[Expr],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        if n == 0 {
+            return Ok(Self::empty(Some(self.schema.clone())));
+        }
+        let tables = self.concat_or_get()?;
+        match tables.as_slice() {
+            [] => Ok(Self::empty(Some(self.schema.clone()))),
+            [single] => {
+                let limited = limit_table_per_group(single, group_by, n, sort_keys, descending)?;
+                let new_len = limited.len();
+                Ok(Self::new(
+                    self.schema.clone(),
+                    TableState::Loaded(Arc::new(vec![limited])),
+                    TableMetadata { length: new_len },
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn limit_table_per_group(
+    table: &Table,
+    group_by: &[Expr],
+    n: usize,
+    sort_keys: &[Expr],
+    descending: &[bool],
+) -> DaftResult<Table> {
+    // Position of each row in the requested sort order.
+    let argsort = table.argsort(sort_keys, descending)?;
+    let mut ranks = vec![0usize; table.len()];
+    for (rank, idx) in argsort.u64()?.as_arrow().values().iter().enumerate() {
+        ranks[*idx as usize] = rank;
+    }
+    let groupby_table = table.eval_expression_list(group_by)?;
+    let (_, groups) = groupby_table.make_groups()?;
+    // Keep the n best ranked rows of each group with a bounded max-heap, evicting the worst ranked row on overflow.
+    let mut limited_groups = groups
+        .into_iter()
+        .filter_map(|group| {
+            let first_idx = *group.first()?;
+            let mut heap = BinaryHeap::with_capacity(n + 1);
+            for idx in group {
+                heap.push((ranks[idx as usize], idx));
+                if heap.len() > n {
+                    heap.pop();
+                }
+            }
+            Some((first_idx, heap.into_sorted_vec()))
+        })
+        .collect::<Vec<_>>();
+    limited_groups.sort_unstable_by_key(|(first_idx, _)| *first_idx);
+    let indices = limited_groups
+        .into_iter()
+        .flat_map(|(_, group)| group.into_iter().map(|(_, idx)| idx))
+        .collect::<Vec<u64>>();
+    table.take(&UInt64Array::from(("idx", indices)).into_series())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+    use daft_dsl::col;
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    #[test]
+    fn test_limit_per_group_top_2_descending() -> DaftResult<()> {
+        let t1 = Table::from_columns(vec![
+            Int64Array::from(("k", vec![1, 2, 1, 2, 1])).into_series(),
+            Int64Array::from(("v", vec![10, 20, 30, 40, 50])).into_series(),
+        ])?;
+        let t2 = Table::from_columns(vec![
+            Int64Array::from(("k", vec![2, 1])).into_series(),
+            Int64Array::from(("v", vec![60, 5])).into_series(),
+        ])?;
+        let mp = MicroPartition::new(
+            t1.schema.clone(),
+            TableState::Loaded(Arc::new(vec![t1, t2])),
+            TableMetadata { length: 7 },
+            None,
+        );
+
+        let limited = mp.limit_per_group(&[col("k")], 2, &[col("v")], &[true])?;
+        assert_eq!(limited.len(), 4);
+        let tables = limited.concat_or_get()?;
+        let table = tables.first().unwrap();
+        assert_eq!(
+            table.get_column("k")?.i64()?.as_arrow().values().as_slice(),
+            &[1, 1, 2, 2]
+        );
+        assert_eq!(
+            table.get_column("v")?.i64()?.as_arrow().values().as_slice(),
+            &[50, 30, 60, 40]
+        );
+
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 mod agg;
+mod approx_quantiles;
 mod cast_to_schema;
 mod concat;
 mod eval_expressions;
 mod filter;
 mod join;
+mod limit_per_group;
 mod partition;
+mod select;
 mod slice;
 mod sort;
 mod take;
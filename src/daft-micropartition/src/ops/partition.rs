@@ -1,12 +1,12 @@
-use std::sync::Arc;
+use std::{ops::Deref, sync::Arc};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_dsl::Expr;
 use daft_table::Table;
 
 use crate::micropartition::{MicroPartition, TableState};
 
-use daft_stats::TableMetadata;
+use daft_stats::{TableMetadata, TableStatistics};
 
 fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
     if v.is_empty() {
@@ -38,7 +38,9 @@ impl MicroPartition {
                     self.schema.clone(),
                     TableState::Loaded(Arc::new(v)),
                     TableMetadata { length: new_len },
-                    self.statistics.clone(),
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
                 )
             })
             .collect())
@@ -108,4 +110,135 @@ impl MicroPartition {
             .collect::<DaftResult<Vec<_>>>()?;
         self.vec_part_tables_to_mps(part_tables)
     }
+
+    /// Splits this MicroPartition into chunks of roughly `target_bytes` each, e.g. to balance the inputs of
+    /// downstream tasks. The rows are split evenly between the chunks, so chunks are only equally sized if the rows
+    /// are of similar size.
+    ///
+    /// A MicroPartition of at most `target_bytes` is returned as-is, without being read if it's unloaded, as decided
+    /// by its estimated size. An empty MicroPartition has no chunks.
+    pub fn split_by_size(&self, target_bytes: usize) -> DaftResult<Vec<Self>> {
+        if target_bytes == 0 {
+            return Err(DaftError::ValueError(
+                "Can not split MicroPartition into chunks of 0 bytes".to_string(),
+            ));
+        }
+        if self.len() == 0 {
+            return Ok(vec![]);
+        }
+        if self.size_bytes()? <= target_bytes {
+            let state = match self.state.lock().unwrap().deref() {
+                TableState::Unloaded(params) => TableState::Unloaded(params.clone()),
+                TableState::Loaded(tables) => TableState::Loaded(tables.clone()),
+            };
+            return Ok(vec![Self::new(
+                self.schema.clone(),
+                state,
+                self.metadata.clone(),
+                self.statistics.clone(),
+            )]);
+        }
+
+        // The length of an unloaded MicroPartition may only be an upper bound, so split by the rows that are read.
+        let len = self
+            .tables_or_read(None)?
+            .iter()
+            .map(|t| t.len())
+            .sum::<usize>();
+        if len == 0 {
+            return Ok(vec![]);
+        }
+        let num_chunks = self.size_bytes()?.div_ceil(target_bytes).clamp(1, len);
+        let rows_per_chunk = len.div_ceil(num_chunks);
+        (0..len)
+            .step_by(rows_per_chunk)
+            .map(|start| self.slice(start, (start + rows_per_chunk).min(len)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    fn int_micropartition(ranges: &[std::ops::Range<i64>]) -> DaftResult<MicroPartition> {
+        let tables = ranges
+            .iter()
+            .map(|range| {
+                Table::from_columns(vec![Int64Array::from((
+                    "a",
+                    range.clone().collect::<Vec<i64>>(),
+                ))
+                .into_series()])
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let length = tables.iter().map(|t| t.len()).sum();
+        Ok(MicroPartition::new(
+            tables[0].schema.clone(),
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length },
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_split_by_size_balances_chunks() -> DaftResult<()> {
+        let mp = int_micropartition(&[0..30, 30..100])?;
+        let size_bytes = mp.size_bytes()?;
+
+        let chunks = mp.split_by_size(size_bytes / 4)?;
+        assert_eq!(chunks.len(), 4);
+        for chunk in &chunks {
+            assert_eq!(chunk.len(), 25);
+            assert_eq!(chunk.metadata.length, 25);
+            assert!(chunk.size_bytes()? <= size_bytes / 4);
+        }
+        let values = chunks
+            .iter()
+            .map(|chunk| chunk.concat_or_get())
+            .collect::<crate::Result<Vec<_>>>()?
+            .iter()
+            .flat_map(|tables| {
+                tables[0]
+                    .get_column("a")
+                    .unwrap()
+                    .i64()
+                    .unwrap()
+                    .as_arrow()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_by_size_smaller_than_target() -> DaftResult<()> {
+        let mp = int_micropartition(&[0..10])?;
+
+        let chunks = mp.split_by_size(mp.size_bytes()?)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 10);
+        assert_eq!(chunks[0].size_bytes()?, mp.size_bytes()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_by_size_empty() -> DaftResult<()> {
+        let mp = MicroPartition::empty(None);
+
+        assert!(mp.split_by_size(1024)?.is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,104 @@
+use std::{ops::Deref, sync::Arc};
+
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_stats::{ColumnRangeStatistics, TableMetadata, TableStatistics};
+
+use crate::micropartition::{MicroPartition, TableState};
+
+impl MicroPartition {
+    /// Projects this MicroPartition to `columns`, in that order. An unloaded MicroPartition stays unloaded, with the
+    /// projection pushed into its deferred read so that only those columns are read once it's loaded, unless filters
+    /// were pushed into the read, which may need other columns.
+    pub fn select(&self, columns: &[&str]) -> DaftResult<Self> {
+        let fields = columns
+            .iter()
+            .map(|name| self.schema.get_field(name).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        let schema = Arc::new(Schema::new(fields)?);
+        let statistics = self.statistics.as_ref().map(|stats| {
+            TableStatistics::new(
+                columns
+                    .iter()
+                    .map(|name| {
+                        let column_stats = stats
+                            .columns
+                            .get(*name)
+                            .cloned()
+                            .unwrap_or(ColumnRangeStatistics::Missing);
+                        (name.to_string(), column_stats)
+                    })
+                    .collect(),
+            )
+            .with_counts_from(stats)
+        });
+
+        {
+            let guard = self.state.lock().unwrap();
+            if let TableState::Unloaded(params) = guard.deref()
+                && !params.has_filters()
+            {
+                return Ok(Self::new(
+                    schema,
+                    TableState::Unloaded(params.with_columns(columns)),
+                    self.metadata.clone(),
+                    statistics,
+                ));
+            }
+        }
+        let tables = self
+            .tables_or_read(None)?
+            .iter()
+            .map(|t| t.get_columns(columns))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::new(
+            schema,
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length: self.len() },
+            statistics,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{
+        datatypes::{Int64Array, Utf8Array},
+        IntoSeries,
+    };
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    #[test]
+    fn test_select_loaded() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Utf8Array::from(("b", ["x", "y", "z"].as_slice())).into_series(),
+            Int64Array::from(("c", vec![4, 5, 6])).into_series(),
+        ])?;
+        let mp = MicroPartition::new(
+            table.schema.clone(),
+            TableState::Loaded(Arc::new(vec![table])),
+            TableMetadata { length: 3 },
+            None,
+        );
+
+        let selected = mp.select(&["c", "a"])?;
+        assert_eq!(selected.column_names(), vec!["c", "a"]);
+        assert_eq!(selected.len(), 3);
+        let tables = selected.concat_or_get()?;
+        assert_eq!(tables[0].column_names(), vec!["c", "a"]);
+
+        assert!(matches!(
+            mp.select(&["d"]),
+            Err(DaftError::FieldNotFound(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -1,50 +1,186 @@
-use common_error::DaftResult;
+use std::ops::Deref;
+
+use common_error::{DaftError, DaftResult};
 
 use crate::micropartition::{MicroPartition, TableState};
 
-use daft_stats::TableMetadata;
+use daft_stats::{TableMetadata, TableStatistics};
 
 impl MicroPartition {
+    /// Returns the rows in `[start, end)`, only slicing the tables that overlap that range, so that the rows of other
+    /// tables aren't copied. An unloaded MicroPartition is read first.
+    ///
+    /// Errors unless `start <= end <= self.len()`.
     pub fn slice(&self, start: usize, end: usize) -> DaftResult<Self> {
         let tables = self.tables_or_read(None)?;
-        let mut slices_tables = vec![];
-        let mut rows_needed = (end - start).max(0);
-        let mut offset_so_far = start;
+        let len = tables.iter().map(|t| t.len()).sum::<usize>();
+        if start > end || end > len {
+            return Err(DaftError::ValueError(format!(
+                "Slice [{start}, {end}) is out of range for a MicroPartition of {len} rows"
+            )));
+        }
 
+        let mut sliced_tables = vec![];
+        let mut table_start = 0;
         for tab in tables.iter() {
-            if rows_needed == 0 {
+            if table_start >= end {
                 break;
             }
-
-            let tab_rows = tab.len();
-            if offset_so_far > 0 && offset_so_far >= tab_rows {
-                offset_so_far -= tab_rows;
-                continue;
-            }
-
-            if offset_so_far == 0 && rows_needed >= tab_rows {
-                slices_tables.push(tab.clone());
-                rows_needed += tab_rows;
-            } else {
-                let new_end = (rows_needed + offset_so_far).min(tab_rows);
-                let sliced = tab.slice(offset_so_far, new_end)?;
-                offset_so_far = 0;
-                rows_needed -= sliced.len();
-                slices_tables.push(sliced);
+            let table_end = table_start + tab.len();
+            let overlap_start = start.max(table_start);
+            let overlap_end = end.min(table_end);
+            if overlap_start == table_start && overlap_end == table_end {
+                sliced_tables.push(tab.clone());
+            } else if overlap_start < overlap_end {
+                sliced_tables
+                    .push(tab.slice(overlap_start - table_start, overlap_end - table_start)?);
             }
+            table_start = table_end;
         }
 
-        let new_len = slices_tables.iter().map(|t| t.len()).sum();
-
         Ok(MicroPartition {
             schema: self.schema.clone(),
-            state: TableState::Loaded(slices_tables.into()).into(),
-            metadata: TableMetadata { length: new_len },
-            statistics: self.statistics.clone(),
+            state: TableState::Loaded(sliced_tables.into()).into(),
+            metadata: TableMetadata {
+                length: end - start,
+            },
+            statistics: self
+                .statistics
+                .as_ref()
+                .map(TableStatistics::without_counts),
         })
     }
 
+    /// Returns the first `num` rows. An unloaded MicroPartition stays unloaded, with the limit pushed into its deferred
+    /// read so that only those rows are read once it's loaded, unless filters were pushed into the read, which have to
+    /// apply before the limit.
     pub fn head(&self, num: usize) -> DaftResult<Self> {
-        self.slice(0, num)
+        {
+            let guard = self.state.lock().unwrap();
+            if let TableState::Unloaded(params) = guard.deref()
+                && !params.has_filters()
+            {
+                return Ok(Self::new(
+                    self.schema.clone(),
+                    TableState::Unloaded(params.with_limit(num)),
+                    TableMetadata {
+                        length: self.metadata.length.min(num),
+                    },
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
+                ));
+            }
+        }
+        let len = self
+            .tables_or_read(None)?
+            .iter()
+            .map(|t| t.len())
+            .sum::<usize>();
+        self.slice(0, num.min(len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    fn two_table_micropartition() -> DaftResult<MicroPartition> {
+        let tables = [0..4, 4..10]
+            .into_iter()
+            .map(|range| {
+                Table::from_columns(vec![
+                    Int64Array::from(("a", range.collect::<Vec<i64>>())).into_series()
+                ])
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(MicroPartition::new(
+            tables[0].schema.clone(),
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length: 10 },
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_slice_across_table_boundary() -> DaftResult<()> {
+        let mp = two_table_micropartition()?;
+
+        for (start, end) in [(2, 7), (0, 4), (4, 10), (3, 3), (0, 10)] {
+            let sliced = mp.slice(start, end)?;
+            assert_eq!(sliced.len(), end - start);
+            let values = sliced
+                .tables_or_read(None)?
+                .iter()
+                .flat_map(|t| {
+                    t.get_column("a")
+                        .unwrap()
+                        .i64()
+                        .unwrap()
+                        .as_arrow()
+                        .values()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(values, (start as i64..end as i64).collect::<Vec<_>>());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_out_of_range() -> DaftResult<()> {
+        let mp = two_table_micropartition()?;
+
+        assert!(matches!(mp.slice(5, 11), Err(DaftError::ValueError(_))));
+        assert!(matches!(mp.slice(6, 5), Err(DaftError::ValueError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_head_across_tables() -> DaftResult<()> {
+        let tables = [0..3, 3..5, 5..9]
+            .into_iter()
+            .map(|range| {
+                Table::from_columns(vec![
+                    Int64Array::from(("a", range.collect::<Vec<i64>>())).into_series()
+                ])
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mp = MicroPartition::new(
+            tables[0].schema.clone(),
+            TableState::Loaded(Arc::new(tables)),
+            TableMetadata { length: 9 },
+            None,
+        );
+
+        for num in [0, 2, 3, 4, 9, 20] {
+            let head = mp.head(num)?;
+            assert_eq!(head.len(), num.min(9));
+            let values = head
+                .concat_or_get()?
+                .iter()
+                .flat_map(|t| {
+                    t.get_column("a")
+                        .unwrap()
+                        .i64()
+                        .unwrap()
+                        .as_arrow()
+                        .values()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(values, (0..num.min(9) as i64).collect::<Vec<_>>());
+        }
+
+        Ok(())
     }
 }
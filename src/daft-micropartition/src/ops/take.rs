@@ -5,7 +5,7 @@ use daft_core::Series;
 use daft_table::Table;
 
 use crate::micropartition::{MicroPartition, TableState};
-use daft_stats::TableMetadata;
+use daft_stats::{TableMetadata, TableStatistics};
 
 impl MicroPartition {
     pub fn take(&self, idx: &Series) -> DaftResult<Self> {
@@ -19,7 +19,9 @@ impl MicroPartition {
                     self.schema.clone(),
                     TableState::Loaded(Arc::new(vec![taken])),
                     TableMetadata { length: idx.len() },
-                    self.statistics.clone(),
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
                 ))
             }
             [single] => {
@@ -28,7 +30,9 @@ impl MicroPartition {
                     self.schema.clone(),
                     TableState::Loaded(Arc::new(vec![taken])),
                     TableMetadata { length: idx.len() },
-                    self.statistics.clone(),
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
                 ))
             }
             _ => unreachable!(),
@@ -47,7 +51,9 @@ impl MicroPartition {
                     self.schema.clone(),
                     TableState::Loaded(Arc::new(vec![taken])),
                     TableMetadata { length: taken_len },
-                    self.statistics.clone(),
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
                 ))
             }
             _ => unreachable!(),
@@ -65,10 +71,61 @@ impl MicroPartition {
                     self.schema.clone(),
                     TableState::Loaded(Arc::new(vec![taken])),
                     TableMetadata { length: taken_len },
-                    self.statistics.clone(),
+                    self.statistics
+                        .as_ref()
+                        .map(TableStatistics::without_counts),
                 ))
             }
             _ => unreachable!(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc};
+
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+    use daft_stats::TableMetadata;
+    use daft_table::Table;
+
+    use crate::micropartition::{MicroPartition, TableState};
+
+    #[test]
+    fn test_sample_exact_distinct_count() -> DaftResult<()> {
+        let t1 = Table::from_columns(vec![
+            Int64Array::from(("a", (0..6).collect::<Vec<i64>>())).into_series()
+        ])?;
+        let t2 = Table::from_columns(vec![
+            Int64Array::from(("a", (6..10).collect::<Vec<i64>>())).into_series()
+        ])?;
+        let mp = MicroPartition::new(
+            t1.schema.clone(),
+            TableState::Loaded(Arc::new(vec![t1, t2])),
+            TableMetadata { length: 10 },
+            None,
+        );
+
+        // (requested, expected) counts, which are capped at the number of rows.
+        for (num, expected) in [(20, 10), (10, 10), (3, 3), (0, 0)] {
+            let sampled = mp.sample(num)?;
+            assert_eq!(sampled.len(), expected);
+            let tables = sampled.concat_or_get()?;
+            let values = tables
+                .first()
+                .unwrap()
+                .get_column("a")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>();
+            assert_eq!(values.len(), expected);
+            assert_eq!(values.iter().collect::<HashSet<_>>().len(), expected);
+        }
+
+        Ok(())
+    }
+}
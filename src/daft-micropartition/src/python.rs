@@ -2,6 +2,7 @@
 
 use std::{
     ops::Deref,
+    str::FromStr,
     sync::{Arc, Mutex},
 };
 
@@ -10,11 +11,13 @@ use daft_core::{
     ffi,
     python::{datatype::PyTimeUnit, schema::PySchema, PySeries},
     schema::Schema,
+    sql_dialect::SqlDialect,
     Series,
 };
+use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 use daft_dsl::python::PyExpr;
 use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
-use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_parquet::{read::ParquetSchemaInferenceOptions, write::ParquetCompression};
 use daft_stats::TableStatistics;
 use daft_table::{python::PyTable, Table};
 use indexmap::IndexMap;
@@ -25,7 +28,9 @@ use pyo3::{
     Python,
 };
 
-use crate::micropartition::{DeferredLoadingParams, MicroPartition, TableState};
+use crate::micropartition::{
+    deserialize_versioned, serialize_versioned, DeferredLoadingParams, MicroPartition, TableState,
+};
 
 use daft_stats::TableMetadata;
 use pyo3::PyTypeInfo;
@@ -36,6 +41,62 @@ struct PyMicroPartition {
     inner: Arc<MicroPartition>,
 }
 
+/// Checks that the columns of a record batch have the names of the fields of `schema`, in order.
+fn validate_record_batch_names(record_batch: &PyAny, schema: &Schema) -> PyResult<()> {
+    let names = record_batch
+        .getattr(pyo3::intern!(record_batch.py(), "schema"))?
+        .getattr(pyo3::intern!(record_batch.py(), "names"))?
+        .extract::<Vec<String>>()?;
+    let expected = schema.names();
+    if let Some((name, expected_name)) = names
+        .iter()
+        .zip(expected.iter())
+        .find(|(name, expected_name)| name != expected_name)
+    {
+        return Err(PyValueError::new_err(format!(
+            "Record batch has column \"{name}\" where the provided schema expects column \"{expected_name}\""
+        )));
+    }
+    if names.len() != expected.len() {
+        let column = names
+            .get(expected.len())
+            .unwrap_or_else(|| &expected[names.len()]);
+        return Err(PyValueError::new_err(format!(
+            "Record batch has {} columns but the provided schema has {}; column \"{column}\" is unmatched",
+            names.len(),
+            expected.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Casts the columns of a table converted from a record batch to the dtypes of `schema`, if they differ.
+fn cast_record_batch_table(table: Table, schema: &Schema) -> PyResult<Table> {
+    if table.schema.as_ref() == schema {
+        return Ok(table);
+    }
+    let columns = schema
+        .fields
+        .values()
+        .enumerate()
+        .map(|(i, field)| {
+            let column = table.get_column_by_index(i)?;
+            if column.data_type() == &field.dtype {
+                return Ok(column.clone());
+            }
+            column.cast(&field.dtype).map_err(|e| {
+                PyValueError::new_err(format!(
+                    "Record batch column \"{}\" has type {}, which can't be cast to the type {} of the provided schema: {e}",
+                    field.name,
+                    column.data_type(),
+                    field.dtype
+                ))
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(Table::from_columns(columns)?)
+}
+
 #[pymethods]
 impl PyMicroPartition {
     pub fn schema(&self) -> PyResult<PySchema> {
@@ -64,7 +125,27 @@ impl PyMicroPartition {
         Ok(self.inner.size_bytes()?)
     }
 
-    pub fn __len__(&self) -> PyResult<usize> {
+    pub fn size_bytes_is_estimate(&self) -> PyResult<bool> {
+        Ok(self.inner.size_bytes_is_estimate())
+    }
+
+    pub fn chunk_info(&self) -> PyResult<Vec<(usize, usize)>> {
+        Ok(self.inner.chunk_info()?)
+    }
+
+    pub fn to_sql_ddl(&self, table_name: &str, dialect: &str) -> PyResult<String> {
+        Ok(self
+            .inner
+            .schema
+            .to_sql_ddl(table_name, SqlDialect::from_str(dialect)?)?)
+    }
+
+    pub fn __len__(&self, py: Python) -> PyResult<usize> {
+        // The length of an unloaded MicroPartition with pushed-down filters is only an upper bound, while callers such
+        // as the runners' partition metadata need the exact number of rows, so it's loaded to count them.
+        if !self.inner.is_exact_length() {
+            py.allow_threads(|| self.inner.concat_or_get())?;
+        }
         Ok(self.inner.len())
     }
 
@@ -115,7 +196,12 @@ impl PyMicroPartition {
         // TODO: Cleanup and refactor code for sharing with Table
         let tables = record_batches
             .iter()
-            .map(|rb| daft_table::ffi::record_batches_to_table(py, &[rb], schema.schema.clone()))
+            .map(|rb| {
+                validate_record_batch_names(rb, &schema.schema)?;
+                let table =
+                    daft_table::ffi::record_batches_to_table(py, &[rb], schema.schema.clone())?;
+                cast_record_batch_table(table, &schema.schema)
+            })
             .collect::<PyResult<Vec<_>>>()?;
 
         let total_len = tables.iter().map(|tbl| tbl.len()).sum();
@@ -140,6 +226,15 @@ impl PyMicroPartition {
         }
     }
 
+    pub fn to_arrow_record_batches(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        let concatted = py.allow_threads(|| self.inner.concat_or_get())?;
+        let pyarrow = py.import("pyarrow")?;
+        concatted
+            .iter()
+            .map(|table| daft_table::ffi::table_to_record_batch(table, py, pyarrow))
+            .collect()
+    }
+
     // Compute Methods
 
     #[staticmethod]
@@ -236,6 +331,21 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn join_with_predicate(
+        &self,
+        py: Python,
+        right: &Self,
+        predicate: PyExpr,
+        max_output_rows: Option<usize>,
+    ) -> PyResult<Self> {
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .join_with_predicate(&right.inner, &predicate.expr, max_output_rows)?
+                .into())
+        })
+    }
+
     pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::Expr> =
             to_explode.into_iter().map(|e| e.expr).collect();
@@ -276,6 +386,17 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn approx_quantiles(&self, py: Python, num: i64, epsilon: f64) -> PyResult<Self> {
+        py.allow_threads(|| {
+            if num < 0 {
+                return Err(PyValueError::new_err(format!(
+                    "Can not fetch quantile from table with negative number: {num}"
+                )));
+            }
+            Ok(self.inner.approx_quantiles(num as usize, epsilon)?.into())
+        })
+    }
+
     pub fn partition_by_hash(
         &self,
         py: Python,
@@ -343,48 +464,93 @@ impl PyMicroPartition {
         })
     }
 
-    #[allow(clippy::too_many_arguments)]
+    pub fn split_by_size(&self, py: Python, target_bytes: i64) -> PyResult<Vec<Self>> {
+        if target_bytes <= 0 {
+            return Err(PyValueError::new_err(format!(
+                "Can not split MicroPartition into chunks of non-positive size: {target_bytes}"
+            )));
+        }
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .split_by_size(target_bytes as usize)?
+                .into_iter()
+                .map(|t| t.into())
+                .collect::<Vec<Self>>())
+        })
+    }
+
     #[staticmethod]
     pub fn read_csv(
         py: Python,
         uri: &str,
-        column_names: Option<Vec<&str>>,
-        include_columns: Option<Vec<&str>>,
-        num_rows: Option<usize>,
-        has_header: Option<bool>,
-        delimiter: Option<&str>,
+        convert_options: Option<CsvConvertOptions>,
+        parse_options: Option<CsvParseOptions>,
+        read_options: Option<CsvReadOptions>,
         io_config: Option<IOConfig>,
         multithreaded_io: Option<bool>,
-        schema: Option<PySchema>,
-        buffer_size: Option<usize>,
-        chunk_size: Option<usize>,
     ) -> PyResult<Self> {
-        let delimiter = delimiter
-            .map(|delimiter| match delimiter.as_bytes() {
-                [c] => Ok(*c),
-                _ => Err(PyValueError::new_err(
-                    "Provided CSV delimiter must be a 1-byte character",
-                )),
-            })
-            .transpose()?;
-
         let mp = py.allow_threads(|| {
             let io_stats = IOStatsContext::new(format!("read_csv: for uri {uri}"));
             let io_config = io_config.unwrap_or_default().config.into();
 
             crate::micropartition::read_csv_into_micropartition(
                 [uri].as_ref(),
-                column_names,
-                include_columns,
-                num_rows,
-                has_header.unwrap_or(true),
-                delimiter,
+                convert_options,
+                parse_options,
+                read_options,
                 io_config,
                 multithreaded_io.unwrap_or(true),
                 Some(io_stats),
-                schema.map(|s| s.schema),
-                buffer_size,
-                chunk_size,
+            )
+        })?;
+        Ok(mp.into())
+    }
+
+    /// Reads multiple CSV files into a single MicroPartition, e.g. with `read_options` that reuse the schema of the
+    /// first file for all of them.
+    #[staticmethod]
+    pub fn read_csv_bulk(
+        py: Python,
+        uris: Vec<&str>,
+        convert_options: Option<CsvConvertOptions>,
+        parse_options: Option<CsvParseOptions>,
+        read_options: Option<CsvReadOptions>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<Self> {
+        let mp = py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_csv: for uri {uris:?}"));
+            let io_config = io_config.unwrap_or_default().config.into();
+
+            crate::micropartition::read_csv_into_micropartition(
+                uris.as_ref(),
+                convert_options,
+                parse_options,
+                read_options,
+                io_config,
+                multithreaded_io.unwrap_or(true),
+                Some(io_stats),
+            )
+        })?;
+        Ok(mp.into())
+    }
+
+    /// Parses a MicroPartition directly from in-memory CSV `data`, without any IO.
+    #[staticmethod]
+    pub fn read_csv_from_bytes(
+        py: Python,
+        data: &[u8],
+        convert_options: Option<CsvConvertOptions>,
+        parse_options: Option<CsvParseOptions>,
+        read_options: Option<CsvReadOptions>,
+    ) -> PyResult<Self> {
+        let mp = py.allow_threads(|| {
+            crate::micropartition::read_csv_from_bytes_into_micropartition(
+                data,
+                convert_options,
+                parse_options,
+                read_options,
             )
         })?;
         Ok(mp.into())
@@ -402,6 +568,8 @@ impl PyMicroPartition {
         io_config: Option<IOConfig>,
         multithreaded_io: Option<bool>,
         coerce_int96_timestamp_unit: Option<PyTimeUnit>,
+        filter: Option<PyExpr>,
+        keep_dictionary_columns: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let mp = py.allow_threads(|| {
             let io_stats = IOStatsContext::new(format!("read_parquet: for uri {uri}"));
@@ -409,9 +577,10 @@ impl PyMicroPartition {
             let io_config = io_config.unwrap_or_default().config.into();
             let schema_infer_options = ParquetSchemaInferenceOptions::new(
                 coerce_int96_timestamp_unit.map(|tu| tu.timeunit),
-            );
+            )
+            .with_keep_dictionary_columns(keep_dictionary_columns);
 
-            crate::micropartition::read_parquet_into_micropartition(
+            let mp = crate::micropartition::read_parquet_into_micropartition(
                 [uri].as_ref(),
                 columns.as_deref(),
                 start_offset,
@@ -422,6 +591,61 @@ impl PyMicroPartition {
                 1,
                 multithreaded_io.unwrap_or(true),
                 &schema_infer_options,
+            )?;
+            // Filtering the unloaded read pushes the filter into it, so that only the row groups whose statistics
+            // may satisfy it are read when it's loaded.
+            match filter {
+                Some(filter) => mp.filter(&[filter.expr]),
+                None => Ok(mp),
+            }
+        })?;
+        Ok(mp.into())
+    }
+
+    /// Writes this MicroPartition as a single Parquet file at `uri`, returning the URI of the written file.
+    pub fn write_parquet(
+        &self,
+        py: Python,
+        uri: &str,
+        compression: Option<&str>,
+        row_group_size: Option<usize>,
+        io_config: Option<IOConfig>,
+    ) -> PyResult<String> {
+        py.allow_threads(|| {
+            let compression = compression
+                .map(ParquetCompression::from_str)
+                .transpose()?
+                .unwrap_or_default();
+            let io_config = io_config.unwrap_or_default().config.into();
+            Ok(self
+                .inner
+                .write_parquet(uri, compression, row_group_size, io_config)?)
+        })
+    }
+
+    /// Parses a MicroPartition directly from in-memory Parquet `data`, without any IO.
+    #[staticmethod]
+    pub fn read_parquet_from_bytes(
+        py: Python,
+        data: &[u8],
+        columns: Option<Vec<&str>>,
+        start_offset: Option<usize>,
+        num_rows: Option<usize>,
+        row_groups: Option<Vec<i64>>,
+        coerce_int96_timestamp_unit: Option<PyTimeUnit>,
+    ) -> PyResult<Self> {
+        let mp = py.allow_threads(|| {
+            let schema_infer_options = ParquetSchemaInferenceOptions::new(
+                coerce_int96_timestamp_unit.map(|tu| tu.timeunit),
+            );
+
+            crate::micropartition::read_parquet_from_bytes_into_micropartition(
+                data,
+                columns.as_deref(),
+                start_offset,
+                num_rows,
+                row_groups,
+                &schema_infer_options,
             )
         })?;
         Ok(mp.into())
@@ -440,6 +664,7 @@ impl PyMicroPartition {
         num_parallel_tasks: Option<i64>,
         multithreaded_io: Option<bool>,
         coerce_int96_timestamp_unit: Option<PyTimeUnit>,
+        keep_dictionary_columns: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let mp = py.allow_threads(|| {
             let io_stats = IOStatsContext::new(format!("read_parquet: for uri {uris:?}"));
@@ -447,7 +672,8 @@ impl PyMicroPartition {
             let io_config = io_config.unwrap_or_default().config.into();
             let schema_infer_options = ParquetSchemaInferenceOptions::new(
                 coerce_int96_timestamp_unit.map(|tu| tu.timeunit),
-            );
+            )
+            .with_keep_dictionary_columns(keep_dictionary_columns);
 
             crate::micropartition::read_parquet_into_micropartition(
                 uris.as_ref(),
@@ -473,12 +699,12 @@ impl PyMicroPartition {
         metadata_bytes: &PyBytes,
         statistics_bytes: &PyBytes,
     ) -> PyResult<Self> {
-        let schema = bincode::deserialize::<Schema>(schema_bytes.as_bytes()).unwrap();
+        let schema = deserialize_versioned::<Schema>(schema_bytes.as_bytes())?;
         let params =
-            bincode::deserialize::<DeferredLoadingParams>(loading_params_bytes.as_bytes()).unwrap();
-        let metadata = bincode::deserialize::<TableMetadata>(metadata_bytes.as_bytes()).unwrap();
+            deserialize_versioned::<DeferredLoadingParams>(loading_params_bytes.as_bytes())?;
+        let metadata = deserialize_versioned::<TableMetadata>(metadata_bytes.as_bytes())?;
         let statistics =
-            bincode::deserialize::<Option<TableStatistics>>(statistics_bytes.as_bytes()).unwrap();
+            deserialize_versioned::<Option<TableStatistics>>(statistics_bytes.as_bytes())?;
 
         Ok(MicroPartition::new(
             schema.into(),
@@ -497,10 +723,10 @@ impl PyMicroPartition {
         metadata_bytes: &PyBytes,
         statistics_bytes: &PyBytes,
     ) -> PyResult<Self> {
-        let schema = bincode::deserialize::<Schema>(schema_bytes.as_bytes()).unwrap();
-        let metadata = bincode::deserialize::<TableMetadata>(metadata_bytes.as_bytes()).unwrap();
+        let schema = deserialize_versioned::<Schema>(schema_bytes.as_bytes())?;
+        let metadata = deserialize_versioned::<TableMetadata>(metadata_bytes.as_bytes())?;
         let statistics =
-            bincode::deserialize::<Option<TableStatistics>>(statistics_bytes.as_bytes()).unwrap();
+            deserialize_versioned::<Option<TableStatistics>>(statistics_bytes.as_bytes())?;
 
         let tables = table_objs
             .into_iter()
@@ -521,11 +747,10 @@ impl PyMicroPartition {
     }
 
     pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
-        let schema_bytes = PyBytes::new(py, &bincode::serialize(&self.inner.schema).unwrap());
+        let schema_bytes = PyBytes::new(py, &serialize_versioned(&self.inner.schema)?);
 
-        let py_metadata_bytes =
-            PyBytes::new(py, &bincode::serialize(&self.inner.metadata).unwrap());
-        let py_stats_bytes = PyBytes::new(py, &bincode::serialize(&self.inner.statistics).unwrap());
+        let py_metadata_bytes = PyBytes::new(py, &serialize_versioned(&self.inner.metadata)?);
+        let py_stats_bytes = PyBytes::new(py, &serialize_versioned(&self.inner.statistics)?);
 
         let guard = self.inner.state.lock().unwrap();
         if let TableState::Loaded(tables) = guard.deref() {
@@ -545,7 +770,7 @@ impl PyMicroPartition {
                 (schema_bytes, pyobjs, py_metadata_bytes, py_stats_bytes).to_object(py),
             ))
         } else if let TableState::Unloaded(params) = guard.deref() {
-            let py_params_bytes = PyBytes::new(py, &bincode::serialize(params).unwrap());
+            let py_params_bytes = PyBytes::new(py, &serialize_versioned(params)?);
             Ok((
                 Self::type_object(py)
                     .getattr(pyo3::intern!(py, "_from_unloaded_table_state"))?
@@ -1,6 +1,5 @@
-use std::{collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
-use arrow2::io::parquet::read::schema::infer_schema_with_options;
 use common_error::DaftResult;
 use daft_core::{utils::arrow::cast_array_for_daft_if_needed, Series};
 use daft_io::{IOClient, IOStatsRef};
@@ -15,6 +14,7 @@ use snafu::ResultExt;
 
 use crate::{
     metadata::read_parquet_metadata,
+    projection::{is_projected_leaf, project_schema, resolve_field_projections, FieldProjections},
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     JoinSnafu, OneShotRecvSnafu, UnableToCreateParquetPageStreamSnafu,
@@ -25,7 +25,7 @@ use arrow2::io::parquet::read::column_iter_to_arrays;
 pub(crate) struct ParquetReaderBuilder {
     pub uri: String,
     pub metadata: parquet2::metadata::FileMetaData,
-    selected_columns: Option<HashSet<String>>,
+    selected_columns: Option<FieldProjections>,
     row_start_offset: usize,
     num_rows: usize,
     row_groups: Option<Vec<i64>>,
@@ -172,26 +172,20 @@ impl ParquetReaderBuilder {
         self.metadata().schema()
     }
 
+    /// Selects the columns to read, either top-level fields or dotted paths to nested struct subfields, e.g.
+    /// `address.zip`, in which case only the leaf columns of the selected subfields are read.
     pub fn prune_columns(mut self, columns: &[&str]) -> super::Result<Self> {
-        let avail_names = self
-            .parquet_schema()
-            .fields()
-            .iter()
-            .map(|f| f.name())
-            .collect::<HashSet<_>>();
-        let mut names_to_keep = HashSet::new();
-        for col_name in columns {
-            if avail_names.contains(col_name) {
-                names_to_keep.insert(col_name.to_string());
-            } else {
-                return Err(super::Error::FieldNotFound {
-                    field: col_name.to_string(),
-                    available_fields: avail_names.iter().map(|v| v.to_string()).collect(),
-                    path: self.uri,
-                });
-            }
-        }
-        self.selected_columns = Some(names_to_keep);
+        let arrow_schema = self
+            .schema_inference_options
+            .infer_arrow_schema(&self.metadata)
+            .context(UnableToParseSchemaFromMetadataSnafu::<String> {
+                path: self.uri.clone(),
+            })?;
+        self.selected_columns = Some(resolve_field_projections(
+            &arrow_schema,
+            columns,
+            &self.uri,
+        )?);
         Ok(self)
     }
 
@@ -226,19 +220,24 @@ impl ParquetReaderBuilder {
             &self.uri,
         )?;
 
-        let mut arrow_schema =
-            infer_schema_with_options(&self.metadata, &Some(self.schema_inference_options.into()))
-                .context(UnableToParseSchemaFromMetadataSnafu::<String> {
-                    path: self.uri.clone(),
-                })?;
+        let mut arrow_schema = self
+            .schema_inference_options
+            .infer_arrow_schema(&self.metadata)
+            .context(UnableToParseSchemaFromMetadataSnafu::<String> {
+                path: self.uri.clone(),
+            })?;
 
-        if let Some(names_to_keep) = self.selected_columns {
-            arrow_schema
-                .fields
-                .retain(|f| names_to_keep.contains(f.name.as_str()));
+        if let Some(projections) = &self.selected_columns {
+            arrow_schema = project_schema(arrow_schema, projections);
         }
 
-        ParquetFileReader::new(self.uri, self.metadata, arrow_schema, row_ranges)
+        ParquetFileReader::new(
+            self.uri,
+            self.metadata,
+            arrow_schema,
+            row_ranges,
+            self.selected_columns.unwrap_or_default(),
+        )
     }
 }
 
@@ -254,6 +253,7 @@ pub(crate) struct ParquetFileReader {
     metadata: Arc<parquet2::metadata::FileMetaData>,
     arrow_schema: arrow2::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
+    field_projections: FieldProjections,
 }
 
 impl ParquetFileReader {
@@ -262,12 +262,14 @@ impl ParquetFileReader {
         metadata: parquet2::metadata::FileMetaData,
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
+        field_projections: FieldProjections,
     ) -> super::Result<Self> {
         Ok(ParquetFileReader {
             uri,
             metadata: Arc::new(metadata),
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
+            field_projections,
         })
     }
 
@@ -292,7 +294,13 @@ impl ParquetFileReader {
                 let field_name = field.name.clone();
                 let filtered_cols = columns
                     .iter()
-                    .filter(|x| x.descriptor().path_in_schema[0] == field_name)
+                    .filter(|x| {
+                        is_projected_leaf(
+                            &self.field_projections,
+                            &field_name,
+                            &x.descriptor().path_in_schema,
+                        )
+                    })
                     .collect::<Vec<_>>();
 
                 for col in filtered_cols {
@@ -333,6 +341,7 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<Table> {
         let metadata = self.metadata;
+        let field_projections = &self.field_projections;
         let all_handles = self
             .arrow_schema
             .fields
@@ -357,7 +366,13 @@ impl ParquetFileReader {
                         let filtered_cols_idx = columns
                             .iter()
                             .enumerate()
-                            .filter(|(_, x)| &x.descriptor().path_in_schema[0] == field_name)
+                            .filter(|(_, x)| {
+                                is_projected_leaf(
+                                    field_projections,
+                                    field_name,
+                                    &x.descriptor().path_in_schema,
+                                )
+                            })
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
@@ -499,6 +514,7 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<Vec<Vec<Box<dyn arrow2::array::Array>>>> {
         let metadata = self.metadata;
+        let field_projections = &self.field_projections;
         let all_handles = self
             .arrow_schema
             .fields
@@ -523,7 +539,13 @@ impl ParquetFileReader {
                         let filtered_cols_idx = columns
                             .iter()
                             .enumerate()
-                            .filter(|(_, x)| &x.descriptor().path_in_schema[0] == field_name)
+                            .filter(|(_, x)| {
+                                is_projected_leaf(
+                                    field_projections,
+                                    field_name,
+                                    &x.descriptor().path_in_schema,
+                                )
+                            })
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
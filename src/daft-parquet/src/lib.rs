@@ -5,6 +5,7 @@ use snafu::Snafu;
 
 mod file;
 pub mod metadata;
+mod projection;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
@@ -12,6 +13,7 @@ mod statistics;
 pub use statistics::row_group_metadata_to_table_stats;
 mod read_planner;
 mod stream_reader;
+pub mod write;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use arrow2::datatypes::{DataType, Field, Schema};
+
+/// Top-level fields selected by a column projection, mapped to the paths of the nested struct subfields selected in
+/// that field, or `None` if the whole field was selected.
+pub(crate) type FieldProjections = HashMap<String, Option<Vec<Vec<String>>>>;
+
+/// Resolves the columns to read from `schema`, where each column is either the name of a top-level field, or a
+/// dotted path to a nested struct subfield, e.g. `address.zip`.
+pub(crate) fn resolve_field_projections<S: AsRef<str>>(
+    schema: &Schema,
+    columns: &[S],
+    uri: &str,
+) -> super::Result<FieldProjections> {
+    let mut projections = FieldProjections::new();
+    for col_name in columns {
+        let col_name = col_name.as_ref();
+        // Top-level fields take precedence, in case a top-level field name contains a dot.
+        let (field_name, subpath) = if schema.fields.iter().any(|f| f.name == col_name) {
+            (col_name.to_string(), vec![])
+        } else {
+            let mut segments = col_name.split('.').map(str::to_string);
+            let field_name = segments.next().unwrap_or_default();
+            (field_name, segments.collect::<Vec<_>>())
+        };
+        let field = schema.fields.iter().find(|f| f.name == field_name);
+        if !field.map_or(false, |f| has_subfield(&f.data_type, &subpath)) {
+            return Err(super::Error::FieldNotFound {
+                field: col_name.to_string(),
+                available_fields: schema.fields.iter().map(|f| f.name.clone()).collect(),
+                path: uri.to_string(),
+            });
+        }
+        let projection = projections
+            .entry(field_name)
+            .or_insert_with(|| Some(vec![]));
+        if subpath.is_empty() {
+            *projection = None;
+        } else if let Some(subpaths) = projection {
+            subpaths.push(subpath);
+        }
+    }
+    Ok(projections)
+}
+
+fn has_subfield(data_type: &DataType, subpath: &[String]) -> bool {
+    match (subpath.split_first(), data_type.to_logical_type()) {
+        (None, _) => true,
+        (Some((name, rest)), DataType::Struct(children)) => children
+            .iter()
+            .find(|child| &child.name == name)
+            .map_or(false, |child| has_subfield(&child.data_type, rest)),
+        _ => false,
+    }
+}
+
+/// Keeps only the projected fields of `schema`, pruning partially selected struct fields down to the selected
+/// subfields.
+pub(crate) fn project_schema(schema: Schema, projections: &FieldProjections) -> Schema {
+    let fields = schema
+        .fields
+        .into_iter()
+        .filter_map(|field| match projections.get(&field.name)? {
+            None => Some(field),
+            Some(subpaths) => Some(prune_struct_field(field, subpaths)),
+        })
+        .collect::<Vec<_>>();
+    Schema::from(fields).with_metadata(schema.metadata)
+}
+
+fn prune_struct_field(field: Field, subpaths: &[Vec<String>]) -> Field {
+    if subpaths.iter().any(Vec::is_empty) {
+        return field;
+    }
+    let DataType::Struct(children) = field.data_type.to_logical_type() else {
+        return field;
+    };
+    let children = children
+        .iter()
+        .filter_map(|child| {
+            let child_subpaths = subpaths
+                .iter()
+                .filter(|subpath| subpath[0] == child.name)
+                .map(|subpath| subpath[1..].to_vec())
+                .collect::<Vec<_>>();
+            (!child_subpaths.is_empty()).then(|| prune_struct_field(child.clone(), &child_subpaths))
+        })
+        .collect();
+    Field::new(field.name, DataType::Struct(children), field.is_nullable)
+        .with_metadata(field.metadata)
+}
+
+/// Whether the Parquet leaf column at `path_in_schema` belongs to the top-level field `field_name`, and to one of the
+/// subfields selected in that field, if any.
+pub(crate) fn is_projected_leaf(
+    projections: &FieldProjections,
+    field_name: &str,
+    path_in_schema: &[String],
+) -> bool {
+    path_in_schema[0] == field_name
+        && projections
+            .get(field_name)
+            .and_then(Option::as_ref)
+            .map_or(true, |subpaths| {
+                subpaths
+                    .iter()
+                    .any(|subpath| path_in_schema[1..].starts_with(subpath))
+            })
+}
+
+/// Number of top-level columns a projection of `columns` yields, given the top-level field names of the file.
+pub(crate) fn num_projected_top_level_columns<S: AsRef<str>>(
+    columns: &[S],
+    top_level_names: &[&str],
+) -> usize {
+    let mut names = columns
+        .iter()
+        .map(|col_name| {
+            let col_name = col_name.as_ref();
+            if top_level_names.contains(&col_name) {
+                col_name
+            } else {
+                col_name.split('.').next().unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    names.len()
+}
@@ -24,6 +24,7 @@ pub mod pylib {
         io_config: Option<IOConfig>,
         multithreaded_io: Option<bool>,
         coerce_int96_timestamp_unit: Option<PyTimeUnit>,
+        keep_dictionary_columns: Option<Vec<String>>,
     ) -> PyResult<PyTable> {
         py.allow_threads(|| {
             let io_stats = IOStatsContext::new(format!("read_parquet: for uri {uri}"));
@@ -34,7 +35,8 @@ pub mod pylib {
             )?;
             let schema_infer_options = ParquetSchemaInferenceOptions::new(
                 coerce_int96_timestamp_unit.map(|tu| tu.timeunit),
-            );
+            )
+            .with_keep_dictionary_columns(keep_dictionary_columns);
             let runtime_handle = daft_io::get_runtime(multithreaded_io.unwrap_or(true))?;
 
             let result = crate::read::read_parquet(
@@ -131,6 +133,7 @@ pub mod pylib {
         num_parallel_tasks: Option<i64>,
         multithreaded_io: Option<bool>,
         coerce_int96_timestamp_unit: Option<PyTimeUnit>,
+        keep_dictionary_columns: Option<Vec<String>>,
     ) -> PyResult<Vec<PyTable>> {
         py.allow_threads(|| {
             let io_stats = IOStatsContext::new("read_parquet_bulk".to_string());
@@ -141,7 +144,8 @@ pub mod pylib {
             )?;
             let schema_infer_options = ParquetSchemaInferenceOptions::new(
                 coerce_int96_timestamp_unit.map(|tu| tu.timeunit),
-            );
+            )
+            .with_keep_dictionary_columns(keep_dictionary_columns);
             let runtime_handle = daft_io::get_runtime(multithreaded_io.unwrap_or(true))?;
 
             Ok(crate::read::read_parquet_bulk(
@@ -7,22 +7,26 @@ use daft_core::{
     schema::Schema,
     DataType, IntoSeries, Series,
 };
-use daft_io::{get_runtime, parse_url, IOClient, IOStatsRef, SourceType};
-use daft_table::Table;
-use futures::{
-    future::{join_all, try_join_all},
-    StreamExt, TryStreamExt,
+use daft_io::{
+    get_runtime, join_all_bounded, parse_url, IOClient, IOStatsRef, SourceType,
+    DEFAULT_MAX_CONCURRENT_IO,
 };
+use daft_table::Table;
+use futures::{future::join_all, StreamExt, TryStreamExt};
 use itertools::Itertools;
 use snafu::ResultExt;
 use tokio::runtime::Runtime;
 
-use crate::{file::ParquetReaderBuilder, JoinSnafu};
+use crate::{file::ParquetReaderBuilder, projection::num_projected_top_level_columns, JoinSnafu};
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ParquetSchemaInferenceOptions {
     pub coerce_int96_timestamp_unit: TimeUnit,
+    /// Top-level columns whose dictionary encoding is preserved, i.e. which are read as [`DataType::Dictionary`]
+    /// columns rather than materialized into plain arrays.
+    #[serde(default)]
+    pub keep_dictionary_columns: Option<Vec<String>>,
 }
 
 impl ParquetSchemaInferenceOptions {
@@ -32,7 +36,61 @@ impl ParquetSchemaInferenceOptions {
             coerce_int96_timestamp_unit.unwrap_or(default.coerce_int96_timestamp_unit);
         ParquetSchemaInferenceOptions {
             coerce_int96_timestamp_unit,
+            ..default
+        }
+    }
+
+    pub fn with_keep_dictionary_columns(
+        mut self,
+        keep_dictionary_columns: Option<Vec<String>>,
+    ) -> Self {
+        self.keep_dictionary_columns = keep_dictionary_columns;
+        self
+    }
+
+    /// Infers the Arrow schema of a Parquet file from its metadata, as a dictionary type for the columns in
+    /// `keep_dictionary_columns`.
+    ///
+    /// Only columns of primitive, string or binary types can be kept dictionary-encoded, and their pages must be
+    /// dictionary-encoded in the file. Columns that aren't in the file are ignored.
+    pub fn infer_arrow_schema(
+        &self,
+        metadata: &parquet2::metadata::FileMetaData,
+    ) -> arrow2::error::Result<arrow2::datatypes::Schema> {
+        let mut schema = arrow2::io::parquet::read::schema::infer_schema_with_options(
+            metadata,
+            &Some(self.into()),
+        )?;
+        let Some(keep_dictionary_columns) = &self.keep_dictionary_columns else {
+            return Ok(schema);
+        };
+        for field in schema
+            .fields
+            .iter_mut()
+            .filter(|field| keep_dictionary_columns.contains(&field.name))
+        {
+            use arrow2::datatypes::PhysicalType::*;
+            // Columns whose stored Arrow schema is already a dictionary are only normalized to `u32` keys.
+            let values_type = match &field.data_type {
+                arrow2::datatypes::DataType::Dictionary(_, values_type, _) => values_type.as_ref(),
+                data_type => data_type,
+            };
+            if !matches!(
+                values_type.to_physical_type(),
+                Primitive(_) | Utf8 | LargeUtf8 | Binary | LargeBinary
+            ) {
+                return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                    "Column {} has type {:?}, which can't be kept dictionary-encoded; only primitive, string and binary columns can",
+                    field.name, field.data_type
+                )));
+            }
+            field.data_type = arrow2::datatypes::DataType::Dictionary(
+                arrow2::datatypes::IntegerType::UInt32,
+                Box::new(values_type.clone()),
+                false,
+            );
         }
+        Ok(schema)
     }
 }
 
@@ -40,20 +98,33 @@ impl Default for ParquetSchemaInferenceOptions {
     fn default() -> Self {
         ParquetSchemaInferenceOptions {
             coerce_int96_timestamp_unit: TimeUnit::Nanoseconds,
+            keep_dictionary_columns: None,
         }
     }
 }
 
-impl From<ParquetSchemaInferenceOptions>
+impl From<&ParquetSchemaInferenceOptions>
     for arrow2::io::parquet::read::schema::SchemaInferenceOptions
 {
-    fn from(value: ParquetSchemaInferenceOptions) -> Self {
+    fn from(value: &ParquetSchemaInferenceOptions) -> Self {
         arrow2::io::parquet::read::schema::SchemaInferenceOptions {
             int96_coerce_to_timeunit: value.coerce_int96_timestamp_unit.to_arrow(),
         }
     }
 }
 
+/// Checks that a read selects either `row_groups`, or a `start_offset` and `num_rows`, rather than both.
+fn validate_row_groups_or_limit(
+    row_groups: Option<&[i64]>,
+    start_offset: Option<usize>,
+    num_rows: Option<usize>,
+) -> DaftResult<()> {
+    if row_groups.is_some() && (num_rows.is_some() || start_offset.is_some()) {
+        return Err(common_error::DaftError::ValueError("Both `row_groups` and `num_rows` or `start_offset` is set at the same time. We only support setting one set or the other.".to_string()));
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn read_parquet_single(
     uri: &str,
@@ -74,6 +145,7 @@ async fn read_parquet_single(
             num_rows,
             row_groups.clone(),
             schema_infer_options,
+            io_stats.clone(),
         )
         .await
     } else {
@@ -87,9 +159,7 @@ async fn read_parquet_single(
             builder
         };
 
-        if row_groups.is_some() && (num_rows.is_some() || start_offset.is_some()) {
-            return Err(common_error::DaftError::ValueError("Both `row_groups` and `num_rows` or `start_offset` is set at the same time. We only support setting one set or the other.".to_string()));
-        }
+        validate_row_groups_or_limit(row_groups.as_deref(), start_offset, num_rows)?;
         let builder = builder.limit(start_offset, num_rows)?;
         let metadata = builder.metadata().clone();
 
@@ -156,7 +226,14 @@ async fn read_parquet_single(
     };
 
     let expected_num_columns = if let Some(columns) = columns {
-        columns.len()
+        // Several nested subfields of the same field are read into a single column.
+        let top_level_names = metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name())
+            .collect::<Vec<_>>();
+        num_projected_top_level_columns(columns, &top_level_names)
     } else {
         metadata_num_columns
     };
@@ -194,6 +271,7 @@ async fn read_parquet_single_into_arrow(
                 num_rows,
                 row_groups.clone(),
                 schema_infer_options,
+                io_stats.clone(),
             )
             .await?;
         (metadata, Arc::new(schema), all_arrays)
@@ -208,9 +286,7 @@ async fn read_parquet_single_into_arrow(
             builder
         };
 
-        if row_groups.is_some() && (num_rows.is_some() || start_offset.is_some()) {
-            return Err(common_error::DaftError::ValueError("Both `row_groups` and `num_rows` or `start_offset` is set at the same time. We only support setting one set or the other.".to_string()));
-        }
+        validate_row_groups_or_limit(row_groups.as_deref(), start_offset, num_rows)?;
         let builder = builder.limit(start_offset, num_rows)?;
         let metadata = builder.metadata().clone();
 
@@ -292,7 +368,14 @@ async fn read_parquet_single_into_arrow(
     };
 
     let expected_num_columns = if let Some(columns) = columns {
-        columns.len()
+        // Several nested subfields of the same field are read into a single column.
+        let top_level_names = metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name())
+            .collect::<Vec<_>>();
+        num_projected_top_level_columns(columns, &top_level_names)
     } else {
         metadata_num_columns
     };
@@ -336,6 +419,31 @@ pub fn read_parquet(
         .await
     })
 }
+
+/// Reads a Parquet file from in-memory `bytes`, e.g. for small inline data, without any IO.
+pub fn read_parquet_from_bytes(
+    bytes: &[u8],
+    columns: Option<&[&str]>,
+    start_offset: Option<usize>,
+    num_rows: Option<usize>,
+    row_groups: Option<Vec<i64>>,
+    schema_infer_options: ParquetSchemaInferenceOptions,
+) -> DaftResult<Table> {
+    validate_row_groups_or_limit(row_groups.as_deref(), start_offset, num_rows)?;
+    let (_, schema, arrays) = crate::stream_reader::parquet_read_into_arrow(
+        &mut std::io::Cursor::new(bytes),
+        "<bytes>",
+        columns
+            .map(|s| s.iter().map(|s| s.to_string()).collect_vec())
+            .as_deref(),
+        start_offset,
+        num_rows,
+        row_groups.as_deref(),
+        schema_infer_options,
+    )?;
+    crate::stream_reader::arrow_chunks_into_table(schema, arrays)
+}
+
 pub type ArrowChunk = Vec<Box<dyn arrow2::array::Array>>;
 pub type ParquetPyarrowChunk = (arrow2::datatypes::SchemaRef, Vec<ArrowChunk>);
 #[allow(clippy::too_many_arguments)]
@@ -402,7 +510,7 @@ pub fn read_parquet_bulk(
 
                 let io_client = io_client.clone();
                 let io_stats = io_stats.clone();
-                let schema_infer_options = *schema_infer_options;
+                let schema_infer_options = schema_infer_options.clone();
                 tokio::task::spawn(async move {
                     let columns = owned_columns
                         .as_ref()
@@ -465,7 +573,7 @@ pub fn read_parquet_into_pyarrow_bulk(
             futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
                 let uri = uri.to_string();
                 let owned_columns = owned_columns.clone();
-                let schema_infer_options = schema_infer_options;
+                let schema_infer_options = schema_infer_options.clone();
                 let owned_row_group = match &row_groups {
                     None => None,
                     Some(v) => v.get(i).cloned(),
@@ -528,22 +636,26 @@ pub async fn read_parquet_metadata(
     let builder = ParquetReaderBuilder::from_uri(uri, io_client, io_stats).await?;
     Ok(builder.metadata)
 }
+/// Reads the metadata of every file of `uris`, in order, with at most `max_concurrent_io` reads at once, which defaults
+/// to [`DEFAULT_MAX_CONCURRENT_IO`].
 pub async fn read_parquet_metadata_bulk(
     uris: &[&str],
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
+    max_concurrent_io: Option<usize>,
 ) -> DaftResult<Vec<parquet2::metadata::FileMetaData>> {
-    let handles_iter = uris.iter().map(|uri| {
+    let futures = uris.iter().map(|uri| {
         let owned_string = uri.to_string();
         let owned_client = io_client.clone();
         let owned_io_stats = io_stats.clone();
-        tokio::spawn(async move {
-            read_parquet_metadata(&owned_string, owned_client, owned_io_stats).await
-        })
+        async move { read_parquet_metadata(&owned_string, owned_client, owned_io_stats).await }
     });
-    let all_metadatas = try_join_all(handles_iter)
-        .await
-        .context(JoinSnafu { path: "BULK READ" })?;
+    let all_metadatas = join_all_bounded(
+        futures,
+        max_concurrent_io.unwrap_or(DEFAULT_MAX_CONCURRENT_IO),
+    )
+    .await
+    .context(JoinSnafu { path: "BULK READ" })?;
     all_metadatas.into_iter().collect::<DaftResult<Vec<_>>>()
 }
 
@@ -630,9 +742,108 @@ mod tests {
 
     use common_error::DaftResult;
 
+    use arrow2::{
+        array::{Array, Int64Array, StructArray, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema},
+        io::parquet::write::{
+            transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+            WriteOptions,
+        },
+    };
+    use daft_core::{datatypes::Field, schema::Schema, DataType};
     use daft_io::{IOClient, IOConfig};
 
     use super::read_parquet;
+
+    /// Writes a Parquet file with an `id` column and an `address` struct column with `city` and `zip` subfields.
+    fn write_nested_parquet(path: &std::path::Path) -> DaftResult<()> {
+        let address_type = ArrowDataType::Struct(vec![
+            ArrowField::new("city", ArrowDataType::Utf8, true),
+            ArrowField::new("zip", ArrowDataType::Int64, true),
+        ]);
+        let schema = ArrowSchema::from(vec![
+            ArrowField::new("id", ArrowDataType::Int64, true),
+            ArrowField::new("address", address_type.clone(), true),
+        ]);
+        let chunk = Chunk::new(vec![
+            Int64Array::from_slice([1, 2, 3]).boxed(),
+            StructArray::new(
+                address_type,
+                vec![
+                    Utf8Array::<i32>::from_slice(["Oakland", "Berkeley", "Alameda"]).boxed(),
+                    Int64Array::from_slice([94607, 94704, 94501]).boxed(),
+                ],
+                None,
+            )
+            .boxed(),
+        ]);
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+            .collect();
+        let row_groups =
+            RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, encodings)?;
+        let mut writer = FileWriter::try_new(std::fs::File::create(path)?, schema, options)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_read_nested_subfield_local() -> DaftResult<()> {
+        let path = std::env::temp_dir().join(format!(
+            "daft_parquet_nested_subfield_{}.parquet",
+            std::process::id()
+        ));
+        write_nested_parquet(&path)?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+        let table = read_parquet(
+            path.to_str().unwrap(),
+            Some(&["address.zip"]),
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            runtime_handle,
+            Default::default(),
+        );
+        std::fs::remove_file(&path)?;
+        let table = table?;
+
+        // Only the zip leaf of the address struct is materialized.
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![Field::new(
+                "address",
+                DataType::Struct(vec![Field::new("zip", DataType::Int64)])
+            )])?
+            .into(),
+        );
+        assert_eq!(table.len(), 3);
+        let address = table.get_column("address")?.to_arrow();
+        let address = address.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(address.values().len(), 1);
+        assert_eq!(
+            address.values()[0].as_ref(),
+            &Int64Array::from_slice([94607, 94704, 94501]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_parquet_read_from_s3() -> DaftResult<()> {
         let file = "s3://daft-public-data/test_fixtures/parquet-dev/mvp.parquet";
@@ -1,5 +1,11 @@
 use common_error::DaftResult;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use parquet2::{
+    schema::types::PhysicalType,
+    statistics::{
+        BinaryStatistics, BooleanStatistics, FixedLenStatistics, PrimitiveStatistics, Statistics,
+    },
+};
 use snafu::ResultExt;
 
 use super::Wrap;
@@ -9,23 +15,77 @@ use indexmap::IndexMap;
 impl TryFrom<&crate::metadata::RowGroupMetaData> for Wrap<TableStatistics> {
     type Error = super::Error;
     fn try_from(value: &crate::metadata::RowGroupMetaData) -> Result<Self, Self::Error> {
-        let _num_rows = value.num_rows();
-        let mut columns = IndexMap::new();
+        let mut table_stats = TableStatistics::new(IndexMap::new());
+        table_stats.num_rows = Some(value.num_rows());
         for col in value.columns() {
             let stats = col
                 .statistics()
                 .transpose()
                 .context(super::UnableToParseParquetColumnStatisticsSnafu)?;
+            let path_in_schema = &col.descriptor().path_in_schema;
+            let name = path_in_schema.get(0).unwrap().clone();
+            // The counts of a leaf of a nested column aren't the counts of the column itself.
+            if let Some(stats) = &stats && path_in_schema.len() == 1 {
+                if let Some(null_count) = stats.null_count() {
+                    table_stats.null_counts.insert(name.clone(), null_count as usize);
+                }
+                if let Some(distinct_count) = distinct_count(stats.as_ref()) {
+                    table_stats
+                        .distinct_counts
+                        .insert(name.clone(), distinct_count as usize);
+                }
+            }
             let col_stats: Option<Wrap<ColumnRangeStatistics>> =
                 stats.and_then(|v| v.as_ref().try_into().ok());
             let col_stats = col_stats.unwrap_or(ColumnRangeStatistics::Missing.into());
-            columns.insert(
-                col.descriptor().path_in_schema.get(0).unwrap().clone(),
-                col_stats.0,
-            );
+            table_stats.columns.insert(name, col_stats.0);
         }
 
-        Ok(TableStatistics { columns }.into())
+        Ok(table_stats.into())
+    }
+}
+
+/// The distinct count that the writer of a Parquet file recorded in the statistics of a column chunk, which is
+/// optional and rarely written.
+fn distinct_count(stats: &dyn Statistics) -> Option<i64> {
+    let stats_any = stats.as_any();
+    match stats.physical_type() {
+        PhysicalType::Boolean => {
+            stats_any
+                .downcast_ref::<BooleanStatistics>()?
+                .distinct_count
+        }
+        PhysicalType::Int32 => {
+            stats_any
+                .downcast_ref::<PrimitiveStatistics<i32>>()?
+                .distinct_count
+        }
+        PhysicalType::Int64 => {
+            stats_any
+                .downcast_ref::<PrimitiveStatistics<i64>>()?
+                .distinct_count
+        }
+        PhysicalType::Int96 => {
+            stats_any
+                .downcast_ref::<PrimitiveStatistics<[u32; 3]>>()?
+                .distinct_count
+        }
+        PhysicalType::Float => {
+            stats_any
+                .downcast_ref::<PrimitiveStatistics<f32>>()?
+                .distinct_count
+        }
+        PhysicalType::Double => {
+            stats_any
+                .downcast_ref::<PrimitiveStatistics<f64>>()?
+                .distinct_count
+        }
+        PhysicalType::ByteArray => stats_any.downcast_ref::<BinaryStatistics>()?.distinct_count,
+        PhysicalType::FixedLenByteArray(_) => {
+            stats_any
+                .downcast_ref::<FixedLenStatistics>()?
+                .distinct_count
+        }
     }
 }
 
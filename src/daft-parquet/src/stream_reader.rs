@@ -1,8 +1,12 @@
-use std::{collections::HashSet, fs::File};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+};
 
 use arrow2::io::parquet::read;
 use common_error::DaftResult;
 use daft_core::{utils::arrow::cast_array_for_daft_if_needed, Series};
+use daft_io::IOStatsRef;
 use daft_table::Table;
 use itertools::Itertools;
 use rayon::prelude::{IndexedParallelIterator, IntoParallelIterator, ParallelBridge};
@@ -10,38 +14,77 @@ use snafu::ResultExt;
 
 use crate::{
     file::build_row_ranges,
+    projection::{is_projected_leaf, project_schema, resolve_field_projections, FieldProjections},
     read::{ArrowChunk, ParquetSchemaInferenceOptions},
 };
 
-use crate::stream_reader::read::schema::infer_schema_with_options;
 use rayon::iter::ParallelIterator;
 
 fn prune_fields_from_schema(
     schema: arrow2::datatypes::Schema,
     columns: Option<&[String]>,
     uri: &str,
-) -> super::Result<arrow2::datatypes::Schema> {
+) -> super::Result<(arrow2::datatypes::Schema, FieldProjections)> {
     if let Some(columns) = columns {
-        let avail_names = schema
-            .fields
-            .iter()
-            .map(|f| f.name.as_str())
-            .collect::<HashSet<_>>();
-        let mut names_to_keep = HashSet::new();
-        for col_name in columns {
-            if avail_names.contains(col_name.as_str()) {
-                names_to_keep.insert(col_name.to_string());
-            } else {
-                return Err(super::Error::FieldNotFound {
-                    field: col_name.to_string(),
-                    available_fields: avail_names.iter().map(|v| v.to_string()).collect(),
-                    path: uri.to_string(),
-                });
-            }
-        }
-        Ok(schema.filter(|_, field| names_to_keep.contains(&field.name)))
+        let projections = resolve_field_projections(&schema, columns, uri)?;
+        Ok((project_schema(schema, &projections), projections))
     } else {
-        Ok(schema)
+        Ok((schema, FieldProjections::new()))
+    }
+}
+
+/// Like [`read::read_columns_many`], but only reads the leaf columns of the projected subfields of each field.
+fn read_projected_columns_many<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &read::RowGroupMetaData,
+    fields: Vec<arrow2::datatypes::Field>,
+    projections: &FieldProjections,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+) -> arrow2::error::Result<Vec<read::ArrayIter<'static>>> {
+    let num_rows = limit.map_or(row_group.num_rows(), |limit| {
+        limit.min(row_group.num_rows())
+    });
+    fields
+        .into_iter()
+        .map(|field| {
+            let columns = row_group
+                .columns()
+                .iter()
+                .filter(|meta| {
+                    is_projected_leaf(projections, &field.name, &meta.descriptor().path_in_schema)
+                })
+                .map(|meta| {
+                    let (start, length) = meta.byte_range();
+                    reader.seek(SeekFrom::Start(start))?;
+                    let mut chunk = vec![0; length as usize];
+                    reader.read_exact(&mut chunk)?;
+                    Ok((meta, chunk))
+                })
+                .collect::<arrow2::error::Result<Vec<_>>>()?;
+            read::to_deserializer(columns, field, num_rows, chunk_size, None)
+        })
+        .collect()
+}
+
+/// Reader that counts the bytes read through it, as local reads don't go through an [`daft_io::IOClient`] that
+/// reports them to the IO stats.
+struct CountingReader<R> {
+    inner: R,
+    bytes_read: usize,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes_read = self.inner.read(buf)?;
+        self.bytes_read += bytes_read;
+        Ok(bytes_read)
+    }
+}
+
+impl<R: Seek> Seek for CountingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
     }
 }
 
@@ -52,6 +95,7 @@ pub(crate) fn local_parquet_read_into_arrow(
     num_rows: Option<usize>,
     row_groups: Option<&[i64]>,
     schema_infer_options: ParquetSchemaInferenceOptions,
+    io_stats: Option<IOStatsRef>,
 ) -> super::Result<(
     parquet2::metadata::FileMetaData,
     arrow2::datatypes::Schema,
@@ -61,21 +105,55 @@ pub(crate) fn local_parquet_read_into_arrow(
 
     let uri = uri.strip_prefix(LOCAL_PROTOCOL).unwrap_or(uri);
 
-    let mut reader = File::open(uri).with_context(|_| super::InternalIOSnafu {
+    let file = File::open(uri).with_context(|_| super::InternalIOSnafu {
         path: uri.to_string(),
     })?;
-    let metadata = read::read_metadata(&mut reader).with_context(|_| {
+    let mut reader = CountingReader {
+        inner: file,
+        bytes_read: 0,
+    };
+    let result = parquet_read_into_arrow(
+        &mut reader,
+        uri,
+        columns,
+        start_offset,
+        num_rows,
+        row_groups,
+        schema_infer_options,
+    );
+    if let Some(io_stats) = io_stats {
+        io_stats.mark_bytes_read(reader.bytes_read);
+    }
+    result
+}
+
+/// Reads a Parquet file from any seekable `reader`, e.g. in-memory bytes; `uri` only identifies the file in errors.
+pub(crate) fn parquet_read_into_arrow<R: Read + Seek + Send>(
+    reader: &mut R,
+    uri: &str,
+    columns: Option<&[String]>,
+    start_offset: Option<usize>,
+    num_rows: Option<usize>,
+    row_groups: Option<&[i64]>,
+    schema_infer_options: ParquetSchemaInferenceOptions,
+) -> super::Result<(
+    parquet2::metadata::FileMetaData,
+    arrow2::datatypes::Schema,
+    Vec<ArrowChunk>,
+)> {
+    let metadata = read::read_metadata(reader).with_context(|_| {
         super::UnableToParseMetadataFromLocalFileSnafu {
             path: uri.to_string(),
         }
     })?;
 
     // and infer a [`Schema`] from the `metadata`.
-    let schema = infer_schema_with_options(&metadata, &Some(schema_infer_options.into()))
+    let schema = schema_infer_options
+        .infer_arrow_schema(&metadata)
         .with_context(|_| super::UnableToParseSchemaFromMetadataSnafu {
             path: uri.to_string(),
         })?;
-    let schema = prune_fields_from_schema(schema, columns, uri)?;
+    let (schema, projections) = prune_fields_from_schema(schema, columns, uri)?;
     let chunk_size = 128 * 1024;
     let expected_rows = metadata.num_rows.min(num_rows.unwrap_or(metadata.num_rows));
 
@@ -93,13 +171,13 @@ pub(crate) fn local_parquet_read_into_arrow(
         .enumerate()
         .map(|(req_idx, rg_range)| {
             let rg = metadata.row_groups.get(rg_range.row_group_index).unwrap();
-            let single_rg_column_iter = read::read_columns_many(
-                &mut reader,
+            let single_rg_column_iter = read_projected_columns_many(
+                reader,
                 rg,
                 schema.fields.clone(),
+                &projections,
                 Some(chunk_size),
                 num_rows,
-                None,
             );
             let single_rg_column_iter = single_rg_column_iter?;
             arrow2::error::Result::Ok(
@@ -160,6 +238,7 @@ pub(crate) async fn local_parquet_read_async(
     num_rows: Option<usize>,
     row_groups: Option<Vec<i64>>,
     schema_infer_options: ParquetSchemaInferenceOptions,
+    io_stats: Option<IOStatsRef>,
 ) -> DaftResult<(parquet2::metadata::FileMetaData, Table)> {
     let (send, recv) = tokio::sync::oneshot::channel();
     let uri = uri.to_string();
@@ -172,23 +251,10 @@ pub(crate) async fn local_parquet_read_async(
                 num_rows,
                 row_groups.as_deref(),
                 schema_infer_options,
+                io_stats,
             );
             let (metadata, schema, arrays) = v?;
-
-            let converted_arrays = arrays
-                .into_par_iter()
-                .zip(schema.fields)
-                .map(|(v, f)| {
-                    let casted_arrays = v
-                        .into_iter()
-                        .map(move |a| {
-                            Series::try_from((f.name.as_str(), cast_array_for_daft_if_needed(a)))
-                        })
-                        .collect::<Result<Vec<_>, _>>()?;
-                    Series::concat(casted_arrays.iter().collect::<Vec<_>>().as_slice())
-                })
-                .collect::<Result<Vec<_>, _>>()?;
-            Ok((metadata, Table::from_columns(converted_arrays)?))
+            Ok((metadata, arrow_chunks_into_table(schema, arrays)?))
         })();
         let _ = send.send(result);
     });
@@ -196,6 +262,25 @@ pub(crate) async fn local_parquet_read_async(
     recv.await.context(super::OneShotRecvSnafu {})?
 }
 
+/// Converts the arrays read for each field of `schema` into a table.
+pub(crate) fn arrow_chunks_into_table(
+    schema: arrow2::datatypes::Schema,
+    arrays: Vec<ArrowChunk>,
+) -> DaftResult<Table> {
+    let converted_arrays = arrays
+        .into_par_iter()
+        .zip(schema.fields)
+        .map(|(v, f)| {
+            let casted_arrays = v
+                .into_iter()
+                .map(move |a| Series::try_from((f.name.as_str(), cast_array_for_daft_if_needed(a))))
+                .collect::<Result<Vec<_>, _>>()?;
+            Series::concat(casted_arrays.iter().collect::<Vec<_>>().as_slice())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Table::from_columns(converted_arrays)
+}
+
 pub(crate) async fn local_parquet_read_into_arrow_async(
     uri: &str,
     columns: Option<Vec<String>>,
@@ -203,6 +288,7 @@ pub(crate) async fn local_parquet_read_into_arrow_async(
     num_rows: Option<usize>,
     row_groups: Option<Vec<i64>>,
     schema_infer_options: ParquetSchemaInferenceOptions,
+    io_stats: Option<IOStatsRef>,
 ) -> super::Result<(
     parquet2::metadata::FileMetaData,
     arrow2::datatypes::Schema,
@@ -218,6 +304,7 @@ pub(crate) async fn local_parquet_read_into_arrow_async(
             num_rows,
             row_groups.as_deref(),
             schema_infer_options,
+            io_stats,
         );
         let _ = send.send(v);
     });
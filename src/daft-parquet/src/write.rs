@@ -0,0 +1,131 @@
+use std::{str::FromStr, sync::Arc};
+
+use arrow2::{
+    chunk::Chunk,
+    datatypes::{Field, Schema},
+    io::parquet::write::{
+        transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+        WriteOptions,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::utils::arrow::cast_array_from_daft_if_needed;
+use daft_io::{get_runtime, IOClient};
+use daft_table::Table;
+
+/// Compression codec of the pages of a written Parquet file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParquetCompression {
+    Uncompressed,
+    #[default]
+    Snappy,
+    Gzip,
+    Brotli,
+    Lz4,
+    Zstd,
+}
+
+impl FromStr for ParquetCompression {
+    type Err = DaftError;
+
+    fn from_str(compression: &str) -> DaftResult<Self> {
+        use ParquetCompression::*;
+        match compression.to_lowercase().as_str() {
+            "uncompressed" | "none" => Ok(Uncompressed),
+            "snappy" => Ok(Snappy),
+            "gzip" => Ok(Gzip),
+            "brotli" => Ok(Brotli),
+            "lz4" => Ok(Lz4),
+            "zstd" => Ok(Zstd),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported Parquet compression: {compression}; expected one of uncompressed, snappy, gzip, brotli, lz4 or zstd"
+            ))),
+        }
+    }
+}
+
+impl From<ParquetCompression> for CompressionOptions {
+    fn from(compression: ParquetCompression) -> Self {
+        use ParquetCompression::*;
+        match compression {
+            Uncompressed => CompressionOptions::Uncompressed,
+            Snappy => CompressionOptions::Snappy,
+            Gzip => CompressionOptions::Gzip(None),
+            Brotli => CompressionOptions::Brotli(None),
+            Lz4 => CompressionOptions::Lz4Raw,
+            Zstd => CompressionOptions::Zstd(None),
+        }
+    }
+}
+
+/// Writes a table as Parquet to the file at `uri`, splitting it into row groups of at most `row_group_size` rows, or
+/// a single row group if unset. Returns the URI of the written file.
+///
+/// The file is written through `io_client`, which never exposes a partially written file to readers: local files are
+/// written to a temporary file next to them and renamed into place, and S3 objects are only exposed once their
+/// upload completes.
+pub fn write_parquet(
+    table: &Table,
+    uri: &str,
+    compression: ParquetCompression,
+    row_group_size: Option<usize>,
+    io_client: Arc<IOClient>,
+) -> DaftResult<String> {
+    if row_group_size == Some(0) {
+        return Err(DaftError::ValueError(
+            "Parquet row group size must be positive".to_string(),
+        ));
+    }
+
+    let columns = table
+        .column_names()
+        .iter()
+        .map(|name| {
+            Ok(cast_array_from_daft_if_needed(
+                table.get_column(name)?.to_arrow(),
+            ))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let schema = Schema::from(
+        table
+            .column_names()
+            .iter()
+            .zip(columns.iter())
+            .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: compression.into(),
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|field| transverse(&field.data_type, |_| Encoding::Plain))
+        .collect();
+    let row_group_size = row_group_size.unwrap_or(table.len()).max(1);
+    let row_groups = (0..table.len()).step_by(row_group_size).map(|start| {
+        let len = row_group_size.min(table.len() - start);
+        Ok(Chunk::new(
+            columns
+                .iter()
+                .map(|array| array.sliced(start, len))
+                .collect(),
+        ))
+    });
+    let row_groups = RowGroupIterator::try_new(row_groups, &schema, options, encodings)?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, options)?;
+    for row_group in row_groups {
+        writer.write(row_group?)?;
+    }
+    writer.end(None)?;
+    let buffer = writer.into_inner();
+
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(io_client.single_url_put(uri, buffer.into(), None))?;
+    Ok(uri.to_string())
+}
@@ -34,9 +34,12 @@ impl Project {
         let projected_schema = {
             let fields = factored_projection
                 .iter()
-                .map(|e| e.to_field(&upstream_schema))
+                .map(|e| e.to_projected_fields(&upstream_schema))
                 .collect::<common_error::DaftResult<Vec<_>>>()
-                .context(CreationSnafu)?;
+                .context(CreationSnafu)?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
             Schema::new(fields).context(CreationSnafu)?.into()
         };
         let partition_spec =
@@ -1,9 +1,16 @@
 use std::fmt::Display;
 
 use common_error::DaftResult;
-use daft_core::schema::SchemaRef;
+use daft_core::{
+    datatypes::Field,
+    schema::{Schema, SchemaRef},
+};
+use daft_dsl::Expr;
 
-use crate::{DataFileSource, FileType, ScanOperator, ScanOperatorRef, ScanTask};
+use crate::{
+    hive::infer_hive_partitions, DataFileSource, FileType, PartitionField, ScanOperator,
+    ScanOperatorRef, ScanTask,
+};
 #[derive(Debug)]
 pub struct AnonymousScanOperator {
     schema: SchemaRef,
@@ -11,17 +18,52 @@ pub struct AnonymousScanOperator {
     files: Vec<String>,
     columns_to_select: Option<Vec<String>>,
     limit: Option<usize>,
+    partitioning_keys: Vec<Field>,
+    // Values of the partitioning keys for each file, if any.
+    partition_fields: Vec<Vec<PartitionField>>,
+    filters: Option<Expr>,
 }
 
 impl AnonymousScanOperator {
-    pub fn new(schema: SchemaRef, file_type: FileType, files: Vec<String>) -> Self {
-        Self {
+    /// Creates a scan over `files`. With `hive_partitioning`, the `key=value` directories of the file paths, e.g.
+    /// `year=2023/month=01`, are read as partition columns, which are added to `schema` if it doesn't have them.
+    pub fn new(
+        schema: SchemaRef,
+        file_type: FileType,
+        files: Vec<String>,
+        hive_partitioning: bool,
+    ) -> DaftResult<Self> {
+        let (partitioning_keys, partition_fields) = if hive_partitioning {
+            infer_hive_partitions(&files)?
+        } else {
+            (vec![], vec![])
+        };
+        let schema = if partitioning_keys.is_empty() {
+            schema
+        } else {
+            let fields = schema
+                .fields
+                .values()
+                .cloned()
+                .chain(
+                    partitioning_keys
+                        .iter()
+                        .filter(|key| schema.get_field(&key.name).is_err())
+                        .cloned(),
+                )
+                .collect::<Vec<_>>();
+            Schema::new(fields)?.into()
+        };
+        Ok(Self {
             schema,
             file_type,
             files,
             columns_to_select: None,
             limit: None,
-        }
+            partitioning_keys,
+            partition_fields,
+            filters: None,
+        })
     }
 }
 
@@ -37,7 +79,7 @@ impl ScanOperator for AnonymousScanOperator {
     }
 
     fn partitioning_keys(&self) -> &[daft_core::datatypes::Field] {
-        &[]
+        &self.partitioning_keys
     }
 
     fn num_partitions(&self) -> common_error::DaftResult<usize> {
@@ -49,7 +91,7 @@ impl ScanOperator for AnonymousScanOperator {
             if self.schema.get_field(c).is_err() {
                 return Err(common_error::DaftError::FieldNotFound(format!(
                     "{c} not found in {:?}",
-                    self.columns_to_select
+                    self.schema.names()
                 )));
             }
         }
@@ -64,27 +106,245 @@ impl ScanOperator for AnonymousScanOperator {
         Ok(to_rtn)
     }
 
-    fn filter(self: Box<Self>, _predicate: &daft_dsl::Expr) -> DaftResult<(bool, ScanOperatorRef)> {
-        Ok((false, self))
+    fn filter(self: Box<Self>, predicate: &Expr) -> DaftResult<(bool, ScanOperatorRef)> {
+        // The predicate is only used to prune files by their partition values, so it isn't absorbed and still has to
+        // be applied to the rows that are read.
+        let mut to_rtn = self;
+        to_rtn.filters = Some(match to_rtn.filters.take() {
+            Some(filters) => filters.and(predicate),
+            None => predicate.clone(),
+        });
+        Ok((false, to_rtn))
     }
 
     fn to_scan_tasks(
         self: Box<Self>,
     ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<crate::ScanTask>>>> {
-        let iter = self.files.clone().into_iter().map(move |f| {
-            let source = DataFileSource::AnonymousDataFile {
-                file_type: self.file_type,
-                path: f,
-                metadata: None,
-                partition_spec: None,
-                statistics: None,
+        let iter = self
+            .files
+            .clone()
+            .into_iter()
+            .enumerate()
+            .filter_map(move |(i, f)| {
+                let partition_fields = self.partition_fields.get(i).cloned().unwrap_or_default();
+                // Files whose partition values can't satisfy the filters are skipped.
+                if let Some(filters) = &self.filters {
+                    match self.partition_may_match(filters, &partition_fields) {
+                        Ok(true) => {}
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                let source = DataFileSource::AnonymousDataFile {
+                    file_type: self.file_type,
+                    path: f,
+                    metadata: None,
+                    partition_spec: None,
+                    statistics: None,
+                };
+                Some(Ok(ScanTask {
+                    source,
+                    columns: self.columns_to_select.clone(),
+                    limit: self.limit,
+                    partition_fields,
+                    filters: self.filters.clone(),
+                }))
+            });
+        Ok(Box::new(iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, schema::Schema, DataType};
+    use daft_dsl::{col, lit, LiteralValue};
+
+    use super::AnonymousScanOperator;
+    use crate::{DataFileSource, FileType, ScanOperator, ScanOperatorRef};
+
+    fn make_operator() -> DaftResult<ScanOperatorRef> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Float64),
+        ])?;
+        let files = ["file1.parquet", "file2.parquet", "file3.parquet"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        Ok(Box::new(AnonymousScanOperator::new(
+            Arc::new(schema),
+            FileType::Parquet,
+            files,
+            false,
+        )?))
+    }
+
+    #[test]
+    fn test_anonymous_scan_tasks_with_pushdowns() -> DaftResult<()> {
+        let operator = make_operator()?;
+        assert_eq!(operator.num_partitions()?, 3);
+        assert_eq!(operator.schema().names(), vec!["a", "b", "c"]);
+
+        let operator = operator.select(&["c", "a"])?.limit(10)?;
+        let scan_tasks = operator.to_scan_tasks()?.collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(scan_tasks.len(), 3);
+        for (scan_task, expected_path) in
+            scan_tasks
+                .iter()
+                .zip(["file1.parquet", "file2.parquet", "file3.parquet"])
+        {
+            let DataFileSource::AnonymousDataFile {
+                file_type, path, ..
+            } = &scan_task.source
+            else {
+                panic!("Expected an anonymous data file");
             };
-            Ok(ScanTask {
-                source,
-                columns: self.columns_to_select.clone(),
-                limit: self.limit,
+            assert!(matches!(file_type, FileType::Parquet));
+            assert_eq!(path, expected_path);
+            assert_eq!(
+                scan_task.columns,
+                Some(vec!["c".to_string(), "a".to_string()])
+            );
+            assert_eq!(scan_task.limit, Some(10));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymous_scan_tasks_json() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let operator = AnonymousScanOperator::new(
+            Arc::new(schema),
+            FileType::Json,
+            vec!["part-0.jsonl".to_string(), "part-1.jsonl".to_string()],
+            false,
+        )?;
+        let scan_tasks = Box::new(operator)
+            .to_scan_tasks()?
+            .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(scan_tasks.len(), 2);
+        for scan_task in scan_tasks {
+            assert_eq!(scan_task.source().file_type(), FileType::Json);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymous_scan_hive_partitioning() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let files = [
+            "s3://bucket/table/year=2022/month=12/region=eu/part-0.parquet",
+            "s3://bucket/table/year=2023/month=01/region=us/part-0.parquet",
+            "s3://bucket/table/year=2023/month=02/region=__HIVE_DEFAULT_PARTITION__/part-0.parquet",
+        ];
+        let operator: ScanOperatorRef = Box::new(AnonymousScanOperator::new(
+            Arc::new(schema),
+            FileType::Parquet,
+            files.iter().map(|f| f.to_string()).collect(),
+            true,
+        )?);
+        let expected_keys = vec![
+            Field::new("year", DataType::Int64),
+            Field::new("month", DataType::Int64),
+            Field::new("region", DataType::Utf8),
+        ];
+        assert_eq!(operator.partitioning_keys(), expected_keys.as_slice());
+        assert_eq!(
+            operator.schema().names(),
+            vec!["a", "year", "month", "region"]
+        );
+
+        let scan_tasks = operator.to_scan_tasks()?.collect::<DaftResult<Vec<_>>>()?;
+        let values = scan_tasks
+            .iter()
+            .map(|scan_task| {
+                scan_task
+                    .partition_fields()
+                    .iter()
+                    .map(|partition_field| partition_field.value.clone())
+                    .collect::<Vec<_>>()
             })
-        });
-        Ok(Box::new(iter))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                vec![
+                    LiteralValue::Int64(2022),
+                    LiteralValue::Int64(12),
+                    LiteralValue::Utf8("eu".to_string())
+                ],
+                vec![
+                    LiteralValue::Int64(2023),
+                    LiteralValue::Int64(1),
+                    LiteralValue::Utf8("us".to_string())
+                ],
+                vec![
+                    LiteralValue::Int64(2023),
+                    LiteralValue::Int64(2),
+                    LiteralValue::Null
+                ],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymous_scan_hive_partitioning_mismatched_keys() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let files = vec![
+            "table/year=2022/part-0.parquet".to_string(),
+            "table/month=01/part-0.parquet".to_string(),
+        ];
+        assert!(
+            AnonymousScanOperator::new(Arc::new(schema), FileType::Parquet, files, true).is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymous_scan_prunes_partitions() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let files = [
+            "table/year=2022/part-0.parquet",
+            "table/year=2023/part-0.parquet",
+            "table/year=2023/part-1.parquet",
+        ];
+        let operator: ScanOperatorRef = Box::new(AnonymousScanOperator::new(
+            Arc::new(schema),
+            FileType::Parquet,
+            files.iter().map(|f| f.to_string()).collect(),
+            true,
+        )?);
+        let predicate = col("year").eq(&lit(2023i64)).and(&col("a").gt(&lit(0i64)));
+        let (absorbed, operator) = operator.filter(&predicate)?;
+        assert!(!absorbed);
+
+        let scan_tasks = operator.to_scan_tasks()?.collect::<DaftResult<Vec<_>>>()?;
+        let paths = scan_tasks
+            .iter()
+            .map(|scan_task| scan_task.source().path())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, files[1..].to_vec());
+        for scan_task in &scan_tasks {
+            assert_eq!(scan_task.filters(), Some(&predicate));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymous_scan_select_missing_column() -> DaftResult<()> {
+        let operator = make_operator()?;
+        assert!(operator.select(&["a", "d"]).is_err());
+
+        Ok(())
     }
 }
@@ -0,0 +1,91 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{datatypes::Field, DataType};
+use daft_dsl::LiteralValue;
+
+use crate::PartitionField;
+
+/// Partition value that Hive writes for rows whose partition column is null.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Parses the `key=value` directory segments of a Hive-style partitioned path, e.g. `year=2023` and `month=01` in
+/// `s3://bucket/year=2023/month=01/part-0.parquet`, in order.
+fn parse_hive_partitions(path: &str) -> Vec<(&str, &str)> {
+    let mut segments = path.split('/').collect::<Vec<_>>();
+    // The last segment is the file name.
+    segments.pop();
+    segments
+        .into_iter()
+        .filter_map(|segment| segment.split_once('='))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+/// Infers the partition columns of Hive-style partitioned `files`, and the values of those columns for each file.
+///
+/// All files must have the same partition keys, in the same order. A partition column whose values all parse as
+/// integers is read as Int64, and otherwise as Utf8.
+pub(crate) fn infer_hive_partitions(
+    files: &[String],
+) -> DaftResult<(Vec<Field>, Vec<Vec<PartitionField>>)> {
+    let parsed = files
+        .iter()
+        .map(|file| parse_hive_partitions(file))
+        .collect::<Vec<_>>();
+    let Some(first) = parsed.first() else {
+        return Ok((vec![], vec![]));
+    };
+    let keys = first.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    for (file, partitions) in files.iter().zip(&parsed) {
+        if !partitions
+            .iter()
+            .map(|(key, _)| *key)
+            .eq(keys.iter().copied())
+        {
+            return Err(DaftError::ValueError(format!(
+                "Hive partition keys of {file} don't match the partition keys {keys:?} of {}",
+                files[0]
+            )));
+        }
+    }
+    let fields = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let all_integers = parsed.iter().all(|partitions| {
+                let value = partitions[i].1;
+                value == HIVE_DEFAULT_PARTITION || value.parse::<i64>().is_ok()
+            });
+            let dtype = if all_integers {
+                DataType::Int64
+            } else {
+                DataType::Utf8
+            };
+            Field::new(*key, dtype)
+        })
+        .collect::<Vec<_>>();
+    let values = parsed
+        .iter()
+        .map(|partitions| {
+            fields
+                .iter()
+                .zip(partitions)
+                .map(|(field, (_, value))| {
+                    let value = match (&field.dtype, *value) {
+                        (_, HIVE_DEFAULT_PARTITION) => LiteralValue::Null,
+                        (DataType::Int64, value) => LiteralValue::Int64(
+                            value
+                                .parse()
+                                .expect("Integer partition values were checked"),
+                        ),
+                        (_, value) => LiteralValue::Utf8(value.to_string()),
+                    };
+                    PartitionField {
+                        field: field.clone(),
+                        value,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    Ok((fields, values))
+}
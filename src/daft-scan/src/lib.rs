@@ -5,22 +5,27 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{datatypes::Field, schema::SchemaRef};
-use daft_dsl::Expr;
+use daft_dsl::{Expr, LiteralValue};
 use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use serde::{Deserialize, Serialize};
 
 mod anonymous;
+pub use anonymous::AnonymousScanOperator;
+mod hive;
+mod pruning;
 #[cfg(feature = "python")]
 pub mod python;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FileType {
     Parquet,
     Avro,
     Orc,
     Csv,
+    /// Line-delimited JSON.
+    Json,
 }
 
 impl FromStr for FileType {
@@ -36,6 +41,10 @@ impl FromStr for FileType {
             Ok(Orc)
         } else if file_type.trim().eq_ignore_ascii_case("csv") {
             Ok(Csv)
+        } else if file_type.trim().eq_ignore_ascii_case("json")
+            || file_type.trim().eq_ignore_ascii_case("jsonl")
+        {
+            Ok(Json)
         } else {
             Err(DaftError::TypeError(format!(
                 "FileType {} not supported!",
@@ -63,12 +72,75 @@ pub enum DataFileSource {
     },
 }
 
+impl DataFileSource {
+    pub fn file_type(&self) -> FileType {
+        match self {
+            Self::AnonymousDataFile { file_type, .. } | Self::CatalogDataFile { file_type, .. } => {
+                *file_type
+            }
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        match self {
+            Self::AnonymousDataFile { path, .. } | Self::CatalogDataFile { path, .. } => path,
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&TableMetadata> {
+        match self {
+            Self::AnonymousDataFile { metadata, .. } => metadata.as_ref(),
+            Self::CatalogDataFile { metadata, .. } => Some(metadata),
+        }
+    }
+
+    pub fn statistics(&self) -> Option<&TableStatistics> {
+        match self {
+            Self::AnonymousDataFile { statistics, .. }
+            | Self::CatalogDataFile { statistics, .. } => statistics.as_ref(),
+        }
+    }
+}
+
+/// A partition column of a scan, with its value for a single file, e.g. `year=2023` in a Hive-style partitioned path.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionField {
+    pub field: Field,
+    pub value: LiteralValue,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ScanTask {
     // Micropartition will take this in as an input
     source: DataFileSource,
     columns: Option<Vec<String>>,
     limit: Option<usize>,
+    // Values of the partition columns for the file, which aren't stored in the file itself.
+    partition_fields: Vec<PartitionField>,
+    // Predicate pushed down into the scan, which rows read for this task still have to satisfy.
+    filters: Option<Expr>,
+}
+
+impl ScanTask {
+    pub fn source(&self) -> &DataFileSource {
+        &self.source
+    }
+
+    pub fn columns(&self) -> Option<&[String]> {
+        self.columns.as_deref()
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn partition_fields(&self) -> &[PartitionField] {
+        &self.partition_fields
+    }
+
+    pub fn filters(&self) -> Option<&Expr> {
+        self.filters.as_ref()
+    }
 }
 
 pub trait ScanOperator: Send + Display {
@@ -80,8 +152,39 @@ pub trait ScanOperator: Send + Display {
     fn filter(self: Box<Self>, predicate: &Expr) -> DaftResult<(bool, ScanOperatorRef)>;
     fn select(self: Box<Self>, columns: &[&str]) -> DaftResult<ScanOperatorRef>;
     fn limit(self: Box<Self>, num: usize) -> DaftResult<ScanOperatorRef>;
+
+    /// Whether a file with the provided partition values may contain rows that satisfy `predicate`, which is only false
+    /// if the partition values provably fail it, so that the file can be skipped without being read.
+    fn partition_may_match(
+        &self,
+        predicate: &Expr,
+        partition_fields: &[PartitionField],
+    ) -> DaftResult<bool> {
+        pruning::partition_may_match(predicate, partition_fields)
+    }
+
     fn to_scan_tasks(self: Box<Self>)
         -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTask>>>>;
 }
 
 pub type ScanOperatorRef = Box<dyn ScanOperator>;
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common_error::DaftResult;
+
+    use crate::FileType;
+
+    #[test]
+    fn test_file_type_from_str() -> DaftResult<()> {
+        assert_eq!(FileType::from_str("csv")?, FileType::Csv);
+        assert_eq!(FileType::from_str(" Parquet ")?, FileType::Parquet);
+        assert_eq!(FileType::from_str("json")?, FileType::Json);
+        assert_eq!(FileType::from_str("JSONL")?, FileType::Json);
+        assert!(FileType::from_str("xlsx").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use common_error::DaftResult;
+use daft_dsl::{optimization::get_required_columns, Expr, Operator};
+use daft_table::Table;
+
+use crate::PartitionField;
+
+/// Splits a predicate into the expressions that are AND-ed together in it.
+fn split_conjunction(predicate: &Expr) -> Vec<&Expr> {
+    match predicate {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = split_conjunction(left);
+            conjuncts.extend(split_conjunction(right));
+            conjuncts
+        }
+        _ => vec![predicate],
+    }
+}
+
+/// Whether a file with the provided partition values may contain rows that satisfy `predicate`.
+///
+/// Partition columns hold a single value per file, so every conjunct of `predicate` that only references partition
+/// columns is evaluated exactly against a single row of those values. The file can only be skipped if one of them
+/// doesn't hold; conjuncts that reference other columns are left to be evaluated against the rows of the file.
+pub(crate) fn partition_may_match(
+    predicate: &Expr,
+    partition_fields: &[PartitionField],
+) -> DaftResult<bool> {
+    let conjuncts = split_conjunction(predicate)
+        .into_iter()
+        .filter(|conjunct| {
+            get_required_columns(conjunct).iter().all(|column| {
+                partition_fields
+                    .iter()
+                    .any(|partition_field| &partition_field.field.name == column)
+            })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if conjuncts.is_empty() {
+        return Ok(true);
+    }
+    let partition_values = Table::from_columns(
+        partition_fields
+            .iter()
+            .map(|partition_field| {
+                let field = &partition_field.field;
+                partition_field
+                    .value
+                    .to_series()
+                    .rename(&field.name)
+                    .cast(&field.dtype)
+            })
+            .collect::<DaftResult<Vec<_>>>()?,
+    )?;
+    Ok(!partition_values.filter(&conjuncts)?.is_empty())
+}
@@ -1,4 +1,15 @@
-use pyo3::prelude::*;
+use std::fmt::Display;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::Field,
+    python::{field::PyField, schema::PySchema},
+    schema::SchemaRef,
+};
+use daft_dsl::{python::PyExpr, Expr};
+use pyo3::{prelude::*, types::PyList};
+
+use crate::{ScanOperator, ScanOperatorRef, ScanTask};
 
 pub mod pylib {
     use pyo3::prelude::*;
@@ -8,35 +19,234 @@ pub mod pylib {
 
     use pyo3::pyclass;
 
+    use super::PythonScanOperatorBridge;
     use crate::anonymous::AnonymousScanOperator;
     use crate::FileType;
+    use crate::ScanOperator as _;
     use crate::ScanOperatorRef;
 
-    #[pyclass(module = "daft.daft", frozen)]
+    #[pyclass(module = "daft.daft")]
     pub(crate) struct ScanOperator {
-        scan_op: ScanOperatorRef,
+        // Pushdowns consume the operator, so it's taken out of this wrapper when one is applied.
+        scan_op: Option<ScanOperatorRef>,
+    }
+
+    impl ScanOperator {
+        fn take(&mut self) -> PyResult<ScanOperatorRef> {
+            self.scan_op.take().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(
+                    "ScanOperator was already consumed by a pushdown",
+                )
+            })
+        }
     }
 
     #[pymethods]
     impl ScanOperator {
         pub fn __repr__(&self) -> PyResult<String> {
-            Ok(format!("{}", self.scan_op))
+            match &self.scan_op {
+                Some(scan_op) => Ok(format!("{}", scan_op)),
+                None => Ok("ScanOperator(consumed)".to_string()),
+            }
         }
 
         #[staticmethod]
+        #[pyo3(signature = (schema, file_type, files, hive_partitioning=false))]
         pub fn anonymous_scan(
             schema: PySchema,
             file_type: &str,
             files: Vec<String>,
+            hive_partitioning: bool,
         ) -> PyResult<Self> {
             let schema = schema.schema;
             let operator = Box::new(AnonymousScanOperator::new(
                 schema,
                 FileType::from_str(file_type)?,
                 files,
-            ));
-            Ok(ScanOperator { scan_op: operator })
+                hive_partitioning,
+            )?);
+            Ok(ScanOperator {
+                scan_op: Some(operator),
+            })
+        }
+
+        /// Wraps a scan operator implemented in Python, see [`PythonScanOperatorBridge`].
+        #[staticmethod]
+        pub fn python_scan(py: Python, operator: PyObject) -> PyResult<Self> {
+            let operator = Box::new(PythonScanOperatorBridge::new(py, operator)?);
+            Ok(ScanOperator {
+                scan_op: Some(operator),
+            })
+        }
+
+        /// Pushes a projection into this operator, returning the resulting operator; this operator is consumed.
+        pub fn select(&mut self, columns: Vec<&str>) -> PyResult<Self> {
+            let scan_op = self.take()?.select(&columns)?;
+            Ok(ScanOperator {
+                scan_op: Some(scan_op),
+            })
         }
+
+        /// Pushes a limit into this operator, returning the resulting operator; this operator is consumed.
+        pub fn limit(&mut self, num: usize) -> PyResult<Self> {
+            let scan_op = self.take()?.limit(num)?;
+            Ok(ScanOperator {
+                scan_op: Some(scan_op),
+            })
+        }
+    }
+}
+
+/// Bridges a scan operator implemented in Python to the [`ScanOperator`] trait.
+///
+/// The Python operator provides the following methods, where pushdowns return a new operator, or `NotImplemented` if
+/// the operator can't absorb them:
+/// * `schema() -> daft.Schema`
+/// * `partitioning_keys() -> list[daft.Field]` (optional, defaults to no partitioning keys)
+/// * `num_partitions() -> int`
+/// * `filter(predicate: PyExpr) -> tuple[bool, operator]`, where the bool indicates whether the predicate was absorbed
+/// * `select(columns: list[str]) -> operator`
+/// * `limit(num: int) -> operator`
+pub struct PythonScanOperatorBridge {
+    operator: PyObject,
+    // Fetched once per operator, since `ScanOperator::schema` can't surface errors.
+    schema: SchemaRef,
+    partitioning_keys: Vec<Field>,
+}
+
+impl PythonScanOperatorBridge {
+    pub fn new(py: Python, operator: PyObject) -> PyResult<Self> {
+        let schema = Self::fetch_schema(py, &operator)?;
+        let partitioning_keys = if operator.as_ref(py).hasattr("partitioning_keys")? {
+            operator
+                .call_method0(py, "partitioning_keys")?
+                .as_ref(py)
+                .iter()?
+                .map(|field| Ok(field?.getattr("_field")?.extract::<PyField>()?.field))
+                .collect::<PyResult<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+        Ok(Self {
+            operator,
+            schema,
+            partitioning_keys,
+        })
+    }
+
+    fn fetch_schema(py: Python, operator: &PyObject) -> PyResult<SchemaRef> {
+        Ok(operator
+            .call_method0(py, "schema")?
+            .getattr(py, "_schema")?
+            .extract::<PySchema>(py)?
+            .schema)
+    }
+
+    fn _filter(&self, py: Python, predicate: &Expr) -> PyResult<(bool, ScanOperatorRef)> {
+        let result = self.operator.call_method1(
+            py,
+            "filter",
+            (PyExpr {
+                expr: predicate.clone(),
+            },),
+        )?;
+        if result.is(&py.NotImplemented()) {
+            return Ok((false, Box::new(self.unchanged(py))));
+        }
+        let (absorbed, operator) = result.extract::<(bool, PyObject)>(py)?;
+        Ok((absorbed, Box::new(self.with_operator(py, operator)?)))
+    }
+
+    fn _select(&self, py: Python, columns: &[&str]) -> PyResult<ScanOperatorRef> {
+        let result = self
+            .operator
+            .call_method1(py, "select", (PyList::new(py, columns),))?;
+        // An operator that can't absorb the projection is returned unchanged, and the projection is applied after the
+        // scan.
+        if result.is(&py.NotImplemented()) {
+            return Ok(Box::new(self.unchanged(py)));
+        }
+        Ok(Box::new(self.with_operator(py, result)?))
+    }
+
+    fn _limit(&self, py: Python, num: usize) -> PyResult<ScanOperatorRef> {
+        let result = self.operator.call_method1(py, "limit", (num,))?;
+        // An operator that can't absorb the limit is returned unchanged, and the limit is applied after the scan.
+        if result.is(&py.NotImplemented()) {
+            return Ok(Box::new(self.unchanged(py)));
+        }
+        Ok(Box::new(self.with_operator(py, result)?))
+    }
+
+    fn unchanged(&self, py: Python) -> Self {
+        Self {
+            operator: self.operator.clone_ref(py),
+            schema: self.schema.clone(),
+            partitioning_keys: self.partitioning_keys.clone(),
+        }
+    }
+
+    /// Wraps the operator returned by a pushdown, whose schema may differ from this one's, e.g. after a projection.
+    fn with_operator(&self, py: Python, operator: PyObject) -> PyResult<Self> {
+        Ok(Self {
+            schema: Self::fetch_schema(py, &operator)?,
+            operator,
+            partitioning_keys: self.partitioning_keys.clone(),
+        })
+    }
+}
+
+impl Display for PythonScanOperatorBridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PythonScanOperatorBridge({})", self.operator)
+    }
+}
+
+impl ScanOperator for PythonScanOperatorBridge {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[Field] {
+        &self.partitioning_keys
+    }
+
+    fn num_partitions(&self) -> DaftResult<usize> {
+        Python::with_gil(|py| {
+            Ok(self
+                .operator
+                .call_method0(py, "num_partitions")?
+                .extract::<usize>(py)?)
+        })
+    }
+
+    fn filter(self: Box<Self>, predicate: &Expr) -> DaftResult<(bool, ScanOperatorRef)> {
+        Python::with_gil(|py| Ok(self._filter(py, predicate)?))
+    }
+
+    fn select(self: Box<Self>, columns: &[&str]) -> DaftResult<ScanOperatorRef> {
+        let schema = self.schema();
+        for c in columns {
+            if schema.get_field(c).is_err() {
+                return Err(DaftError::FieldNotFound(format!(
+                    "{c} not found in {:?}",
+                    schema.names()
+                )));
+            }
+        }
+        Python::with_gil(|py| Ok(self._select(py, columns)?))
+    }
+
+    fn limit(self: Box<Self>, num: usize) -> DaftResult<ScanOperatorRef> {
+        Python::with_gil(|py| Ok(self._limit(py, num)?))
+    }
+
+    fn to_scan_tasks(
+        self: Box<Self>,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTask>>>> {
+        Err(DaftError::ValueError(
+            "Scan tasks can't be generated from Python scan operators yet".to_string(),
+        ))
     }
 }
 
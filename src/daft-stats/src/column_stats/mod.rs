@@ -96,25 +96,12 @@ impl ColumnRangeStatistics {
         }
     }
 
+    /// The bounds of the values of `series`, or `Missing` if its type has no ordering.
     pub fn from_series(series: &Series) -> Self {
-        let lower = series.min(None).unwrap();
-        let upper = series.max(None).unwrap();
-        let _count = series
-            .count(None, daft_core::CountMode::All)
-            .unwrap()
-            .u64()
-            .unwrap()
-            .get(0)
-            .unwrap() as usize;
-        let _null_count = series
-            .count(None, daft_core::CountMode::Null)
-            .unwrap()
-            .u64()
-            .unwrap()
-            .get(0)
-            .unwrap() as usize;
-        let _num_bytes = series.size_bytes().unwrap();
-        Self::Loaded(lower, upper)
+        match (series.min(None), series.max(None)) {
+            (Ok(lower), Ok(upper)) => Self::Loaded(lower, upper),
+            _ => Self::Missing,
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use common_error::DaftError;
 use snafu::Snafu;
 
 mod column_stats;
+mod hyperloglog;
 mod partition_spec;
 mod table_metadata;
 mod table_stats;
@@ -22,6 +23,16 @@ pub enum Error {
     #[snafu(display("MissingStatistics: {}", source))]
     MissingStatistics { source: column_stats::Error },
 
+    #[snafu(display(
+        "Can't union statistics of different columns: {:?} and {:?}",
+        left,
+        right
+    ))]
+    MismatchedColumns {
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
     #[snafu(display(
         "Field: {} not found in Parquet File:  Available Fields: {:?}",
         field,
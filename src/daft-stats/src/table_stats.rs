@@ -1,49 +1,192 @@
 use std::{fmt::Display, ops::Not};
 
-use daft_dsl::Expr;
+use common_error::DaftResult;
+use daft_dsl::{Expr, LiteralValue, Operator};
 use daft_table::Table;
 use indexmap::{IndexMap, IndexSet};
+use snafu::ResultExt;
 
-use crate::column_stats::ColumnRangeStatistics;
+use crate::{column_stats::ColumnRangeStatistics, hyperloglog::HyperLogLog, DaftCoreComputeSnafu};
 
-use daft_core::{array::ops::DaftCompare, schema::Schema};
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, DaftCompare},
+    schema::Schema,
+    CountMode, Series,
+};
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct TableStatistics {
     pub columns: IndexMap<String, ColumnRangeStatistics>,
+    /// Number of rows, if known.
+    pub num_rows: Option<usize>,
+    /// Number of nulls in each column, for the columns where it's known.
+    pub null_counts: IndexMap<String, usize>,
+    /// Approximate number of distinct non-null values in each column, for the columns where it's known.
+    pub distinct_counts: IndexMap<String, usize>,
 }
 
 impl TableStatistics {
-    fn _from_table(table: &Table) -> Self {
-        let mut columns = IndexMap::with_capacity(table.num_columns());
+    /// Statistics with the provided column bounds, and no known null or distinct counts.
+    pub fn new(columns: IndexMap<String, ColumnRangeStatistics>) -> Self {
+        Self {
+            columns,
+            num_rows: None,
+            null_counts: IndexMap::new(),
+            distinct_counts: IndexMap::new(),
+        }
+    }
+
+    /// Computes the bounds and counts of every column of `table`.
+    ///
+    /// Distinct counts are estimated by a HyperLogLog sketch of the value hashes, in memory that doesn't grow with the
+    /// number of rows.
+    pub fn from_table(table: &Table) -> crate::Result<Self> {
+        let mut stats = Self::new(IndexMap::with_capacity(table.num_columns()));
+        stats.num_rows = Some(table.len());
         for name in table.column_names() {
-            let col = table.get_column(&name).unwrap();
-            let stats = ColumnRangeStatistics::from_series(col);
-            columns.insert(name, stats);
+            let col = table.get_column(&name).context(DaftCoreComputeSnafu)?;
+            let null_count = col
+                .count(None, CountMode::Null)
+                .context(DaftCoreComputeSnafu)?
+                .u64()
+                .context(DaftCoreComputeSnafu)?
+                .get(0)
+                .unwrap_or(0) as usize;
+            // Columns without any values have no bounds.
+            let column_stats = if null_count == col.len() {
+                ColumnRangeStatistics::Missing
+            } else {
+                ColumnRangeStatistics::from_series(col)
+            };
+            stats.columns.insert(name.clone(), column_stats);
+            stats.null_counts.insert(name.clone(), null_count);
+            if let Some(distinct_count) = count_distinct(col) {
+                stats.distinct_counts.insert(name, distinct_count);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Number of nulls in `column`, if known.
+    pub fn null_count(&self, column: &str) -> Option<usize> {
+        self.null_counts.get(column).copied()
+    }
+
+    /// Approximate number of distinct non-null values in `column`, if known.
+    pub fn distinct_count(&self, column: &str) -> Option<usize> {
+        self.distinct_counts.get(column).copied()
+    }
+
+    /// These statistics without row, null or distinct counts, e.g. for a subset of the rows that they describe, for
+    /// which the bounds still hold but the counts don't.
+    pub fn without_counts(&self) -> Self {
+        Self::new(self.columns.clone())
+    }
+
+    /// Aligns these statistics with the columns of `schema`, e.g. the unioned schema of files that don't all have the
+    /// same columns. Columns of `schema` that these statistics don't have are all null, and other columns are dropped.
+    pub fn cast_to_schema(&self, schema: &Schema) -> Self {
+        let columns = schema
+            .fields
+            .keys()
+            .map(|name| {
+                let column_stats = self
+                    .columns
+                    .get(name)
+                    .cloned()
+                    .unwrap_or(ColumnRangeStatistics::Missing);
+                (name.clone(), column_stats)
+            })
+            .collect();
+        let mut stats = Self::new(columns).with_counts_from(self);
+        if let Some(num_rows) = self.num_rows {
+            for name in schema.fields.keys() {
+                if !self.columns.contains_key(name) {
+                    stats.null_counts.insert(name.clone(), num_rows);
+                    stats.distinct_counts.insert(name.clone(), 0);
+                }
+            }
         }
-        TableStatistics { columns }
+        stats
+    }
+
+    /// Copies the known counts of `other` for the columns of these statistics, e.g. after projecting or casting the
+    /// columns that `other` describes without changing their rows.
+    pub fn with_counts_from(mut self, other: &Self) -> Self {
+        let counts_of = |counts: &IndexMap<String, usize>| {
+            self.columns
+                .keys()
+                .filter_map(|name| Some((name.clone(), *counts.get(name)?)))
+                .collect()
+        };
+        self.num_rows = other.num_rows;
+        self.null_counts = counts_of(&other.null_counts);
+        self.distinct_counts = counts_of(&other.distinct_counts);
+        self
     }
 }
 
+/// The column that `expr` passes through as-is, possibly under another name.
+fn source_column(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Column(name) => Some(name.as_ref()),
+        Expr::Alias(inner, _) => source_column(inner),
+        _ => None,
+    }
+}
+
+/// Approximates the number of distinct hashes of the non-null values of `series`, or `None` if its type can't be
+/// hashed.
+fn count_distinct(series: &Series) -> Option<usize> {
+    let hashes = series.hash(None).ok()?;
+    let values = series.to_arrow();
+    let mut sketch = HyperLogLog::default();
+    hashes
+        .as_arrow()
+        .values()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| values.is_valid(*i))
+        .for_each(|(_, hash)| sketch.add(*hash));
+    Some(sketch.count())
+}
+
 impl TableStatistics {
+    /// Returns the statistics of the rows of both `self` and `other`, which must have the same columns.
+    ///
+    /// Row and null counts add up, as do distinct counts, since the values of both may not overlap, but distinct counts
+    /// are capped by the number of non-null values when it's known.
     pub fn union(&self, other: &Self) -> crate::Result<Self> {
-        // maybe use the schema from micropartition instead
-        let unioned_columns = self
-            .columns
-            .keys()
-            .chain(other.columns.keys())
-            .collect::<IndexSet<_>>();
-        let mut columns = IndexMap::with_capacity(unioned_columns.len());
-        for col in unioned_columns {
-            let res_col = match (self.columns.get(col), other.columns.get(col)) {
-                (None, None) => panic!("Key missing from both tables; invalid state"),
-                (Some(_l), None) => Ok(ColumnRangeStatistics::Missing),
-                (None, Some(_r)) => Ok(ColumnRangeStatistics::Missing),
-                (Some(l), Some(r)) => l.union(r),
-            }?;
-            columns.insert(col.clone(), res_col);
+        let columns_of = |stats: &Self| stats.columns.keys().cloned().collect::<IndexSet<_>>();
+        if columns_of(self) != columns_of(other) {
+            return Err(crate::Error::MismatchedColumns {
+                left: self.columns.keys().cloned().collect(),
+                right: other.columns.keys().cloned().collect(),
+            });
+        }
+        let mut columns = IndexMap::with_capacity(self.columns.len());
+        for (col, l) in self.columns.iter() {
+            columns.insert(col.clone(), l.union(&other.columns[col])?);
+        }
+        let mut stats = TableStatistics::new(columns);
+        stats.num_rows = self.num_rows.zip(other.num_rows).map(|(l, r)| l + r);
+        for col in self.columns.keys() {
+            if let (Some(l), Some(r)) = (self.null_count(col), other.null_count(col)) {
+                stats.null_counts.insert(col.clone(), l + r);
+            }
+            if let (Some(l), Some(r)) = (self.distinct_count(col), other.distinct_count(col)) {
+                let non_null_count = stats
+                    .num_rows
+                    .zip(stats.null_count(col))
+                    .map(|(num_rows, null_count)| num_rows - null_count);
+                let distinct_count = match non_null_count {
+                    Some(non_null_count) => (l + r).min(non_null_count),
+                    None => l + r,
+                };
+                stats.distinct_counts.insert(col.clone(), distinct_count);
+            }
         }
-        Ok(TableStatistics { columns })
+        Ok(stats)
     }
 
     pub fn eval_expression_list(
@@ -62,9 +205,21 @@ impl TableStatistics {
             .map(|(c, f)| (f.clone(), c))
             .collect::<IndexMap<_, _>>();
 
-        Ok(Self {
-            columns: new_col_stats,
-        })
+        // The rows are the same, and columns that are passed through as-is keep their counts.
+        let mut stats = Self::new(new_col_stats);
+        stats.num_rows = self.num_rows;
+        for (expr, name) in exprs.iter().zip(expected_schema.fields.keys()) {
+            let Some(source) = source_column(expr) else {
+                continue;
+            };
+            if let Some(null_count) = self.null_count(source) {
+                stats.null_counts.insert(name.clone(), null_count);
+            }
+            if let Some(distinct_count) = self.distinct_count(source) {
+                stats.distinct_counts.insert(name.clone(), distinct_count);
+            }
+        }
+        Ok(stats)
     }
 
     pub fn estimate_row_size(&self) -> super::Result<usize> {
@@ -104,6 +259,91 @@ impl TableStatistics {
             _ => Ok(ColumnRangeStatistics::Missing),
         }
     }
+
+    /// Returns the statistics of the rows that satisfy `predicate`.
+    ///
+    /// The bounds of a column that is compared to a literal, e.g. `col("a") > lit(100)`, are narrowed to the values
+    /// that the comparison allows, for every such comparison that `predicate` is a conjunction of. Other predicates
+    /// leave the statistics as-is, as they remain valid, if loose, bounds of the filtered rows. Null and distinct counts
+    /// of the filtered rows aren't known, so they're dropped.
+    pub fn apply_filter(&self, predicate: &Expr) -> crate::Result<Self> {
+        let mut columns = self.columns.clone();
+        narrow_bounds(&mut columns, predicate)?;
+        Ok(Self::new(columns))
+    }
+}
+
+fn narrow_bounds(
+    columns: &mut IndexMap<String, ColumnRangeStatistics>,
+    predicate: &Expr,
+) -> crate::Result<()> {
+    use Operator::*;
+
+    let Expr::BinaryOp { op, left, right } = predicate else {
+        return Ok(());
+    };
+    let (name, value, op) = match (op, left.as_ref(), right.as_ref()) {
+        (And, _, _) => {
+            narrow_bounds(columns, left)?;
+            return narrow_bounds(columns, right);
+        }
+        (_, Expr::Column(name), Expr::Literal(value)) => (name, value, *op),
+        // Flip the comparison so that the column is on the left, e.g. `100 < a` is `a > 100`.
+        (_, Expr::Literal(value), Expr::Column(name)) => match op {
+            Lt => (name, value, Gt),
+            LtEq => (name, value, GtEq),
+            Gt => (name, value, Lt),
+            GtEq => (name, value, LtEq),
+            op => (name, value, *op),
+        },
+        _ => return Ok(()),
+    };
+    let Some(ColumnRangeStatistics::Loaded(lower, upper)) = columns.get(name.as_ref()) else {
+        return Ok(());
+    };
+    // Literals that can't be compared with the column don't narrow its bounds.
+    let Ok(value) = literal_as(value, lower) else {
+        return Ok(());
+    };
+    let (lower, upper) = match op {
+        Gt | GtEq => (greater_of(lower, &value)?, upper.clone()),
+        Lt | LtEq => (lower.clone(), lesser_of(upper, &value)?),
+        Eq => (greater_of(lower, &value)?, lesser_of(upper, &value)?),
+        _ => return Ok(()),
+    };
+    columns.insert(
+        name.to_string(),
+        ColumnRangeStatistics::Loaded(lower, upper),
+    );
+    Ok(())
+}
+
+/// Casts a literal to a series of the type of `bound`.
+fn literal_as(value: &LiteralValue, bound: &Series) -> DaftResult<Series> {
+    value
+        .to_series()
+        .rename(bound.name())
+        .cast(bound.data_type())
+}
+
+/// Returns the greater of two single-value series, or `bound` if they can't be compared, e.g. if `value` is null.
+fn greater_of(bound: &Series, value: &Series) -> crate::Result<Series> {
+    let is_greater = value.gt(bound).context(DaftCoreComputeSnafu)?.get(0);
+    Ok(if is_greater == Some(true) {
+        value.clone()
+    } else {
+        bound.clone()
+    })
+}
+
+/// Returns the lesser of two single-value series, or `bound` if they can't be compared, e.g. if `value` is null.
+fn lesser_of(bound: &Series, value: &Series) -> crate::Result<Series> {
+    let is_lesser = value.lt(bound).context(DaftCoreComputeSnafu)?.get(0);
+    Ok(if is_lesser == Some(true) {
+        value.clone()
+    } else {
+        bound.clone()
+    })
 }
 
 impl Display for TableStatistics {
@@ -122,11 +362,15 @@ impl Display for TableStatistics {
 #[cfg(test)]
 mod test {
 
-    use daft_core::{datatypes::Int64Array, IntoSeries};
+    use daft_core::{
+        datatypes::{Field, Int64Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
     use daft_dsl::{col, lit};
     use daft_table::Table;
 
-    use crate::column_stats::TruthValue;
+    use crate::column_stats::{ColumnRangeStatistics, TruthValue};
 
     use super::TableStatistics;
 
@@ -135,7 +379,7 @@ mod test {
         let table =
             Table::from_columns(vec![Int64Array::from(("a", vec![1, 2, 3, 4])).into_series()])
                 .unwrap();
-        let table_stats = TableStatistics::_from_table(&table);
+        let table_stats = TableStatistics::from_table(&table)?;
 
         // False case
         let expr = col("a").eq(&lit(0));
@@ -150,7 +394,7 @@ mod test {
         // True case
         let table = Table::from_columns(vec![Int64Array::from(("a", vec![0, 0, 0])).into_series()])
             .unwrap();
-        let table_stats = TableStatistics::_from_table(&table);
+        let table_stats = TableStatistics::from_table(&table)?;
 
         let expr = col("a").eq(&lit(0));
         let result = table_stats.eval_expression(&expr)?;
@@ -158,4 +402,118 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_table_counts() -> crate::Result<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from_iter("a", [Some(1), None, Some(1), Some(2), None].into_iter())
+                .into_series(),
+            Int64Array::from_iter("b", [None; 5].into_iter()).into_series(),
+        ])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table)?;
+
+        assert_eq!(table_stats.null_count("a"), Some(2));
+        assert_eq!(table_stats.distinct_count("a"), Some(2));
+        assert_eq!(table_stats.null_count("b"), Some(5));
+        assert_eq!(table_stats.distinct_count("b"), Some(0));
+        assert!(matches!(
+            table_stats.columns["b"],
+            ColumnRangeStatistics::Missing
+        ));
+        assert_eq!(table_stats.null_count("c"), None);
+
+        // Projected columns keep their counts.
+        let projected = table_stats.eval_expression_list(
+            &[col("b"), col("a").alias("c")],
+            &Schema::new(vec![
+                Field::new("b", DataType::Int64),
+                Field::new("c", DataType::Int64),
+            ])
+            .unwrap(),
+        )?;
+        assert_eq!(projected.null_count("b"), Some(5));
+        assert_eq!(projected.null_count("c"), Some(2));
+        assert_eq!(projected.distinct_count("c"), Some(2));
+
+        // Filtered rows have unknown counts.
+        let filtered = table_stats.apply_filter(&col("a").gt(&lit(1)))?;
+        assert_eq!(filtered.null_count("a"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_counts() -> crate::Result<()> {
+        let stats = [
+            vec![Some(1), None, Some(2)],
+            vec![None, None],
+            vec![Some(2), Some(3), None, Some(3)],
+        ]
+        .into_iter()
+        .map(|values| {
+            let table = Table::from_columns(vec![
+                Int64Array::from_iter("a", values.into_iter()).into_series()
+            ])
+            .unwrap();
+            TableStatistics::from_table(&table)
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+        let unioned = stats[0].union(&stats[1])?.union(&stats[2])?;
+
+        assert_eq!(unioned.num_rows, Some(9));
+        assert_eq!(unioned.null_count("a"), Some(4));
+        // Distinct values may not overlap across tables, so their counts add up.
+        assert_eq!(unioned.distinct_count("a"), Some(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_mismatched_columns() {
+        let table_stats = |name: &str| {
+            let table =
+                Table::from_columns(vec![Int64Array::from((name, vec![1, 2])).into_series()])
+                    .unwrap();
+            TableStatistics::from_table(&table).unwrap()
+        };
+        assert!(matches!(
+            table_stats("a").union(&table_stats("b")),
+            Err(crate::Error::MismatchedColumns { .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_filter_narrows_bounds() -> crate::Result<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", (50..200).collect::<Vec<i64>>())).into_series(),
+            Int64Array::from(("b", (0..150).collect::<Vec<i64>>())).into_series(),
+        ])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table)?;
+        let bounds = |stats: &TableStatistics, name: &str| match &stats.columns[name] {
+            ColumnRangeStatistics::Loaded(lower, upper) => (
+                lower.i64().unwrap().get(0).unwrap(),
+                upper.i64().unwrap().get(0).unwrap(),
+            ),
+            ColumnRangeStatistics::Missing => panic!("{name} should have statistics"),
+        };
+
+        let filtered = table_stats.apply_filter(&col("a").gt(&lit(100)))?;
+        assert_eq!(bounds(&filtered, "a"), (100, 199));
+        assert_eq!(bounds(&filtered, "b"), (0, 149));
+
+        // Comparisons with a literal on the left, and conjunctions.
+        let filtered =
+            table_stats.apply_filter(&lit(120).gt(&col("a")).and(&col("b").gt_eq(&lit(-10))))?;
+        assert_eq!(bounds(&filtered, "a"), (50, 120));
+        assert_eq!(bounds(&filtered, "b"), (0, 149));
+
+        // Other predicates keep the bounds as-is.
+        let filtered =
+            table_stats.apply_filter(&col("a").gt(&lit(100)).or(&col("b").lt(&lit(3))))?;
+        assert_eq!(bounds(&filtered, "a"), (50, 199));
+
+        Ok(())
+    }
 }
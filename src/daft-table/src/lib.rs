@@ -4,6 +4,7 @@ use std::collections::HashSet;
 use std::fmt::{Display, Formatter, Result};
 
 use daft_core::array::ops::full::FullNull;
+use daft_core::array::StructArray;
 use num_traits::ToPrimitive;
 
 use daft_core::array::ops::GroupIndices;
@@ -132,9 +133,13 @@ impl Table {
         if num >= self.len() {
             Ok(self.clone())
         } else {
-            use rand::{distributions::Uniform, Rng};
-            let range = Uniform::from(0..self.len() as u64);
-            let values: Vec<u64> = rand::thread_rng().sample_iter(&range).take(num).collect();
+            // Sample distinct indices without replacement, so exactly `num` rows are returned.
+            let mut values: Vec<u64> =
+                rand::seq::index::sample(&mut rand::thread_rng(), self.len(), num)
+                    .into_iter()
+                    .map(|i| i as u64)
+                    .collect();
+            values.sort_unstable();
             let indices: daft_core::array::DataArray<daft_core::datatypes::UInt64Type> =
                 UInt64Array::from(("idx", values));
             self.take(&indices.into_series())
@@ -347,8 +352,19 @@ impl Table {
     pub fn eval_expression_list(&self, exprs: &[Expr]) -> DaftResult<Self> {
         let result_series = exprs
             .iter()
-            .map(|e| self.eval_expression(e))
-            .collect::<DaftResult<Vec<Series>>>()?;
+            .map(|e| {
+                let series = self.eval_expression(e)?;
+                // Unpack the struct of UDFs with struct output into a column per field.
+                if e.has_struct_output() {
+                    Ok(series.downcast::<StructArray>()?.children.clone())
+                } else {
+                    Ok(vec![series])
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<Series>>();
 
         let fields = result_series
             .iter()
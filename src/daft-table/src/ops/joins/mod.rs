@@ -8,6 +8,7 @@ use daft_dsl::Expr;
 use crate::Table;
 
 mod hash_join;
+mod nested_loop_join;
 
 fn match_types_for_tables(left: &Table, right: &Table) -> DaftResult<(Table, Table)> {
     let mut lseries = vec![];
@@ -180,4 +181,26 @@ impl Table {
         drop(ridx);
         Table::new(join_schema, join_series)
     }
+
+    /// Joins this table with `right` on an arbitrary boolean `predicate`, such as a range condition like
+    /// `col("ts").gt_eq(&col("start")).and(&col("ts").lt_eq(&col("end")))`.
+    ///
+    /// The output contains all columns of this table followed by all columns of `right`, where right columns whose
+    /// names collide with a left column are prefixed with `right.`; `predicate` is evaluated against those names.
+    /// If `max_output_rows` is provided, the join fails once it produces more rows than that.
+    pub fn join_with_predicate(
+        &self,
+        right: &Self,
+        predicate: &Expr,
+        max_output_rows: Option<usize>,
+    ) -> DaftResult<Self> {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, &[], &[])?;
+        nested_loop_join::nested_loop_join(
+            self,
+            right,
+            join_schema.into(),
+            predicate,
+            max_output_rows,
+        )
+    }
 }
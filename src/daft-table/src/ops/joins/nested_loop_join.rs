@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use daft_core::{
+    datatypes::UInt64Array,
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::Expr;
+
+use crate::Table;
+use common_error::{DaftError, DaftResult};
+
+/// Number of candidate row pairs materialized at once when evaluating the join predicate.
+const CANDIDATE_BATCH_SIZE: usize = 64 * 1024;
+
+/// Joins `left` and `right` on an arbitrary predicate by evaluating it over the cross product of both tables.
+///
+/// The cross product is materialized in batches of left rows, so only `CANDIDATE_BATCH_SIZE` candidate pairs are
+/// held in memory at a time. `join_schema` must be the schema of all left columns followed by all right columns.
+pub(super) fn nested_loop_join(
+    left: &Table,
+    right: &Table,
+    join_schema: Arc<Schema>,
+    predicate: &Expr,
+    max_output_rows: Option<usize>,
+) -> DaftResult<Table> {
+    let right_len = right.len();
+    let left_batch_size = (CANDIDATE_BATCH_SIZE / right_len.max(1)).max(1);
+    let mut matched = vec![];
+    let mut num_matched = 0;
+    for batch_start in (0..left.len()).step_by(left_batch_size) {
+        let batch_end = (batch_start + left_batch_size).min(left.len());
+        let (lidx, ridx): (Vec<u64>, Vec<u64>) = (batch_start..batch_end)
+            .flat_map(|l| (0..right_len).map(move |r| (l as u64, r as u64)))
+            .unzip();
+        let candidates = take_joined(
+            left,
+            right,
+            join_schema.clone(),
+            &UInt64Array::from(("left_indices", lidx)).into_series(),
+            &UInt64Array::from(("right_indices", ridx)).into_series(),
+        )?;
+        let batch_matched = candidates.filter(&[predicate.clone()])?;
+        num_matched += batch_matched.len();
+        if max_output_rows.map_or(false, |max| num_matched > max) {
+            return Err(DaftError::ComputeError(format!(
+                "Join with predicate {} produced more than the maximum of {} rows",
+                predicate,
+                max_output_rows.unwrap()
+            )));
+        }
+        matched.push(batch_matched);
+    }
+    if matched.is_empty() {
+        return Table::empty(Some(join_schema));
+    }
+    Table::concat(matched.iter().collect::<Vec<_>>().as_slice())
+}
+
+/// Builds the joined table of the rows at `lidx` in `left` and the rows at `ridx` in `right`.
+fn take_joined(
+    left: &Table,
+    right: &Table,
+    join_schema: Arc<Schema>,
+    lidx: &Series,
+    ridx: &Series,
+) -> DaftResult<Table> {
+    let left_columns = left.columns.iter().map(|s| s.take(lidx));
+    let right_columns = right
+        .columns
+        .iter()
+        .zip(join_schema.fields.keys().skip(left.num_columns()))
+        .map(|(s, name)| s.rename(name).take(ridx));
+    let columns = left_columns
+        .chain(right_columns)
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new(join_schema, columns)
+}
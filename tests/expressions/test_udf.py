@@ -64,6 +64,25 @@ def test_udf_kwargs():
     assert result.to_pydict() == {"a": ["foofoo", "barbar", "bazbaz"]}
 
 
+@pytest.mark.parametrize(
+    ["batch_size", "expected_num_calls"],
+    [(None, 1), (1, 5), (2, 3), (5, 1), (10, 1)],
+)
+def test_udf_batch_size(batch_size, expected_num_calls):
+    table = Table.from_pydict({"a": [1, 2, 3, 4, 5]})
+    batch_lengths = []
+
+    @udf(return_dtype=DataType.int64(), batch_size=batch_size)
+    def plus_one(data):
+        batch_lengths.append(len(data))
+        return [v + 1 for v in data.to_pylist()]
+
+    result = table.eval_expression_list([plus_one(col("a"))])
+    assert result.to_pydict() == {"a": [2, 3, 4, 5, 6]}
+    assert len(batch_lengths) == expected_num_calls
+    assert all(length <= (batch_size or 5) for length in batch_lengths)
+
+
 @pytest.mark.parametrize("container", [Series, list, np.ndarray])
 def test_udf_return_containers(container):
     table = Table.from_pydict({"a": ["foo", "bar", "baz"]})
@@ -96,6 +115,80 @@ def test_udf_error():
         table.eval_expression_list([expr])
 
 
+def test_udf_declared_return_dtype():
+    table = Table.from_pydict({"a": [1, 2, 3]})
+
+    @udf(return_dtype=DataType.int64())
+    def plus_one(data):
+        return Series.from_pylist([v + 1 for v in data.to_pylist()]).cast(DataType.int32())
+
+    expr = plus_one(col("a"))
+    assert expr._to_field(table.schema()).dtype == DataType.int64()
+
+    result = table.eval_expression_list([expr])
+    assert result.schema()["a"].dtype == DataType.int64()
+    assert result.to_pydict() == {"a": [2, 3, 4]}
+
+
+def test_udf_return_dtype_mismatch():
+    table = Table.from_pydict({"a": [1, 2, 3]})
+
+    @udf(return_dtype=DataType.int64())
+    def wrap_in_list(data):
+        return Series.from_pylist([[v] for v in data.to_pylist()])
+
+    with pytest.raises(ValueError, match="cannot be cast to its declared return type Int64"):
+        table.eval_expression_list([wrap_in_list(col("a"))])
+
+
+def test_udf_struct_output():
+    table = Table.from_pydict({"a": [5, 7, 9], "b": [1, 2, 3]})
+
+    @udf(
+        return_dtype=DataType.struct({"sum": DataType.int64(), "diff": DataType.int64()}),
+        struct_output=True,
+    )
+    def sum_and_diff(a, b):
+        a, b = a.to_pylist(), b.to_pylist()
+        return {
+            "diff": [x - y for x, y in zip(a, b)],
+            "sum": [x + y for x, y in zip(a, b)],
+        }
+
+    expr = sum_and_diff(col("a"), col("b"))
+    assert [(f.name, f.dtype) for f in expr._to_projected_fields(table.schema())] == [
+        ("sum", DataType.int64()),
+        ("diff", DataType.int64()),
+    ]
+
+    result = table.eval_expression_list([expr])
+    assert result.column_names() == ["sum", "diff"]
+    assert result.to_pydict() == {"sum": [6, 9, 12], "diff": [4, 5, 6]}
+
+
+def test_udf_struct_output_field_mismatch():
+    table = Table.from_pydict({"a": [5, 7, 9], "b": [1, 2, 3]})
+
+    @udf(
+        return_dtype=DataType.struct({"sum": DataType.int64(), "diff": DataType.int64()}),
+        struct_output=True,
+    )
+    def sum_only(a, b):
+        return {"sum": [x + y for x, y in zip(a.to_pylist(), b.to_pylist())]}
+
+    with pytest.raises(ValueError, match="declared fields"):
+        table.eval_expression_list([sum_only(col("a"), col("b"))])
+
+
+def test_udf_struct_output_requires_struct_dtype():
+    @udf(return_dtype=DataType.int64(), struct_output=True)
+    def not_a_struct(a):
+        return a
+
+    with pytest.raises(ValueError, match="must declare a struct return dtype"):
+        not_a_struct(col("a"))
+
+
 def test_no_args_udf_call():
     @udf(return_dtype=DataType.int64())
     def udf_no_args():
@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.daft import ScanOperator
+from daft.datatype import DataType
+from daft.logical.schema import Schema
+
+
+class FakeScanOperator:
+    def __init__(self, limit=None, absorbs_limit=True):
+        self.received_limit = None
+        self.received_columns = None
+        self._limit = limit
+        self._absorbs_limit = absorbs_limit
+
+    def schema(self):
+        return Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])
+
+    def num_partitions(self):
+        return 1
+
+    def select(self, columns):
+        self.received_columns = columns
+        return FakeScanOperator()
+
+    def limit(self, num):
+        self.received_limit = num
+        if not self._absorbs_limit:
+            return NotImplemented
+        return FakeScanOperator(limit=num)
+
+    def __repr__(self):
+        return f"FakeScanOperator(limit={self._limit})"
+
+
+def test_python_scan_operator_limit():
+    fake = FakeScanOperator()
+    operator = ScanOperator.python_scan(fake).limit(10)
+    assert fake.received_limit == 10
+    assert "FakeScanOperator(limit=10)" in repr(operator)
+
+
+def test_python_scan_operator_limit_not_implemented():
+    fake = FakeScanOperator(absorbs_limit=False)
+    operator = ScanOperator.python_scan(fake).limit(10)
+    assert fake.received_limit == 10
+    assert "FakeScanOperator(limit=None)" in repr(operator)
+
+
+def test_scan_operator_consumed_by_limit():
+    operator = ScanOperator.python_scan(FakeScanOperator())
+    operator.limit(10)
+    with pytest.raises(ValueError, match="already consumed"):
+        operator.limit(5)
+
+
+def test_python_scan_operator_select():
+    fake = FakeScanOperator()
+    ScanOperator.python_scan(fake).select(["a", "b"])
+    assert fake.received_columns == ["a", "b"]
+
+
+def test_python_scan_operator_select_missing_column():
+    fake = FakeScanOperator()
+    with pytest.raises(ValueError, match="c not found"):
+        ScanOperator.python_scan(fake).select(["a", "c"])
+    assert fake.received_columns is None
+
+
+def test_python_scan_operator_invalid_schema():
+    class BadSchemaScanOperator(FakeScanOperator):
+        def schema(self):
+            return None
+
+    with pytest.raises(AttributeError):
+        ScanOperator.python_scan(BadSchemaScanOperator())
@@ -9,7 +9,7 @@ import tempfile
 import pytest
 
 import daft
-from daft.daft import NativeStorageConfig, PythonStorageConfig, StorageConfig
+from daft.daft import NativeStorageConfig, PythonStorageConfig, StorageConfig, read_csv_schema_bulk
 from daft.datatype import DataType
 from daft.logical.schema import Schema
 from daft.runners.partitioning import TableParseCSVOptions, TableReadOptions
@@ -263,3 +263,21 @@ def test_csv_read_data_csv_no_header(use_native_downloader):
             read_options=TableReadOptions(column_names=["id", "data"]),
         )
         assert table.to_arrow() == expected.to_arrow(), f"Expected:\n{expected}\n\nReceived:\n{table}"
+
+
+def test_read_csv_schema_bulk_merge_schemas(tmpdir):
+    tmpdir = pathlib.Path(tmpdir)
+    with open(tmpdir / "ints.csv", "w") as f:
+        f.write("id,price\n1,10\n2,20\n")
+    with open(tmpdir / "floats.csv", "w") as f:
+        f.write("id,price,note\n3,10.5,a\n4,20.25,b\n")
+    uris = [str(tmpdir / "ints.csv"), str(tmpdir / "floats.csv")]
+
+    schemas = [Schema._from_pyschema(s) for s in read_csv_schema_bulk(uris)]
+    assert [s.column_names() for s in schemas] == [["id", "price"], ["id", "price", "note"]]
+
+    expected = Schema._from_field_name_and_types(
+        [("id", DataType.int64()), ("price", DataType.float64()), ("note", DataType.string())]
+    )
+    schemas = [Schema._from_pyschema(s) for s in read_csv_schema_bulk(uris, merge_schemas=True)]
+    assert schemas == [expected, expected]
@@ -304,3 +304,25 @@ def test_parquet_read_int96_timestamps_schema_inference(coerce_to, store_schema)
     ) as f:
         schema = Schema.from_parquet(f, coerce_int96_timestamp_unit=coerce_to)
         assert schema == expected, f"Expected:\n{expected}\n\nReceived:\n{schema}"
+
+
+###
+# Test keeping Parquet dictionary encoding
+###
+
+
+def test_parquet_read_keep_dictionary_columns():
+    data = pa.Table.from_pydict(
+        {
+            "id": [1, 2, 3, 4],
+            "category": ["a", "b", None, "a"],
+        }
+    )
+    with _parquet_write_helper(data, papq_write_table_kwargs={"use_dictionary": True}) as f:
+        table = Table.read_parquet(f, keep_dictionary_columns=["category"])
+        assert table.schema()["id"].dtype == DataType.int64()
+        assert table.schema()["category"].dtype == DataType.dictionary(DataType.string())
+        assert table.get_column("category").cast(DataType.string()).to_pylist() == ["a", "b", None, "a"]
+
+        table = Table.read_parquet(f)
+        assert table.schema()["category"].dtype == DataType.string()
@@ -1,13 +1,23 @@
 from __future__ import annotations
 
 import copy
+import datetime
+import math
 
 import pyarrow as pa
+import pyarrow.parquet as papq
 import pytest
 
+from daft.daft import CsvConvertOptions, CsvParseOptions, CsvReadOptions
+from daft.datatype import DataType, TimeUnit
+from daft.execution.execution_step import FanoutSlices, LocalLimit
+from daft.expressions import ExpressionsProjection, col
 from daft.logical.schema import Schema
+from daft.runners.partitioning import PartitionMetadata
 from daft.table.micropartition import MicroPartition
 
+PYARROW_GE_11_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (11, 0, 0)
+
 
 @pytest.mark.parametrize(
     "mp",
@@ -24,3 +34,145 @@ def test_pickling_loaded(mp) -> None:
 def test_pickling_unloaded() -> None:
     mp = MicroPartition.read_parquet("tests/assets/parquet-data/parquet-with-schema-metadata.parquet")
     assert copy.deepcopy(mp).to_arrow() == mp.to_arrow()
+
+
+def test_pickling_unloaded_int96_coerced(tmp_path) -> None:
+    # These timestamps are out of the range of nanosecond timestamps, so they can only be read when coerced.
+    timestamps = [datetime.datetime(1000, 1, 1), datetime.datetime(2000, 1, 1), datetime.datetime(3000, 1, 1)]
+    path = str(tmp_path / "int96.parquet")
+    papq_write_table_kwargs = {"use_deprecated_int96_timestamps": True}
+    if PYARROW_GE_11_0_0:
+        papq_write_table_kwargs["store_schema"] = False
+    papq.write_table(
+        pa.table({"id": [1, 2, 3], "timestamp": pa.array(timestamps, pa.timestamp("ms"))}),
+        path,
+        **papq_write_table_kwargs,
+    )
+
+    mp = MicroPartition.read_parquet(path, coerce_int96_timestamp_unit=TimeUnit.ms())
+    assert mp.size_bytes_is_estimate()
+    copied = copy.deepcopy(mp).to_arrow()
+    assert copied.schema.field("timestamp").type == pa.timestamp("ms")
+    assert copied.column("timestamp").to_pylist() == timestamps
+
+
+def test_pickling_unknown_format_version() -> None:
+    mp = MicroPartition.from_pydict({"a": pa.array([1, 2, 3], type=pa.int64())})
+    constructor, (schema_bytes, tables, metadata_bytes, stats_bytes) = mp._micropartition.__reduce__()
+    bumped_metadata_bytes = bytes([metadata_bytes[0] + 1]) + metadata_bytes[1:]
+    with pytest.raises(ValueError, match="Unsupported MicroPartition serialization format version"):
+        constructor(schema_bytes, tables, bumped_metadata_bytes, stats_bytes)
+
+
+def test_arrow_record_batches_roundtrip() -> None:
+    arrow_schema = pa.schema([("b", pa.string()), ("a", pa.int64())])
+    rbs = [
+        pa.record_batch([pa.array(["x", "y"]), pa.array([1, 2])], schema=arrow_schema),
+        pa.record_batch([pa.array([None]), pa.array([3])], schema=arrow_schema),
+    ]
+    mp = MicroPartition.from_arrow_record_batches(rbs, arrow_schema)
+
+    roundtripped = mp.to_arrow_record_batches()
+    assert all(rb.schema.names == ["b", "a"] for rb in roundtripped)
+    assert [len(rb) for rb in roundtripped] == [3]
+    assert pa.Table.from_batches(roundtripped).to_pydict() == {"b": ["x", "y", None], "a": [1, 2, 3]}
+
+
+def test_arrow_record_batches_empty() -> None:
+    mp = MicroPartition.empty(Schema.from_pyarrow_schema(pa.schema({"a": pa.int64()})))
+    assert mp.to_arrow_record_batches() == []
+
+
+def test_from_arrow_record_batches_casts_to_schema() -> None:
+    rb = pa.record_batch([pa.array([1, 2, 3], type=pa.int32())], names=["a"])
+    mp = MicroPartition.from_arrow_record_batches([rb], pa.schema([("a", pa.int64())]))
+    assert mp.to_arrow() == pa.table({"a": pa.array([1, 2, 3], type=pa.int64())})
+
+
+def test_from_arrow_record_batches_name_mismatch() -> None:
+    rb = pa.record_batch([pa.array([1, 2, 3]), pa.array([4, 5, 6])], names=["a", "c"])
+    with pytest.raises(ValueError, match='column "c" where the provided schema expects column "b"'):
+        MicroPartition.from_arrow_record_batches([rb], pa.schema([("a", pa.int64()), ("b", pa.int64())]))
+
+
+def test_from_arrow_record_batches_dtype_mismatch() -> None:
+    rb = pa.record_batch([pa.array([1, 2, 3]), pa.array([[1], [2], [3]])], names=["a", "b"])
+    with pytest.raises(ValueError, match='column "b" has type List\\[Int64\\], which can\'t be cast'):
+        MicroPartition.from_arrow_record_batches([rb], pa.schema([("a", pa.int64()), ("b", pa.int64())]))
+
+
+def test_read_csv_deprecated_kwargs(tmp_path) -> None:
+    path = tmp_path / "file.csv"
+    path.write_text("a|b\n1|x\n2|y\n3|z\n")
+
+    with pytest.warns(DeprecationWarning, match="include_columns, num_rows, delimiter"):
+        mp = MicroPartition.read_csv(str(path), include_columns=["a"], num_rows=2, delimiter="|")
+    expected = MicroPartition.read_csv(
+        str(path),
+        convert_options=CsvConvertOptions(include_columns=["a"], limit=2),
+        parse_options=CsvParseOptions(delimiter="|"),
+    )
+    assert mp.to_pydict() == expected.to_pydict() == {"a": [1, 2]}
+
+    # The deprecated arguments keep their positions from before the CSV options classes existed.
+    with pytest.warns(DeprecationWarning, match="include_columns, num_rows, delimiter"):
+        mp = MicroPartition.read_csv(str(path), None, ["a"], 2, None, "|")
+    assert mp.to_pydict() == {"a": [1, 2]}
+
+    with pytest.raises(ValueError, match="Cannot pass both parse_options and the deprecated delimiter"):
+        MicroPartition.read_csv(str(path), parse_options=CsvParseOptions(), delimiter="|")
+
+
+def test_read_csv_bulk_reuse_first_schema(tmp_path) -> None:
+    first, second = tmp_path / "first.csv", tmp_path / "second.csv"
+    first.write_text("a,b\n1,x\n")
+    second.write_text("c,d\n2,y\n")
+    paths = [str(first), str(second)]
+
+    # The second file's header is skipped, and its columns are read as the first file's.
+    mp = MicroPartition.read_csv_bulk(paths, read_options=CsvReadOptions(reuse_first_schema=True))
+    assert mp.to_pydict() == {"a": [1, 2], "b": ["x", "y"]}
+
+    with pytest.raises(ValueError, match="does not match the schema inferred from"):
+        MicroPartition.read_csv_bulk(paths, read_options=CsvReadOptions(reuse_first_schema=True, strict_schema=True))
+
+
+def test_read_csv_bulk_skip_empty_files(tmp_path) -> None:
+    full, empty = tmp_path / "full.csv", tmp_path / "empty.csv"
+    full.write_text("a,b\n1,x\n")
+    empty.write_text("")
+
+    mp = MicroPartition.read_csv_bulk([str(full), str(empty)])
+    assert mp.to_pydict() == {"a": [1], "b": ["x"]}
+
+    with pytest.raises(ValueError, match="is empty"):
+        MicroPartition.read_csv_bulk([str(full), str(empty)], read_options=CsvReadOptions(skip_empty_files=False))
+
+    # With a known schema, empty files are read as zero rows, and the read keeps the schema it was given.
+    schema = Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])
+    mp = MicroPartition.read_csv_bulk(
+        [str(empty), str(empty)],
+        convert_options=CsvConvertOptions(schema=schema._schema),
+        read_options=CsvReadOptions(skip_empty_files=False),
+    )
+    assert len(mp) == 0
+    assert mp.schema() == schema
+
+
+def test_filter_unloaded_then_limit_and_split(tmp_path) -> None:
+    path = str(tmp_path / "data.parquet")
+    papq.write_table(pa.table({"a": list(range(100))}), path, row_group_size=10)
+    mp = MicroPartition.read_parquet(path)
+
+    # The filter is pushed into the deferred read, so the partition is loaded to count its rows exactly.
+    filtered = mp.filter(ExpressionsProjection([col("a") < 15]))
+    assert PartitionMetadata.from_table(filtered).num_rows == 15
+
+    # A global limit and a split plan their steps with the partition's number of rows.
+    [limited] = LocalLimit(20).run([filtered])
+    assert limited.to_pydict() == {"a": list(range(15))}
+    unloaded = mp.filter(ExpressionsProjection([col("a") < 15]))
+    num_rows = len(unloaded)
+    boundaries = [math.ceil(num_rows * i / 2) for i in range(3)]
+    slices = FanoutSlices(_num_outputs=2, slices=list(zip(boundaries[:-1], boundaries[1:]))).run([unloaded])
+    assert [s.to_pydict()["a"] for s in slices] == [list(range(8)), list(range(8, 15))]